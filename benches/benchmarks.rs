@@ -6,7 +6,7 @@ use criterion::{
     black_box, criterion_group, criterion_main, AxisScale, BenchmarkId, Criterion,
     PlotConfiguration,
 };
-use ibig::{ops::DivRem, ubig, ModuloRing, UBig};
+use ibig::{fmt::RadixFormatter, ibig, ubig, ModuloRing, PreparedDivisor, UBig};
 use rand::prelude::*;
 use std::fmt::Write;
 
@@ -34,6 +34,43 @@ fn bench_add(criterion: &mut Criterion) {
     group.finish();
 }
 
+fn bench_inc(criterion: &mut Criterion) {
+    let mut group = criterion.benchmark_group("inc");
+
+    for bits in [10, 100, 1000] {
+        let start = ubig!(1) << bits;
+        group.bench_with_input(BenchmarkId::new("add", bits), &bits, |bencher, _| {
+            bencher.iter(|| {
+                let mut x = start.clone();
+                for _ in 0..1000 {
+                    x = &x + ubig!(1);
+                }
+                x
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("add_assign", bits), &bits, |bencher, _| {
+            bencher.iter(|| {
+                let mut x = start.clone();
+                for _ in 0..1000 {
+                    x += 1u8;
+                }
+                x
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("inc", bits), &bits, |bencher, _| {
+            bencher.iter(|| {
+                let mut x = start.clone();
+                for _ in 0..1000 {
+                    x.inc();
+                }
+                x
+            })
+        });
+    }
+
+    group.finish();
+}
+
 fn bench_sub(criterion: &mut Criterion) {
     let mut rng = StdRng::seed_from_u64(1);
     let mut group = criterion.benchmark_group("sub");
@@ -69,6 +106,41 @@ fn bench_mul(criterion: &mut Criterion) {
     group.finish();
 }
 
+fn bench_product_of(criterion: &mut Criterion) {
+    let mut group = criterion.benchmark_group("product_of");
+
+    let factors: Vec<UBig> = (2..10000u32).map(UBig::from).collect();
+    group.bench_function("fold", |bencher| {
+        bencher.iter(|| {
+            black_box(&factors)
+                .iter()
+                .fold(ubig!(1), |acc, factor| acc * factor)
+        })
+    });
+    group.bench_function("product_of", |bencher| {
+        bencher.iter(|| UBig::product_of(black_box(&factors).iter().cloned()))
+    });
+
+    group.finish();
+}
+
+fn bench_mul_power_of_two(criterion: &mut Criterion) {
+    let mut rng = StdRng::seed_from_u64(1);
+    let mut group = criterion.benchmark_group("mul_power_of_two");
+    group.plot_config(PlotConfiguration::default().summary_scale(AxisScale::Logarithmic));
+
+    for log_bits in 1..=5 {
+        let bits = 10usize.pow(log_bits);
+        let a = random_ubig(bits, &mut rng);
+        let b = ubig!(1) << (bits + 100);
+        group.bench_with_input(BenchmarkId::from_parameter(bits), &bits, |bencher, _| {
+            bencher.iter(|| black_box(&a) * black_box(&b))
+        });
+    }
+
+    group.finish();
+}
+
 fn bench_div(criterion: &mut Criterion) {
     let mut rng = StdRng::seed_from_u64(1);
     let mut group = criterion.benchmark_group("div");
@@ -86,6 +158,180 @@ fn bench_div(criterion: &mut Criterion) {
     group.finish();
 }
 
+fn bench_div_double_word(criterion: &mut Criterion) {
+    let mut rng = StdRng::seed_from_u64(1);
+    let mut group = criterion.benchmark_group("div_double_word");
+    group.plot_config(PlotConfiguration::default().summary_scale(AxisScale::Logarithmic));
+
+    let d = random_ubig(120, &mut rng);
+    for bits in [200, 1000, 10000] {
+        let a = random_ubig(bits, &mut rng);
+        group.bench_with_input(BenchmarkId::new("div_rem", bits), &bits, |bencher, _| {
+            bencher.iter(|| black_box(&a).div_rem(black_box(&d)))
+        });
+        group.bench_with_input(BenchmarkId::new("rem", bits), &bits, |bencher, _| {
+            bencher.iter(|| black_box(&a) % black_box(&d))
+        });
+    }
+
+    group.finish();
+}
+
+fn bench_prepared_divisor(criterion: &mut Criterion) {
+    let mut rng = StdRng::seed_from_u64(1);
+    let mut group = criterion.benchmark_group("prepared_divisor");
+
+    let d: u64 = 1000003;
+    let prepared = PreparedDivisor::new(d);
+    for bits in [100, 1000] {
+        let numbers: Vec<UBig> = (0..1000).map(|_| random_ubig(bits, &mut rng)).collect();
+        group.bench_with_input(
+            BenchmarkId::new("div_rem_u64", bits),
+            &bits,
+            |bencher, _| {
+                bencher.iter(|| {
+                    for x in black_box(&numbers) {
+                        black_box(x.div_rem(black_box(d)));
+                    }
+                })
+            },
+        );
+        group.bench_with_input(
+            BenchmarkId::new("div_rem_prepared", bits),
+            &bits,
+            |bencher, _| {
+                bencher.iter(|| {
+                    for x in black_box(&numbers) {
+                        black_box(x.div_rem(black_box(&prepared)));
+                    }
+                })
+            },
+        );
+        group.bench_with_input(BenchmarkId::new("rem_u64", bits), &bits, |bencher, _| {
+            bencher.iter(|| {
+                for x in black_box(&numbers) {
+                    black_box(x % black_box(d));
+                }
+            })
+        });
+        group.bench_with_input(
+            BenchmarkId::new("rem_prepared", bits),
+            &bits,
+            |bencher, _| {
+                bencher.iter(|| {
+                    for x in black_box(&numbers) {
+                        black_box(x % black_box(&prepared));
+                    }
+                })
+            },
+        );
+    }
+
+    group.finish();
+}
+
+fn bench_rem_assign(criterion: &mut Criterion) {
+    let mut rng = StdRng::seed_from_u64(1);
+    let mut group = criterion.benchmark_group("rem_assign");
+    group.plot_config(PlotConfiguration::default().summary_scale(AxisScale::Logarithmic));
+
+    for bits in [100, 1000, 5000] {
+        let m = random_ubig(bits, &mut rng);
+        let base = ubig!(1) << 64;
+        group.bench_with_input(BenchmarkId::from_parameter(bits), &bits, |bencher, _| {
+            bencher.iter(|| {
+                let mut acc = ubig!(0);
+                for digit in 0..100u64 {
+                    acc = acc * &base + digit;
+                    acc %= black_box(&m);
+                }
+                acc
+            })
+        });
+    }
+
+    group.finish();
+}
+
+fn bench_shl_assign(criterion: &mut Criterion) {
+    let mut rng = StdRng::seed_from_u64(1);
+    let mut group = criterion.benchmark_group("shl_assign");
+    group.plot_config(PlotConfiguration::default().summary_scale(AxisScale::Logarithmic));
+
+    for bits in [100, 1000, 10000] {
+        let a = random_ubig(bits, &mut rng);
+        group.bench_with_input(BenchmarkId::from_parameter(bits), &bits, |bencher, _| {
+            bencher.iter(|| {
+                let mut x = black_box(&a).clone();
+                for _ in 0..100 {
+                    x <<= 1;
+                }
+                x
+            })
+        });
+    }
+
+    group.finish();
+}
+
+fn bench_double_word(criterion: &mut Criterion) {
+    let mut rng = StdRng::seed_from_u64(1);
+    let mut group = criterion.benchmark_group("double_word");
+
+    // Operands and results between 64 and 128 bits.
+    let a = random_ubig(120, &mut rng);
+    let b = random_ubig(60, &mut rng);
+    let c = random_ubig(100, &mut rng);
+    group.bench_function("add", |bencher| {
+        bencher.iter(|| black_box(&a) + black_box(&c))
+    });
+    group.bench_function("sub", |bencher| {
+        bencher.iter(|| black_box(&a) - black_box(&c))
+    });
+    group.bench_function("mul", |bencher| {
+        bencher.iter(|| black_box(&b) * black_box(&b))
+    });
+    group.bench_function("div", |bencher| {
+        bencher.iter(|| black_box(&a) / black_box(&c))
+    });
+    group.bench_function("shl", |bencher| bencher.iter(|| black_box(&b) << 50));
+
+    group.finish();
+}
+
+fn bench_cmp_zero(criterion: &mut Criterion) {
+    let mut rng = StdRng::seed_from_u64(1);
+    let mut group = criterion.benchmark_group("cmp_zero");
+
+    let a = random_ubig(1000, &mut rng);
+    group.bench_function("is_zero", |bencher| {
+        bencher.iter(|| black_box(&a).is_zero())
+    });
+    group.bench_function("eq", |bencher| bencher.iter(|| *black_box(&a) == ubig!(0)));
+    group.bench_function("gt", |bencher| bencher.iter(|| *black_box(&a) > ubig!(0)));
+
+    group.finish();
+}
+
+#[allow(clippy::cmp_owned)]
+fn bench_eq_u64(criterion: &mut Criterion) {
+    let mut rng = StdRng::seed_from_u64(1);
+    let mut group = criterion.benchmark_group("eq_u64");
+
+    let small = ubig!(12345);
+    let large = random_ubig(1000, &mut rng);
+    for (name, a) in [("small", &small), ("large", &large)] {
+        group.bench_function(format!("{}/primitive", name), |bencher| {
+            bencher.iter(|| *black_box(a) == black_box(12345u64))
+        });
+        group.bench_function(format!("{}/from", name), |bencher| {
+            bencher.iter(|| *black_box(a) == UBig::from(black_box(12345u64)))
+        });
+    }
+
+    group.finish();
+}
+
 fn bench_gcd(criterion: &mut Criterion) {
     let mut rng = StdRng::seed_from_u64(1);
     let mut group = criterion.benchmark_group("gcd");
@@ -102,6 +348,29 @@ fn bench_gcd(criterion: &mut Criterion) {
 
     group.finish();
 
+    // A large number with a small one, and a large number with a near multiple of itself.
+    let mut group = criterion.benchmark_group("gcd_lopsided");
+    group.plot_config(PlotConfiguration::default().summary_scale(AxisScale::Logarithmic));
+
+    for log_bits in 2..=5 {
+        let bits = 10usize.pow(log_bits);
+        let a = random_ubig(bits, &mut rng);
+        let b = random_ubig(128, &mut rng);
+        group.bench_with_input(BenchmarkId::new("small", bits), &bits, |bencher, _| {
+            bencher.iter(|| black_box(&a).gcd(black_box(&b)))
+        });
+        // Both odd, so that removing factors of 2 keeps c a near multiple of a.
+        let a = a | ubig!(1);
+        let c = &a * ubig!(3) + ubig!(6);
+        group.bench_with_input(
+            BenchmarkId::new("near_multiple", bits),
+            &bits,
+            |bencher, _| bencher.iter(|| black_box(&c).gcd(black_box(&a))),
+        );
+    }
+
+    group.finish();
+
     let mut group = criterion.benchmark_group("extended_gcd");
     group.plot_config(PlotConfiguration::default().summary_scale(AxisScale::Logarithmic));
 
@@ -117,6 +386,47 @@ fn bench_gcd(criterion: &mut Criterion) {
     group.finish();
 }
 
+fn bench_extended_gcd_into(criterion: &mut Criterion) {
+    let mut rng = StdRng::seed_from_u64(1);
+    let mut group = criterion.benchmark_group("extended_gcd_continued_fraction");
+
+    for bits in [100, 1000] {
+        // Convergents p/q of the continued fraction of a random a/b.
+        let mut convergents = Vec::new();
+        let (mut a, mut b) = (random_ubig(bits, &mut rng), random_ubig(bits, &mut rng));
+        let (mut p0, mut q0, mut p1, mut q1) = (ubig!(0), ubig!(1), ubig!(1), ubig!(0));
+        while b != ubig!(0) {
+            let (quotient, remainder) = a.div_rem(&b);
+            let p2 = &quotient * &p1 + &p0;
+            let q2 = &quotient * &q1 + &q0;
+            (p0, q0, p1, q1) = (p1, q1, p2, q2);
+            convergents.push((p1.clone(), q1.clone()));
+            (a, b) = (b, remainder);
+        }
+
+        group.bench_with_input(BenchmarkId::new("fresh", bits), &bits, |bencher, _| {
+            bencher.iter(|| {
+                for (p, q) in &convergents {
+                    black_box(black_box(p).extended_gcd(black_box(q)));
+                }
+            })
+        });
+        let mut g = ubig!(0);
+        let mut x = ibig!(0);
+        let mut y = ibig!(0);
+        group.bench_with_input(BenchmarkId::new("reuse", bits), &bits, |bencher, _| {
+            bencher.iter(|| {
+                for (p, q) in &convergents {
+                    black_box(p).extended_gcd_into(black_box(q), &mut g, &mut x, &mut y);
+                    black_box((&g, &x, &y));
+                }
+            })
+        });
+    }
+
+    group.finish();
+}
+
 fn bench_to_hex(criterion: &mut Criterion) {
     let mut rng = StdRng::seed_from_u64(1);
     let mut group = criterion.benchmark_group("to_hex");
@@ -159,6 +469,95 @@ fn bench_to_dec(criterion: &mut Criterion) {
     group.finish();
 }
 
+fn bench_significant_digits(criterion: &mut Criterion) {
+    let mut rng = StdRng::seed_from_u64(1);
+    let mut group = criterion.benchmark_group("significant_digits");
+    group.plot_config(PlotConfiguration::default().summary_scale(AxisScale::Logarithmic));
+
+    for log_bits in 2..=6 {
+        let bits = 10usize.pow(log_bits);
+        let a = random_ubig(bits, &mut rng);
+        group.bench_with_input(BenchmarkId::new("dec", bits), &bits, |bencher, _| {
+            bencher.iter(|| black_box(&a).significant_digits(10, 10))
+        });
+        group.bench_with_input(BenchmarkId::new("exp", bits), &bits, |bencher, _| {
+            bencher.iter(|| format!("{:.9e}", black_box(&a)))
+        });
+    }
+
+    group.finish();
+}
+
+fn bench_to_dec_batch(criterion: &mut Criterion) {
+    let mut rng = StdRng::seed_from_u64(1);
+    let mut group = criterion.benchmark_group("to_dec_batch");
+
+    for bits in [3000, 30000] {
+        let numbers: Vec<UBig> = (0..100).map(|_| random_ubig(bits, &mut rng)).collect();
+        let mut out = String::with_capacity(bits / 3 + 1);
+        group.bench_with_input(BenchmarkId::new("display", bits), &bits, |bencher, _| {
+            bencher.iter(|| {
+                for x in black_box(&numbers) {
+                    out.clear();
+                    write!(&mut out, "{}", x).unwrap();
+                }
+                out.len()
+            })
+        });
+        let mut formatter = RadixFormatter::new(10);
+        group.bench_with_input(
+            BenchmarkId::new("radix_formatter", bits),
+            &bits,
+            |bencher, _| {
+                bencher.iter(|| {
+                    for x in black_box(&numbers) {
+                        out.clear();
+                        write!(&mut out, "{}", formatter.format(x)).unwrap();
+                    }
+                    out.len()
+                })
+            },
+        );
+    }
+
+    group.finish();
+}
+
+fn bench_write_radix_digits(criterion: &mut Criterion) {
+    let mut rng = StdRng::seed_from_u64(1);
+    let mut group = criterion.benchmark_group("write_radix_digits");
+
+    for bits in [100, 1000] {
+        let numbers: Vec<UBig> = (0..100).map(|_| random_ubig(bits, &mut rng)).collect();
+        group.bench_with_input(
+            BenchmarkId::new("to_radix_string", bits),
+            &bits,
+            |bencher, _| {
+                bencher.iter(|| {
+                    for x in black_box(&numbers) {
+                        black_box(x.to_radix_string(10));
+                    }
+                })
+            },
+        );
+        let mut digits = Vec::new();
+        group.bench_with_input(
+            BenchmarkId::new("write_radix_digits", bits),
+            &bits,
+            |bencher, _| {
+                bencher.iter(|| {
+                    for x in black_box(&numbers) {
+                        x.write_radix_digits(10, &mut digits);
+                        black_box(&digits);
+                    }
+                })
+            },
+        );
+    }
+
+    group.finish();
+}
+
 fn bench_from_hex(criterion: &mut Criterion) {
     let mut rng = StdRng::seed_from_u64(1);
     let mut group = criterion.benchmark_group("from_hex");
@@ -193,6 +592,21 @@ fn bench_from_dec(criterion: &mut Criterion) {
     group.finish();
 }
 
+fn bench_from_dec_million_digits(criterion: &mut Criterion) {
+    let mut rng = StdRng::seed_from_u64(1);
+    let mut group = criterion.benchmark_group("from_dec_million_digits");
+    group.sample_size(10);
+
+    let s: String = (0..1_000_000)
+        .map(|_| char::from(b'0' + rng.gen_range(0..10)))
+        .collect();
+    group.bench_function("parse", |bencher| {
+        bencher.iter(|| UBig::from_str_radix(black_box(&s), 10))
+    });
+
+    group.finish();
+}
+
 fn bench_pow(criterion: &mut Criterion) {
     let mut group = criterion.benchmark_group("pow");
     group.plot_config(PlotConfiguration::default().summary_scale(AxisScale::Logarithmic));
@@ -247,20 +661,67 @@ fn bench_modulo_pow(criterion: &mut Criterion) {
 
     group.finish();
 }
+
+fn bench_modulo_pow_table(criterion: &mut Criterion) {
+    let mut rng = StdRng::seed_from_u64(1);
+    let mut group = criterion.benchmark_group("modulo_pow_table");
+    group.sample_size(10);
+
+    for bits in [100, 1000] {
+        let m = random_ubig(bits, &mut rng);
+        let ring = ModuloRing::new(&m);
+        let a = ring.from(random_ubig(bits, &mut rng));
+        let table = a.precompute_powers();
+        let exps: Vec<UBig> = (0..100).map(|_| random_ubig(bits, &mut rng)).collect();
+        group.bench_with_input(BenchmarkId::new("pow", bits), &bits, |bencher, _| {
+            bencher.iter(|| {
+                for exp in black_box(&exps) {
+                    black_box(a.pow(exp));
+                }
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("table", bits), &bits, |bencher, _| {
+            bencher.iter(|| {
+                for exp in black_box(&exps) {
+                    black_box(table.pow(exp));
+                }
+            })
+        });
+    }
+
+    group.finish();
+}
+
 criterion_group!(
     benches,
     bench_add,
+    bench_inc,
     bench_sub,
     bench_mul,
+    bench_product_of,
+    bench_mul_power_of_two,
     bench_div,
+    bench_div_double_word,
+    bench_prepared_divisor,
+    bench_rem_assign,
+    bench_shl_assign,
+    bench_double_word,
+    bench_cmp_zero,
+    bench_eq_u64,
     bench_gcd,
+    bench_extended_gcd_into,
     bench_to_hex,
     bench_to_dec,
+    bench_to_dec_batch,
+    bench_significant_digits,
+    bench_write_radix_digits,
     bench_from_hex,
     bench_from_dec,
+    bench_from_dec_million_digits,
     bench_pow,
     bench_modulo_mul,
     bench_modulo_pow,
+    bench_modulo_pow_table,
 );
 
 criterion_main!(benches);
@@ -2,11 +2,11 @@
 
 use crate::{
     add,
-    arch::word::Word,
+    arch::word::{DoubleWord, Word},
     buffer::Buffer,
     helper_macros,
     ibig::IBig,
-    primitive::{PrimitiveSigned, PrimitiveUnsigned},
+    primitive::{extend_word, split_double_word, PrimitiveSigned, PrimitiveUnsigned},
     sign::Sign::*,
     ubig::{Repr::*, UBig},
 };
@@ -22,8 +22,13 @@ impl Add<UBig> for UBig {
     fn add(self, rhs: UBig) -> UBig {
         match (self.into_repr(), rhs.into_repr()) {
             (Small(word0), Small(word1)) => UBig::add_word(word0, word1),
+            (Small(word0), Double(dword1)) => UBig::add_double_word(extend_word(word0), dword1),
             (Small(word0), Large(buffer1)) => UBig::add_large_word(buffer1, word0),
+            (Double(dword0), Small(word1)) => UBig::add_double_word(dword0, extend_word(word1)),
+            (Double(dword0), Double(dword1)) => UBig::add_double_word(dword0, dword1),
+            (Double(dword0), Large(buffer1)) => UBig::add_large_double_word(buffer1, dword0),
             (Large(buffer0), Small(word1)) => UBig::add_large_word(buffer0, word1),
+            (Large(buffer0), Double(dword1)) => UBig::add_large_double_word(buffer0, dword1),
             (Large(buffer0), Large(buffer1)) => {
                 if buffer0.len() >= buffer1.len() {
                     UBig::add_large(buffer0, &buffer1)
@@ -42,8 +47,15 @@ impl Add<&UBig> for UBig {
     fn add(self, rhs: &UBig) -> UBig {
         match (self.into_repr(), rhs.repr()) {
             (Small(word0), Small(word1)) => UBig::add_word(word0, *word1),
+            (Small(word0), Double(dword1)) => UBig::add_double_word(extend_word(word0), *dword1),
             (Small(word0), Large(buffer1)) => UBig::add_large_word(buffer1.clone(), word0),
+            (Double(dword0), Small(word1)) => UBig::add_double_word(dword0, extend_word(*word1)),
+            (Double(dword0), Double(dword1)) => UBig::add_double_word(dword0, *dword1),
+            (Double(dword0), Large(buffer1)) => {
+                UBig::add_large_double_word(buffer1.clone(), dword0)
+            }
             (Large(buffer0), Small(word1)) => UBig::add_large_word(buffer0, *word1),
+            (Large(buffer0), Double(dword1)) => UBig::add_large_double_word(buffer0, *dword1),
             (Large(buffer0), Large(buffer1)) => UBig::add_large(buffer0, buffer1),
         }
     }
@@ -65,8 +77,17 @@ impl Add<&UBig> for &UBig {
     fn add(self, rhs: &UBig) -> UBig {
         match (self.repr(), rhs.repr()) {
             (Small(word0), Small(word1)) => UBig::add_word(*word0, *word1),
+            (Small(word0), Double(dword1)) => UBig::add_double_word(extend_word(*word0), *dword1),
             (Small(word0), Large(buffer1)) => UBig::add_large_word(buffer1.clone(), *word0),
+            (Double(dword0), Small(word1)) => UBig::add_double_word(*dword0, extend_word(*word1)),
+            (Double(dword0), Double(dword1)) => UBig::add_double_word(*dword0, *dword1),
+            (Double(dword0), Large(buffer1)) => {
+                UBig::add_large_double_word(buffer1.clone(), *dword0)
+            }
             (Large(buffer0), Small(word1)) => UBig::add_large_word(buffer0.clone(), *word1),
+            (Large(buffer0), Double(dword1)) => {
+                UBig::add_large_double_word(buffer0.clone(), *dword1)
+            }
             (Large(buffer0), Large(buffer1)) => {
                 if buffer0.len() >= buffer1.len() {
                     UBig::add_large(buffer0.clone(), buffer1)
@@ -99,8 +120,11 @@ impl Sub<UBig> for UBig {
     fn sub(self, rhs: UBig) -> UBig {
         match (self.into_repr(), rhs.into_repr()) {
             (Small(word0), Small(word1)) => UBig::sub_word(word0, word1),
-            (Small(_), Large(_)) => UBig::panic_negative(),
+            (Small(_), Double(_) | Large(_)) | (Double(_), Large(_)) => UBig::panic_negative(),
+            (Double(dword0), Small(word1)) => UBig::sub_double_word(dword0, extend_word(word1)),
+            (Double(dword0), Double(dword1)) => UBig::sub_double_word(dword0, dword1),
             (Large(buffer0), Small(word1)) => UBig::sub_large_word(buffer0, word1),
+            (Large(buffer0), Double(dword1)) => UBig::sub_large_double_word(buffer0, dword1),
             (Large(buffer0), Large(buffer1)) => UBig::sub_large(buffer0, &buffer1),
         }
     }
@@ -113,8 +137,11 @@ impl Sub<&UBig> for UBig {
     fn sub(self, rhs: &UBig) -> UBig {
         match (self.into_repr(), rhs.repr()) {
             (Small(word0), Small(word1)) => UBig::sub_word(word0, *word1),
-            (Small(_), Large(_)) => UBig::panic_negative(),
+            (Small(_), Double(_) | Large(_)) | (Double(_), Large(_)) => UBig::panic_negative(),
+            (Double(dword0), Small(word1)) => UBig::sub_double_word(dword0, extend_word(*word1)),
+            (Double(dword0), Double(dword1)) => UBig::sub_double_word(dword0, *dword1),
             (Large(buffer0), Small(word1)) => UBig::sub_large_word(buffer0, *word1),
+            (Large(buffer0), Double(dword1)) => UBig::sub_large_double_word(buffer0, *dword1),
             (Large(buffer0), Large(buffer1)) => UBig::sub_large(buffer0, buffer1),
         }
     }
@@ -127,8 +154,13 @@ impl Sub<UBig> for &UBig {
     fn sub(self, rhs: UBig) -> UBig {
         match (self.repr(), rhs.into_repr()) {
             (Small(word0), Small(word1)) => UBig::sub_word(*word0, word1),
-            (Small(_), Large(_)) => UBig::panic_negative(),
+            (Small(_), Double(_) | Large(_)) | (Double(_), Large(_)) => UBig::panic_negative(),
+            (Double(dword0), Small(word1)) => UBig::sub_double_word(*dword0, extend_word(word1)),
+            (Double(dword0), Double(dword1)) => UBig::sub_double_word(*dword0, dword1),
             (Large(buffer0), Small(word1)) => UBig::sub_large_word(buffer0.clone(), word1),
+            (Large(buffer0), Double(dword1)) => {
+                UBig::sub_large_double_word(buffer0.clone(), dword1)
+            }
             (Large(buffer0), Large(buffer1)) => UBig::sub_large_ref_val(buffer0, buffer1),
         }
     }
@@ -141,8 +173,13 @@ impl Sub<&UBig> for &UBig {
     fn sub(self, rhs: &UBig) -> UBig {
         match (self.repr(), rhs.repr()) {
             (Small(word0), Small(word1)) => UBig::sub_word(*word0, *word1),
-            (Small(_), Large(_)) => UBig::panic_negative(),
+            (Small(_), Double(_) | Large(_)) | (Double(_), Large(_)) => UBig::panic_negative(),
+            (Double(dword0), Small(word1)) => UBig::sub_double_word(*dword0, extend_word(*word1)),
+            (Double(dword0), Double(dword1)) => UBig::sub_double_word(*dword0, *dword1),
             (Large(buffer0), Small(word1)) => UBig::sub_large_word(buffer0.clone(), *word1),
+            (Large(buffer0), Double(dword1)) => {
+                UBig::sub_large_double_word(buffer0.clone(), *dword1)
+            }
             (Large(buffer0), Large(buffer1)) => UBig::sub_large(buffer0.clone(), buffer1),
         }
     }
@@ -525,30 +562,264 @@ impl_add_ibig_primitive!(i64);
 impl_add_ibig_primitive!(i128);
 impl_add_ibig_primitive!(isize);
 
+impl UBig {
+    /// Checked addition.
+    ///
+    /// Returns `None` if the sum would exceed [UBig::MAX_BIT_LEN] bits, where `+` would panic.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// assert_eq!(ubig!(2).checked_add(&ubig!(3)), Some(ubig!(5)));
+    /// ```
+    pub fn checked_add(&self, rhs: &UBig) -> Option<UBig> {
+        if self.bit_len().max(rhs.bit_len()) < UBig::MAX_BIT_LEN {
+            return Some(self + rhs);
+        }
+        // The longer operand fills all `MAX_LEN` words, so the sum fits iff adding into its
+        // words does not carry out of the top word.
+        let (long, short) = if self.len() >= rhs.len() {
+            (self, rhs)
+        } else {
+            (rhs, self)
+        };
+        let mut buffer = Buffer::allocate(long.len());
+        buffer.extend(&*long.as_words());
+        if add::add_in_place(&mut buffer, &short.as_words()) {
+            None
+        } else {
+            Some(buffer.into())
+        }
+    }
+
+    /// Saturating subtraction.
+    ///
+    /// Returns 0 if `rhs > self`, where `-` would panic.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// assert_eq!(ubig!(5).saturating_sub(&ubig!(3)), ubig!(2));
+    /// assert_eq!(ubig!(3).saturating_sub(&ubig!(5)), ubig!(0));
+    /// ```
+    pub fn saturating_sub(&self, rhs: &UBig) -> UBig {
+        if *rhs > *self {
+            UBig::from_word(0)
+        } else {
+            self - rhs
+        }
+    }
+
+    /// Increment in place: `self += 1`.
+    ///
+    /// Large numbers are incremented in their existing buffer, which is only reallocated if the
+    /// carry goes out of the top word and there is no room for another.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// let mut a = ubig!(0xff);
+    /// a.inc();
+    /// assert_eq!(a, ubig!(0x100));
+    /// ```
+    #[inline]
+    pub fn inc(&mut self) {
+        match self.repr_mut() {
+            Small(word) => match word.checked_add(1) {
+                Some(res) => *word = res,
+                None => *self = UBig::from_double_word(extend_word(Word::MAX) + 1),
+            },
+            Double(dword) => *self = UBig::add_double_word(*dword, 1),
+            // The top word stays non-zero, so the buffer stays normalized.
+            Large(buffer) => {
+                if add::add_one_in_place(buffer) {
+                    buffer.push_may_reallocate(1);
+                }
+            }
+        }
+    }
+
+    /// Decrement in place: `self -= 1`.
+    ///
+    /// Large numbers are decremented in their existing buffer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is 0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// let mut a = ubig!(0x100);
+    /// a.dec();
+    /// assert_eq!(a, ubig!(0xff));
+    /// ```
+    #[inline]
+    pub fn dec(&mut self) {
+        match self.repr_mut() {
+            Small(word) => match word.checked_sub(1) {
+                Some(res) => *word = res,
+                None => UBig::panic_negative(),
+            },
+            // The top word stays non-zero, so the buffer stays normalized.
+            Large(buffer) if *buffer.last().unwrap() > 1 => {
+                let borrow = add::sub_one_in_place(buffer);
+                debug_assert!(!borrow);
+            }
+            _ => *self = mem::take(self) - UBig::from_word(1),
+        }
+    }
+
+    /// The next number below an exclusive upper bound: `self + 1` if it is less than `limit`,
+    /// `None` otherwise.
+    ///
+    /// Starting from `start`, repeated calls visit the numbers of the range `start..limit`, like
+    /// iterating over a [Range](core::ops::Range).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// assert_eq!(ubig!(3).checked_next(&ubig!(5)), Some(ubig!(4)));
+    /// assert_eq!(ubig!(4).checked_next(&ubig!(5)), None);
+    ///
+    /// let mut sum = ubig!(0);
+    /// let mut i = Some(ubig!(10));
+    /// while let Some(x) = i {
+    ///     sum += &x;
+    ///     i = x.checked_next(&ubig!(20));
+    /// }
+    /// assert_eq!(sum, ubig!(145));
+    /// ```
+    #[inline]
+    pub fn checked_next(&self, limit: &UBig) -> Option<UBig> {
+        let mut next = self.clone();
+        next.inc();
+        if next < *limit {
+            Some(next)
+        } else {
+            None
+        }
+    }
+
+    /// The previous number down to an inclusive lower bound: `self - 1` if it is at least
+    /// `limit`, `None` otherwise.
+    ///
+    /// Starting from `end`, repeated calls visit the numbers of the range `limit..end` in
+    /// reverse. In particular, `ubig!(0).checked_prev(&ubig!(0))` is `None`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// assert_eq!(ubig!(5).checked_prev(&ubig!(3)), Some(ubig!(4)));
+    /// assert_eq!(ubig!(3).checked_prev(&ubig!(3)), None);
+    /// ```
+    #[inline]
+    pub fn checked_prev(&self, limit: &UBig) -> Option<UBig> {
+        if *self > *limit {
+            let mut prev = self.clone();
+            prev.dec();
+            Some(prev)
+        } else {
+            None
+        }
+    }
+}
+
+impl IBig {
+    /// The next number below an exclusive upper bound: `self + 1` if it is less than `limit`,
+    /// `None` otherwise.
+    ///
+    /// Starting from `start`, repeated calls visit the numbers of the range `start..limit`, like
+    /// iterating over a [Range](core::ops::Range).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ibig::ibig;
+    /// assert_eq!(ibig!(-1).checked_next(&ibig!(5)), Some(ibig!(0)));
+    /// assert_eq!(ibig!(4).checked_next(&ibig!(5)), None);
+    /// ```
+    #[inline]
+    pub fn checked_next(&self, limit: &IBig) -> Option<IBig> {
+        let mut next = self.clone();
+        next += 1u8;
+        if next < *limit {
+            Some(next)
+        } else {
+            None
+        }
+    }
+
+    /// The previous number down to an inclusive lower bound: `self - 1` if it is at least
+    /// `limit`, `None` otherwise.
+    ///
+    /// Starting from `end`, repeated calls visit the numbers of the range `limit..end` in
+    /// reverse.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ibig::ibig;
+    /// assert_eq!(ibig!(0).checked_prev(&ibig!(-3)), Some(ibig!(-1)));
+    /// assert_eq!(ibig!(-3).checked_prev(&ibig!(-3)), None);
+    /// ```
+    #[inline]
+    pub fn checked_prev(&self, limit: &IBig) -> Option<IBig> {
+        if *self > *limit {
+            let mut prev = self.clone();
+            prev -= 1u8;
+            Some(prev)
+        } else {
+            None
+        }
+    }
+}
+
 impl UBig {
     /// Add two `Word`s.
     #[inline]
     fn add_word(a: Word, b: Word) -> UBig {
+        UBig::from_double_word(extend_word(a) + extend_word(b))
+    }
+
+    /// Add two `DoubleWord`s.
+    #[inline]
+    fn add_double_word(a: DoubleWord, b: DoubleWord) -> UBig {
         let (res, overflow) = a.overflowing_add(b);
         if overflow {
-            let mut buffer = Buffer::allocate(2);
-            buffer.push(res);
+            let (lo, hi) = split_double_word(res);
+            let mut buffer = Buffer::allocate(3);
+            buffer.push(lo);
+            buffer.push(hi);
             buffer.push(1);
             buffer.into()
         } else {
-            UBig::from_word(res)
+            UBig::from_double_word(res)
         }
     }
 
     /// Add a large number to a `Word`.
     fn add_large_word(mut buffer: Buffer, rhs: Word) -> UBig {
-        debug_assert!(buffer.len() >= 2);
+        debug_assert!(buffer.len() >= 3);
         if add::add_word_in_place(&mut buffer, rhs) {
             buffer.push_may_reallocate(1);
         }
         buffer.into()
     }
 
+    /// Add a large number to a `DoubleWord`.
+    fn add_large_double_word(buffer: Buffer, rhs: DoubleWord) -> UBig {
+        debug_assert!(buffer.len() >= 3);
+        let (lo, hi) = split_double_word(rhs);
+        UBig::add_large(buffer, &[lo, hi])
+    }
+
     /// Add two large numbers.
     fn add_large(mut buffer: Buffer, rhs: &[Word]) -> UBig {
         let n = buffer.len().min(rhs.len());
@@ -572,12 +843,26 @@ impl UBig {
         }
     }
 
+    /// Subtract two `DoubleWord`s.
+    #[inline]
+    fn sub_double_word(a: DoubleWord, b: DoubleWord) -> UBig {
+        match a.checked_sub(b) {
+            Some(res) => UBig::from_double_word(res),
+            None => UBig::panic_negative(),
+        }
+    }
+
     fn sub_large_word(mut lhs: Buffer, rhs: Word) -> UBig {
         let overflow = add::sub_word_in_place(&mut lhs, rhs);
         assert!(!overflow);
         lhs.into()
     }
 
+    fn sub_large_double_word(lhs: Buffer, rhs: DoubleWord) -> UBig {
+        let (lo, hi) = split_double_word(rhs);
+        UBig::sub_large(lhs, &[lo, hi])
+    }
+
     fn sub_large(mut lhs: Buffer, rhs: &[Word]) -> UBig {
         if lhs.len() < rhs.len() || add::sub_in_place(&mut lhs, rhs) {
             UBig::panic_negative();
@@ -665,8 +950,13 @@ impl IBig {
     fn sub_ubig_val_val(lhs: UBig, rhs: UBig) -> IBig {
         match (lhs.into_repr(), rhs.into_repr()) {
             (Small(word0), Small(word1)) => IBig::sub_word_word(word0, word1),
+            (Small(word0), Double(dword1)) => IBig::sub_double_word(extend_word(word0), dword1),
             (Small(word0), Large(buffer1)) => -IBig::sub_large_word(buffer1, word0),
+            (Double(dword0), Small(word1)) => IBig::sub_double_word(dword0, extend_word(word1)),
+            (Double(dword0), Double(dword1)) => IBig::sub_double_word(dword0, dword1),
+            (Double(dword0), Large(buffer1)) => -IBig::sub_large_double_word(buffer1, dword0),
             (Large(buffer0), Small(word1)) => IBig::sub_large_word(buffer0, word1),
+            (Large(buffer0), Double(dword1)) => IBig::sub_large_double_word(buffer0, dword1),
             (Large(buffer0), Large(buffer1)) => {
                 if buffer0.len() >= buffer1.len() {
                     IBig::sub_large(buffer0, &buffer1)
@@ -681,8 +971,15 @@ impl IBig {
     fn sub_ubig_val_ref(lhs: UBig, rhs: &UBig) -> IBig {
         match (lhs.into_repr(), rhs.repr()) {
             (Small(word0), Small(word1)) => IBig::sub_word_word(word0, *word1),
+            (Small(word0), Double(dword1)) => IBig::sub_double_word(extend_word(word0), *dword1),
             (Small(word0), Large(buffer1)) => -IBig::sub_large_word(buffer1.clone(), word0),
+            (Double(dword0), Small(word1)) => IBig::sub_double_word(dword0, extend_word(*word1)),
+            (Double(dword0), Double(dword1)) => IBig::sub_double_word(dword0, *dword1),
+            (Double(dword0), Large(buffer1)) => {
+                -IBig::sub_large_double_word(buffer1.clone(), dword0)
+            }
             (Large(buffer0), Small(word1)) => IBig::sub_large_word(buffer0, *word1),
+            (Large(buffer0), Double(dword1)) => IBig::sub_large_double_word(buffer0, *dword1),
             (Large(buffer0), Large(buffer1)) => IBig::sub_large(buffer0, buffer1),
         }
     }
@@ -691,8 +988,17 @@ impl IBig {
     fn sub_ubig_ref_ref(lhs: &UBig, rhs: &UBig) -> IBig {
         match (lhs.repr(), rhs.repr()) {
             (Small(word0), Small(word1)) => IBig::sub_word_word(*word0, *word1),
+            (Small(word0), Double(dword1)) => IBig::sub_double_word(extend_word(*word0), *dword1),
             (Small(word0), Large(buffer1)) => -IBig::sub_large_word(buffer1.clone(), *word0),
+            (Double(dword0), Small(word1)) => IBig::sub_double_word(*dword0, extend_word(*word1)),
+            (Double(dword0), Double(dword1)) => IBig::sub_double_word(*dword0, *dword1),
+            (Double(dword0), Large(buffer1)) => {
+                -IBig::sub_large_double_word(buffer1.clone(), *dword0)
+            }
             (Large(buffer0), Small(word1)) => IBig::sub_large_word(buffer0.clone(), *word1),
+            (Large(buffer0), Double(dword1)) => {
+                IBig::sub_large_double_word(buffer0.clone(), *dword1)
+            }
             (Large(buffer0), Large(buffer1)) => {
                 if buffer0.len() >= buffer1.len() {
                     IBig::sub_large(buffer0.clone(), buffer1)
@@ -713,10 +1019,24 @@ impl IBig {
         }
     }
 
+    #[inline]
+    fn sub_double_word(lhs: DoubleWord, rhs: DoubleWord) -> IBig {
+        let (val, overflow) = lhs.overflowing_sub(rhs);
+        if !overflow {
+            IBig::from(UBig::from_double_word(val))
+        } else {
+            -IBig::from(UBig::from_double_word(val.wrapping_neg()))
+        }
+    }
+
     fn sub_large_word(lhs: Buffer, rhs: Word) -> IBig {
         UBig::sub_large_word(lhs, rhs).into()
     }
 
+    fn sub_large_double_word(lhs: Buffer, rhs: DoubleWord) -> IBig {
+        UBig::sub_large_double_word(lhs, rhs).into()
+    }
+
     fn sub_large(mut lhs: Buffer, rhs: &[Word]) -> IBig {
         if lhs.len() >= rhs.len() {
             let sign = add::sub_in_place_with_sign(&mut lhs, rhs);
@@ -1,19 +1,22 @@
 //! Bitwise operators.
 
 use crate::{
-    arch::word::Word,
+    arch::word::{DoubleWord, Word},
     buffer::Buffer,
     helper_macros,
     ibig::IBig,
     math,
-    ops::{AndNot, NextPowerOfTwo, UnsignedAbs},
-    primitive::{double_word, PrimitiveSigned, PrimitiveUnsigned, WORD_BITS_USIZE},
+    ops::{AndNot, NextPowerOfTwo},
+    primitive::{
+        double_word, extend_word, split_double_word, PrimitiveSigned, PrimitiveUnsigned,
+        WORD_BITS_USIZE,
+    },
     sign::Sign::*,
-    ubig::{Repr::*, UBig},
+    ubig::{Repr::*, UBig, Words},
 };
 use core::{
     mem,
-    ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not},
+    ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not, Range},
 };
 
 impl UBig {
@@ -31,6 +34,7 @@ impl UBig {
     pub fn bit(&self, n: usize) -> bool {
         match self.repr() {
             Small(word) => n < WORD_BITS_USIZE && word & 1 << n != 0,
+            Double(dword) => n < 2 * WORD_BITS_USIZE && dword & 1 << n != 0,
             Large(buffer) => {
                 let idx = n / WORD_BITS_USIZE;
                 idx < buffer.len() && buffer[idx] & 1 << (n % WORD_BITS_USIZE) != 0
@@ -38,6 +42,60 @@ impl UBig {
         }
     }
 
+    /// A read-only view of the bits of the number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// let x = ubig!(0b10010);
+    /// let bits = x.view_bits();
+    /// assert_eq!(bits.len(), 5);
+    /// assert!(bits.get(1));
+    /// assert!(!bits.get(2));
+    /// assert!(bits.iter().eq([false, true, false, false, true]));
+    /// ```
+    #[inline]
+    pub fn view_bits(&self) -> BitView<'_> {
+        BitView {
+            words: self.as_words(),
+            len: self.bit_len(),
+        }
+    }
+
+    /// Returns true if any bit in the range `range` is set.
+    ///
+    /// This doesn't construct a masked copy of the number. `!x.any_bit_set(0..k)` checks whether
+    /// `x` is a multiple of `2^k`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// assert_eq!(ubig!(0b10010).any_bit_set(2..4), false);
+    /// assert_eq!(ubig!(0b10010).any_bit_set(2..5), true);
+    /// assert_eq!(ubig!(0b10010).any_bit_set(5..1000), false);
+    /// ```
+    pub fn any_bit_set(&self, range: Range<usize>) -> bool {
+        let words = self.as_words();
+        let start = range.start;
+        let end = range.end.min(words.len() * WORD_BITS_USIZE);
+        if start >= end {
+            return false;
+        }
+        let start_idx = start / WORD_BITS_USIZE;
+        let start_mask = Word::MAX << (start % WORD_BITS_USIZE);
+        let end_idx = (end - 1) / WORD_BITS_USIZE;
+        let end_mask = math::ones_word(((end - 1) % WORD_BITS_USIZE) as u32 + 1);
+        if start_idx == end_idx {
+            words[start_idx] & start_mask & end_mask != 0
+        } else {
+            words[start_idx] & start_mask != 0
+                || words[start_idx + 1..end_idx].iter().any(|word| *word != 0)
+                || words[end_idx] & end_mask != 0
+        }
+    }
+
     /// Set the `n`-th bit.
     ///
     /// # Examples
@@ -57,21 +115,20 @@ impl UBig {
                 if n < WORD_BITS_USIZE {
                     *self = UBig::from_word(word | 1 << n)
                 } else {
-                    *self = UBig::with_bit_word_slow(word, n)
+                    *self = UBig::with_bit_double_word(extend_word(word), n)
                 }
             }
+            Double(dword) => *self = UBig::with_bit_double_word(dword, n),
             Large(buffer) => *self = UBig::with_bit_large(buffer, n),
         }
     }
 
-    fn with_bit_word_slow(word: Word, n: usize) -> UBig {
-        debug_assert!(n >= WORD_BITS_USIZE);
-        let idx = n / WORD_BITS_USIZE;
-        let mut buffer = Buffer::allocate(idx + 1);
-        buffer.push(word);
-        buffer.extend((1..idx).map(|_| 0));
-        buffer.push(1 << (n % WORD_BITS_USIZE));
-        buffer.into()
+    fn with_bit_double_word(dword: DoubleWord, n: usize) -> UBig {
+        if n < 2 * WORD_BITS_USIZE {
+            UBig::from_double_word(dword | 1 << n)
+        } else {
+            UBig::with_bit_large(Buffer::from_double_word(dword), n)
+        }
     }
 
     fn with_bit_large(mut buffer: Buffer, n: usize) -> UBig {
@@ -102,6 +159,15 @@ impl UBig {
             Small(word) => {
                 if n < WORD_BITS_USIZE {
                     *self = UBig::from_word(word & !(1 << n))
+                } else {
+                    *self = UBig::from_word(word)
+                }
+            }
+            Double(dword) => {
+                if n < 2 * WORD_BITS_USIZE {
+                    *self = UBig::from_double_word(dword & !(1 << n))
+                } else {
+                    *self = UBig::from_double_word(dword)
                 }
             }
             Large(buffer) => *self = UBig::without_bit_large(buffer, n),
@@ -136,11 +202,54 @@ impl UBig {
         match self.repr() {
             Small(0) => None,
             Small(word) => Some(word.trailing_zeros() as usize),
+            Double(dword) => Some(dword.trailing_zeros() as usize),
             Large(buffer) => Some(UBig::trailing_zeros_large(buffer)),
         }
     }
 
-    fn trailing_zeros_large(words: &[Word]) -> usize {
+    /// Returns the number of trailing zeros of a number known to be nonzero.
+    ///
+    /// Same as `self.trailing_zeros().unwrap()` for nonzero numbers, without the [Option].
+    ///
+    /// For 0 the result is unspecified but safe: it panics in debug builds and returns 0 in
+    /// release builds. Use [UBig::trailing_zeros] when the number may be 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// assert_eq!(ubig!(17).trailing_zeros_nonzero(), 0);
+    /// assert_eq!(ubig!(48).trailing_zeros_nonzero(), 4);
+    /// ```
+    #[inline]
+    pub fn trailing_zeros_nonzero(&self) -> usize {
+        debug_assert!(*self != UBig::from_word(0), "trailing_zeros_nonzero(0)");
+        self.trailing_zeros().unwrap_or(0)
+    }
+
+    /// Split into an odd part and a power of two.
+    ///
+    /// Returns `(d, s)` such that `self == d * 2^s` and `d` is odd.
+    ///
+    /// For 0, it returns `(0, 0)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// assert_eq!(ubig!(48).remove_factor_two(), (ubig!(3), 4));
+    /// assert_eq!(ubig!(17).remove_factor_two(), (ubig!(17), 0));
+    /// assert_eq!(ubig!(0).remove_factor_two(), (ubig!(0), 0));
+    /// ```
+    #[inline]
+    pub fn remove_factor_two(&self) -> (UBig, usize) {
+        match self.trailing_zeros() {
+            None => (UBig::from_word(0), 0),
+            Some(zeros) => (self >> zeros, zeros),
+        }
+    }
+
+    pub(crate) fn trailing_zeros_large(words: &[Word]) -> usize {
         debug_assert!(*words.last().unwrap() != 0);
 
         for (idx, word) in words.iter().enumerate() {
@@ -178,12 +287,54 @@ impl UBig {
     pub fn bit_len(&self) -> usize {
         match self.repr() {
             Small(word) => math::bit_len(*word) as usize,
+            Double(dword) => 2 * WORD_BITS_USIZE - dword.leading_zeros() as usize,
             Large(buffer) => {
                 buffer.len() * WORD_BITS_USIZE - buffer.last().unwrap().leading_zeros() as usize
             }
         }
     }
 
+    /// Floor of the base 2 logarithm: `bit_len - 1`.
+    ///
+    /// Returns `None` for 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// assert_eq!(ubig!(0).floor_log2(), None);
+    /// assert_eq!(ubig!(8).floor_log2(), Some(3));
+    /// assert_eq!(ubig!(9).floor_log2(), Some(3));
+    /// ```
+    #[inline]
+    pub fn floor_log2(&self) -> Option<usize> {
+        self.bit_len().checked_sub(1)
+    }
+
+    /// Ceiling of the base 2 logarithm.
+    ///
+    /// This is the number of bits needed to represent `self` distinct values: `bit_len - 1` for
+    /// powers of 2 and `bit_len` otherwise. Returns `None` for 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// assert_eq!(ubig!(0).ceil_log2(), None);
+    /// assert_eq!(ubig!(1).ceil_log2(), Some(0));
+    /// assert_eq!(ubig!(8).ceil_log2(), Some(3));
+    /// assert_eq!(ubig!(9).ceil_log2(), Some(4));
+    /// ```
+    #[inline]
+    pub fn ceil_log2(&self) -> Option<usize> {
+        let floor = self.floor_log2()?;
+        if self.is_power_of_two() {
+            Some(floor)
+        } else {
+            Some(floor + 1)
+        }
+    }
+
     /// True if the number is a power of 2.
     ///
     /// # Examples
@@ -198,15 +349,162 @@ impl UBig {
     pub fn is_power_of_two(&self) -> bool {
         match self.repr() {
             Small(word) => word.is_power_of_two(),
+            Double(dword) => dword.is_power_of_two(),
             Large(buffer) => UBig::is_power_of_two_large(buffer),
         }
     }
 
-    fn is_power_of_two_large(words: &[Word]) -> bool {
+    pub(crate) fn is_power_of_two_large(words: &[Word]) -> bool {
         debug_assert!(*words.last().unwrap() != 0);
 
         words[..words.len() - 1].iter().all(|x| *x == 0) && words.last().unwrap().is_power_of_two()
     }
+
+    /// Reverse the order of the low `width` bits. Higher bits are discarded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// assert_eq!(ubig!(0b1101).reverse_bits(4), ubig!(0b1011));
+    /// assert_eq!(ubig!(0b1101).reverse_bits(6), ubig!(0b101100));
+    /// assert_eq!(ubig!(0b1101).reverse_bits(2), ubig!(0b10));
+    /// ```
+    pub fn reverse_bits(&self, width: usize) -> UBig {
+        let bit_len = self.bit_len();
+        if width > bit_len {
+            return self.reverse_bits(bit_len) << (width - bit_len);
+        }
+        if width == 0 {
+            return UBig::from_word(0);
+        }
+        let words = self.as_words();
+        if width <= WORD_BITS_USIZE {
+            return UBig::from_word(words[0].reverse_bits() >> (WORD_BITS_USIZE - width));
+        }
+        let n = math::ceil_div_usize(width, WORD_BITS_USIZE);
+        let mut buffer = Buffer::allocate(n);
+        buffer.extend(words[..n].iter().rev().map(|word| word.reverse_bits()));
+        UBig::from(buffer) >> (n * WORD_BITS_USIZE - width)
+    }
+
+    /// A number with the low `n` bits set: `2^n - 1`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::{ubig, UBig};
+    /// assert_eq!(UBig::ones(0), ubig!(0));
+    /// assert_eq!(UBig::ones(5), ubig!(0b11111));
+    /// assert_eq!(UBig::ones(100), (ubig!(1) << 100) - ubig!(1));
+    /// ```
+    pub fn ones(n: usize) -> UBig {
+        if n <= WORD_BITS_USIZE {
+            return UBig::from_word(math::ones_word(n as u32));
+        }
+        let len = math::ceil_div_usize(n, WORD_BITS_USIZE);
+        let mut buffer = Buffer::allocate(len);
+        buffer.extend((0..len).map(|_| Word::MAX));
+        let top_bits = n % WORD_BITS_USIZE;
+        if top_bits != 0 {
+            buffer[len - 1] = math::ones_word(top_bits as u32);
+        }
+        buffer.into()
+    }
+
+    /// Bitwise NOT of the low `width` bits: `(2^width - 1) ^ self`, truncated to `width` bits.
+    ///
+    /// Bits at positions `width` and above in `self` are ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// assert_eq!(ubig!(0b1101).not_in_width(4), ubig!(0b0010));
+    /// assert_eq!(ubig!(0b1101).not_in_width(6), ubig!(0b110010));
+    /// assert_eq!(ubig!(0b1101).not_in_width(2), ubig!(0b10));
+    /// ```
+    pub fn not_in_width(&self, width: usize) -> UBig {
+        let words = self.as_words();
+        if width <= WORD_BITS_USIZE {
+            return UBig::from_word(
+                !words.first().copied().unwrap_or(0) & math::ones_word(width as u32),
+            );
+        }
+        let n = math::ceil_div_usize(width, WORD_BITS_USIZE);
+        let mut buffer = Buffer::allocate(n);
+        buffer.extend((0..n).map(|i| !words.get(i).copied().unwrap_or(0)));
+        let top_bits = width % WORD_BITS_USIZE;
+        if top_bits != 0 {
+            buffer[n - 1] &= math::ones_word(top_bits as u32);
+        }
+        buffer.into()
+    }
+
+    /// Iterate over `bits`-wide chunks of the number, least significant first.
+    ///
+    /// Each chunk is the value of its bits, so chunk `i` is `(self >> (i * bits)) mod 2^bits`.
+    /// The last chunk is the one containing the most significant set bit, so 0 has no chunks.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bits` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// let chunks: Vec<_> = ubig!(0x12345).chunks(8).collect();
+    /// assert_eq!(chunks, [ubig!(0x45), ubig!(0x23), ubig!(0x1)]);
+    /// assert_eq!(ubig!(0).chunks(8).next(), None);
+    /// ```
+    pub fn chunks(&self, bits: usize) -> impl ExactSizeIterator<Item = UBig> + '_ {
+        assert!(bits != 0, "chunk width must be positive");
+        let words = self.as_words();
+        let count = math::ceil_div_usize(self.bit_len(), bits);
+        (0..count).map(move |i| UBig::extract_bits(&words, i * bits, bits))
+    }
+
+    /// Bits `start..start + len` of a number given by `words`.
+    fn extract_bits(words: &[Word], start: usize, len: usize) -> UBig {
+        let start_idx = start / WORD_BITS_USIZE;
+        let shift = start % WORD_BITS_USIZE;
+        if start_idx >= words.len() {
+            return UBig::from_word(0);
+        }
+        if len <= WORD_BITS_USIZE {
+            let mut word = words[start_idx] >> shift;
+            if shift != 0 && start_idx + 1 < words.len() {
+                word |= words[start_idx + 1] << (WORD_BITS_USIZE - shift);
+            }
+            return UBig::from_word(word & math::ones_word(len as u32));
+        }
+        let end_idx = math::ceil_div_usize(start + len, WORD_BITS_USIZE).min(words.len());
+        let mut buffer = Buffer::allocate(end_idx - start_idx);
+        buffer.extend(&words[start_idx..end_idx]);
+        (UBig::from(buffer) >> shift).truncate_bits(len)
+    }
+
+    /// The low `width` bits: `self mod 2^width`.
+    pub(crate) fn truncate_bits(&self, width: usize) -> UBig {
+        let words = self.as_words();
+        if width <= WORD_BITS_USIZE {
+            return UBig::from_word(
+                words.first().copied().unwrap_or(0) & math::ones_word(width as u32),
+            );
+        }
+        let n = math::ceil_div_usize(width, WORD_BITS_USIZE);
+        if n > words.len() {
+            return self.clone();
+        }
+        let mut buffer = Buffer::allocate(n);
+        buffer.extend(&words[..n]);
+        let top_bits = width % WORD_BITS_USIZE;
+        if top_bits != 0 {
+            buffer[n - 1] &= math::ones_word(top_bits as u32);
+        }
+        buffer.into()
+    }
 }
 
 impl IBig {
@@ -229,6 +527,81 @@ impl IBig {
     pub fn trailing_zeros(&self) -> Option<usize> {
         self.magnitude().trailing_zeros()
     }
+
+    /// Bit length of the magnitude.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ibig;
+    /// assert_eq!(ibig!(17).bit_len(), 5);
+    /// assert_eq!(ibig!(-17).bit_len(), 5);
+    /// assert_eq!(ibig!(0).bit_len(), 0);
+    /// ```
+    #[inline]
+    pub fn bit_len(&self) -> usize {
+        self.magnitude().bit_len()
+    }
+
+    /// Number of bits needed to store the number in two's complement, including the sign bit.
+    ///
+    /// This is the smallest `n` such that `-2^(n-1) <= self < 2^(n-1)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ibig;
+    /// assert_eq!(ibig!(0).signed_bits(), 1);
+    /// assert_eq!(ibig!(-1).signed_bits(), 1);
+    /// assert_eq!(ibig!(127).signed_bits(), 8);
+    /// assert_eq!(ibig!(128).signed_bits(), 9);
+    /// assert_eq!(ibig!(-128).signed_bits(), 8);
+    /// assert_eq!(ibig!(-129).signed_bits(), 9);
+    /// ```
+    #[inline]
+    pub fn signed_bits(&self) -> usize {
+        let mag = self.magnitude();
+        match self.sign() {
+            // Negative numbers need as many bits as !self = |self| - 1.
+            Negative if mag.is_power_of_two() => mag.bit_len(),
+            _ => mag.bit_len() + 1,
+        }
+    }
+}
+
+/// A read-only view of the bits of a [UBig], least significant first.
+///
+/// Created by [UBig::view_bits]. Bit `i` has value `2^i`, as in [UBig::bit].
+#[derive(Clone, Copy)]
+pub struct BitView<'a> {
+    words: Words<'a>,
+    len: usize,
+}
+
+impl BitView<'_> {
+    /// The number of bits up to the most significant set bit: [UBig::bit_len].
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// True if no bits are set, i.e. the number is 0.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The `i`-th bit. Bits at positions `len()` and above are all `false`.
+    #[inline]
+    pub fn get(&self, i: usize) -> bool {
+        i < self.len && self.words[i / WORD_BITS_USIZE] & 1 << (i % WORD_BITS_USIZE) != 0
+    }
+
+    /// Iterate over the `len()` bits, least significant first.
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = bool> + '_ {
+        (0..self.len)
+            .map(move |i| self.words[i / WORD_BITS_USIZE] & 1 << (i % WORD_BITS_USIZE) != 0)
+    }
 }
 
 impl NextPowerOfTwo for UBig {
@@ -239,7 +612,11 @@ impl NextPowerOfTwo for UBig {
         match self.into_repr() {
             Small(word) => match word.checked_next_power_of_two() {
                 Some(p) => UBig::from_word(p),
-                None => UBig::from(double_word(0, 1)),
+                None => UBig::from_double_word(double_word(0, 1)),
+            },
+            Double(dword) => match dword.checked_next_power_of_two() {
+                Some(p) => UBig::from_double_word(p),
+                None => UBig::next_power_of_two_large(Buffer::from_double_word(dword)),
             },
             Large(buffer) => UBig::next_power_of_two_large(buffer),
         }
@@ -299,6 +676,15 @@ impl BitAnd<UBig> for UBig {
             (Small(word0), Small(word1)) => UBig::from_word(word0 & word1),
             (Small(word0), Large(buffer1)) => UBig::from_word(word0 & buffer1.first().unwrap()),
             (Large(buffer0), Small(word1)) => UBig::from_word(buffer0.first().unwrap() & word1),
+            (Small(word0), Double(dword1)) => UBig::from_word(word0 & dword1 as Word),
+            (Double(dword0), Small(word1)) => UBig::from_word(dword0 as Word & word1),
+            (Double(dword0), Double(dword1)) => UBig::from_double_word(dword0 & dword1),
+            (Double(dword0), Large(buffer1)) => {
+                UBig::from_double_word(dword0 & double_word(buffer1[0], buffer1[1]))
+            }
+            (Large(buffer0), Double(dword1)) => {
+                UBig::from_double_word(double_word(buffer0[0], buffer0[1]) & dword1)
+            }
             (Large(buffer0), Large(buffer1)) => {
                 if buffer0.len() <= buffer1.len() {
                     UBig::bitand_large(buffer0, &buffer1)
@@ -319,6 +705,15 @@ impl BitAnd<&UBig> for UBig {
             (Small(word0), Small(word1)) => UBig::from_word(word0 & word1),
             (Small(word0), Large(buffer1)) => UBig::from_word(word0 & buffer1.first().unwrap()),
             (Large(buffer0), Small(word1)) => UBig::from_word(buffer0.first().unwrap() & word1),
+            (Small(word0), Double(dword1)) => UBig::from_word(word0 & *dword1 as Word),
+            (Double(dword0), Small(word1)) => UBig::from_word(dword0 as Word & *word1),
+            (Double(dword0), Double(dword1)) => UBig::from_double_word(dword0 & *dword1),
+            (Double(dword0), Large(buffer1)) => {
+                UBig::from_double_word(dword0 & double_word(buffer1[0], buffer1[1]))
+            }
+            (Large(buffer0), Double(dword1)) => {
+                UBig::from_double_word(double_word(buffer0[0], buffer0[1]) & *dword1)
+            }
             (Large(buffer0), Large(buffer1)) => UBig::bitand_large(buffer0, buffer1),
         }
     }
@@ -342,6 +737,15 @@ impl BitAnd<&UBig> for &UBig {
             (Small(word0), Small(word1)) => UBig::from_word(word0 & word1),
             (Small(word0), Large(buffer1)) => UBig::from_word(word0 & buffer1.first().unwrap()),
             (Large(buffer0), Small(word1)) => UBig::from_word(buffer0.first().unwrap() & word1),
+            (Small(word0), Double(dword1)) => UBig::from_word(*word0 & *dword1 as Word),
+            (Double(dword0), Small(word1)) => UBig::from_word(*dword0 as Word & *word1),
+            (Double(dword0), Double(dword1)) => UBig::from_double_word(*dword0 & *dword1),
+            (Double(dword0), Large(buffer1)) => {
+                UBig::from_double_word(*dword0 & double_word(buffer1[0], buffer1[1]))
+            }
+            (Large(buffer0), Double(dword1)) => {
+                UBig::from_double_word(double_word(buffer0[0], buffer0[1]) & *dword1)
+            }
             (Large(buffer0), Large(buffer1)) => {
                 if buffer0.len() <= buffer1.len() {
                     UBig::bitand_large(buffer0.clone(), buffer1)
@@ -388,6 +792,17 @@ impl BitOr<UBig> for UBig {
             (Small(word0), Small(word1)) => UBig::from_word(word0 | word1),
             (Small(word0), Large(buffer1)) => UBig::bitor_large_word(buffer1, word0),
             (Large(buffer0), Small(word1)) => UBig::bitor_large_word(buffer0, word1),
+            (Small(word0), Double(dword1)) => UBig::from_double_word(extend_word(word0) | dword1),
+            (Double(dword0), Small(word1)) => UBig::from_double_word(dword0 | extend_word(word1)),
+            (Double(dword0), Double(dword1)) => UBig::from_double_word(dword0 | dword1),
+            (Double(dword0), Large(buffer1)) => {
+                let (lo, hi) = split_double_word(dword0);
+                UBig::bitor_large(buffer1, &[lo, hi])
+            }
+            (Large(buffer0), Double(dword1)) => {
+                let (lo, hi) = split_double_word(dword1);
+                UBig::bitor_large(buffer0, &[lo, hi])
+            }
             (Large(buffer0), Large(buffer1)) => {
                 if buffer0.len() >= buffer1.len() {
                     UBig::bitor_large(buffer0, &buffer1)
@@ -408,6 +823,17 @@ impl BitOr<&UBig> for UBig {
             (Small(word0), Small(word1)) => UBig::from_word(word0 | word1),
             (Small(word0), Large(buffer1)) => UBig::bitor_large_word(buffer1.clone(), word0),
             (Large(buffer0), Small(word1)) => UBig::bitor_large_word(buffer0, *word1),
+            (Small(word0), Double(dword1)) => UBig::from_double_word(extend_word(word0) | *dword1),
+            (Double(dword0), Small(word1)) => UBig::from_double_word(dword0 | extend_word(*word1)),
+            (Double(dword0), Double(dword1)) => UBig::from_double_word(dword0 | *dword1),
+            (Double(dword0), Large(buffer1)) => {
+                let (lo, hi) = split_double_word(dword0);
+                UBig::bitor_large(buffer1.clone(), &[lo, hi])
+            }
+            (Large(buffer0), Double(dword1)) => {
+                let (lo, hi) = split_double_word(*dword1);
+                UBig::bitor_large(buffer0, &[lo, hi])
+            }
             (Large(buffer0), Large(buffer1)) => UBig::bitor_large(buffer0, buffer1),
         }
     }
@@ -431,6 +857,17 @@ impl BitOr<&UBig> for &UBig {
             (Small(word0), Small(word1)) => UBig::from_word(word0 | word1),
             (Small(word0), Large(buffer1)) => UBig::bitor_large_word(buffer1.clone(), *word0),
             (Large(buffer0), Small(word1)) => UBig::bitor_large_word(buffer0.clone(), *word1),
+            (Small(word0), Double(dword1)) => UBig::from_double_word(extend_word(*word0) | *dword1),
+            (Double(dword0), Small(word1)) => UBig::from_double_word(*dword0 | extend_word(*word1)),
+            (Double(dword0), Double(dword1)) => UBig::from_double_word(*dword0 | *dword1),
+            (Double(dword0), Large(buffer1)) => {
+                let (lo, hi) = split_double_word(*dword0);
+                UBig::bitor_large(buffer1.clone(), &[lo, hi])
+            }
+            (Large(buffer0), Double(dword1)) => {
+                let (lo, hi) = split_double_word(*dword1);
+                UBig::bitor_large(buffer0.clone(), &[lo, hi])
+            }
             (Large(buffer0), Large(buffer1)) => {
                 if buffer0.len() >= buffer1.len() {
                     UBig::bitor_large(buffer0.clone(), buffer1)
@@ -458,7 +895,7 @@ impl BitOrAssign<&UBig> for UBig {
 
 impl UBig {
     fn bitor_large_word(mut buffer: Buffer, rhs: Word) -> UBig {
-        debug_assert!(buffer.len() >= 2);
+        debug_assert!(buffer.len() >= 3);
 
         *buffer.first_mut().unwrap() |= rhs;
         buffer.into()
@@ -485,6 +922,17 @@ impl BitXor<UBig> for UBig {
             (Small(word0), Small(word1)) => UBig::from_word(word0 ^ word1),
             (Small(word0), Large(buffer1)) => UBig::bitxor_large_word(buffer1, word0),
             (Large(buffer0), Small(word1)) => UBig::bitxor_large_word(buffer0, word1),
+            (Small(word0), Double(dword1)) => UBig::from_double_word(extend_word(word0) ^ dword1),
+            (Double(dword0), Small(word1)) => UBig::from_double_word(dword0 ^ extend_word(word1)),
+            (Double(dword0), Double(dword1)) => UBig::from_double_word(dword0 ^ dword1),
+            (Double(dword0), Large(buffer1)) => {
+                let (lo, hi) = split_double_word(dword0);
+                UBig::bitxor_large(buffer1, &[lo, hi])
+            }
+            (Large(buffer0), Double(dword1)) => {
+                let (lo, hi) = split_double_word(dword1);
+                UBig::bitxor_large(buffer0, &[lo, hi])
+            }
             (Large(buffer0), Large(buffer1)) => {
                 if buffer0.len() >= buffer1.len() {
                     UBig::bitxor_large(buffer0, &buffer1)
@@ -505,6 +953,17 @@ impl BitXor<&UBig> for UBig {
             (Small(word0), Small(word1)) => UBig::from_word(word0 ^ word1),
             (Small(word0), Large(buffer1)) => UBig::bitxor_large_word(buffer1.clone(), word0),
             (Large(buffer0), Small(word1)) => UBig::bitxor_large_word(buffer0, *word1),
+            (Small(word0), Double(dword1)) => UBig::from_double_word(extend_word(word0) ^ *dword1),
+            (Double(dword0), Small(word1)) => UBig::from_double_word(dword0 ^ extend_word(*word1)),
+            (Double(dword0), Double(dword1)) => UBig::from_double_word(dword0 ^ *dword1),
+            (Double(dword0), Large(buffer1)) => {
+                let (lo, hi) = split_double_word(dword0);
+                UBig::bitxor_large(buffer1.clone(), &[lo, hi])
+            }
+            (Large(buffer0), Double(dword1)) => {
+                let (lo, hi) = split_double_word(*dword1);
+                UBig::bitxor_large(buffer0, &[lo, hi])
+            }
             (Large(buffer0), Large(buffer1)) => UBig::bitxor_large(buffer0, buffer1),
         }
     }
@@ -528,6 +987,17 @@ impl BitXor<&UBig> for &UBig {
             (Small(word0), Small(word1)) => UBig::from_word(word0 ^ word1),
             (Small(word0), Large(buffer1)) => UBig::bitxor_large_word(buffer1.clone(), *word0),
             (Large(buffer0), Small(word1)) => UBig::bitxor_large_word(buffer0.clone(), *word1),
+            (Small(word0), Double(dword1)) => UBig::from_double_word(extend_word(*word0) ^ *dword1),
+            (Double(dword0), Small(word1)) => UBig::from_double_word(*dword0 ^ extend_word(*word1)),
+            (Double(dword0), Double(dword1)) => UBig::from_double_word(*dword0 ^ *dword1),
+            (Double(dword0), Large(buffer1)) => {
+                let (lo, hi) = split_double_word(*dword0);
+                UBig::bitxor_large(buffer1.clone(), &[lo, hi])
+            }
+            (Large(buffer0), Double(dword1)) => {
+                let (lo, hi) = split_double_word(*dword1);
+                UBig::bitxor_large(buffer0.clone(), &[lo, hi])
+            }
             (Large(buffer0), Large(buffer1)) => {
                 if buffer0.len() >= buffer1.len() {
                     UBig::bitxor_large(buffer0.clone(), buffer1)
@@ -555,7 +1025,7 @@ impl BitXorAssign<&UBig> for UBig {
 
 impl UBig {
     fn bitxor_large_word(mut buffer: Buffer, rhs: Word) -> UBig {
-        debug_assert!(buffer.len() >= 2);
+        debug_assert!(buffer.len() >= 3);
 
         *buffer.first_mut().unwrap() ^= rhs;
         buffer.into()
@@ -582,6 +1052,16 @@ impl AndNot<UBig> for UBig {
             (Small(word0), Small(word1)) => UBig::from_word(word0 & !word1),
             (Small(word0), Large(buffer1)) => UBig::from_word(word0 & !buffer1.first().unwrap()),
             (Large(buffer0), Small(word1)) => UBig::and_not_large_word(buffer0, word1),
+            (Small(word0), Double(dword1)) => UBig::from_word(word0 & !(dword1 as Word)),
+            (Double(dword0), Small(word1)) => UBig::from_double_word(dword0 & !extend_word(word1)),
+            (Double(dword0), Double(dword1)) => UBig::from_double_word(dword0 & !dword1),
+            (Double(dword0), Large(buffer1)) => {
+                UBig::from_double_word(dword0 & !double_word(buffer1[0], buffer1[1]))
+            }
+            (Large(buffer0), Double(dword1)) => {
+                let (lo, hi) = split_double_word(dword1);
+                UBig::and_not_large(buffer0, &[lo, hi])
+            }
             (Large(buffer0), Large(buffer1)) => UBig::and_not_large(buffer0, &buffer1),
         }
     }
@@ -596,6 +1076,16 @@ impl AndNot<&UBig> for UBig {
             (Small(word0), Small(word1)) => UBig::from_word(word0 & !word1),
             (Small(word0), Large(buffer1)) => UBig::from_word(word0 & !buffer1.first().unwrap()),
             (Large(buffer0), Small(word1)) => UBig::and_not_large_word(buffer0, *word1),
+            (Small(word0), Double(dword1)) => UBig::from_word(word0 & !(*dword1 as Word)),
+            (Double(dword0), Small(word1)) => UBig::from_double_word(dword0 & !extend_word(*word1)),
+            (Double(dword0), Double(dword1)) => UBig::from_double_word(dword0 & !*dword1),
+            (Double(dword0), Large(buffer1)) => {
+                UBig::from_double_word(dword0 & !double_word(buffer1[0], buffer1[1]))
+            }
+            (Large(buffer0), Double(dword1)) => {
+                let (lo, hi) = split_double_word(*dword1);
+                UBig::and_not_large(buffer0, &[lo, hi])
+            }
             (Large(buffer0), Large(buffer1)) => UBig::and_not_large(buffer0, buffer1),
         }
     }
@@ -611,6 +1101,16 @@ impl AndNot<UBig> for &UBig {
             (Small(word0), Large(buffer1)) => UBig::from_word(word0 & !buffer1.first().unwrap()),
             (Large(buffer0), Small(word1)) => UBig::and_not_large_word(buffer0.clone(), word1),
             // TODO: Could reuse buffer1 in some cases.
+            (Small(word0), Double(dword1)) => UBig::from_word(*word0 & !(dword1 as Word)),
+            (Double(dword0), Small(word1)) => UBig::from_double_word(*dword0 & !extend_word(word1)),
+            (Double(dword0), Double(dword1)) => UBig::from_double_word(*dword0 & !dword1),
+            (Double(dword0), Large(buffer1)) => {
+                UBig::from_double_word(*dword0 & !double_word(buffer1[0], buffer1[1]))
+            }
+            (Large(buffer0), Double(dword1)) => {
+                let (lo, hi) = split_double_word(dword1);
+                UBig::and_not_large(buffer0.clone(), &[lo, hi])
+            }
             (Large(buffer0), Large(buffer1)) => UBig::and_not_large(buffer0.clone(), &buffer1),
         }
     }
@@ -625,6 +1125,18 @@ impl AndNot<&UBig> for &UBig {
             (Small(word0), Small(word1)) => UBig::from_word(word0 & !word1),
             (Small(word0), Large(buffer1)) => UBig::from_word(word0 & !buffer1.first().unwrap()),
             (Large(buffer0), Small(word1)) => UBig::and_not_large_word(buffer0.clone(), *word1),
+            (Small(word0), Double(dword1)) => UBig::from_word(*word0 & !(*dword1 as Word)),
+            (Double(dword0), Small(word1)) => {
+                UBig::from_double_word(*dword0 & !extend_word(*word1))
+            }
+            (Double(dword0), Double(dword1)) => UBig::from_double_word(*dword0 & !*dword1),
+            (Double(dword0), Large(buffer1)) => {
+                UBig::from_double_word(*dword0 & !double_word(buffer1[0], buffer1[1]))
+            }
+            (Large(buffer0), Double(dword1)) => {
+                let (lo, hi) = split_double_word(*dword1);
+                UBig::and_not_large(buffer0.clone(), &[lo, hi])
+            }
             (Large(buffer0), Large(buffer1)) => UBig::and_not_large(buffer0.clone(), buffer1),
         }
     }
@@ -632,7 +1144,7 @@ impl AndNot<&UBig> for &UBig {
 
 impl UBig {
     fn and_not_large_word(mut buffer: Buffer, rhs: Word) -> UBig {
-        debug_assert!(buffer.len() >= 2);
+        debug_assert!(buffer.len() >= 3);
 
         *buffer.first_mut().unwrap() &= !rhs;
         buffer.into()
@@ -923,29 +1435,6 @@ impl AndNot<&IBig> for &IBig {
     }
 }
 
-impl UBig {
-    /// low n bits or'd
-    #[inline]
-    pub(crate) fn are_low_bits_nonzero(&self, n: usize) -> bool {
-        match self.repr() {
-            Small(word) => {
-                let n = n.min(WORD_BITS_USIZE) as u32;
-                word & math::ones::<Word>(n) != 0
-            }
-            Large(buffer) => {
-                let n_words = n / WORD_BITS_USIZE;
-                if n_words >= buffer.len() {
-                    true
-                } else {
-                    let n_top = (n % WORD_BITS_USIZE) as u32;
-                    buffer[..n_words].iter().any(|x| *x != 0)
-                        || buffer[n_words] & math::ones::<Word>(n_top) != 0
-                }
-            }
-        }
-    }
-}
-
 macro_rules! impl_bit_ops_ubig_unsigned {
     ($t:ty) => {
         impl BitAnd<$t> for UBig {
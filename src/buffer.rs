@@ -1,6 +1,10 @@
 //! Word buffer.
 
-use crate::{arch::word::Word, ubig::UBig};
+use crate::{
+    arch::word::{DoubleWord, Word},
+    primitive::split_double_word,
+    ubig::UBig,
+};
 
 use alloc::vec::Vec;
 use core::{
@@ -28,6 +32,21 @@ impl Buffer {
         Buffer(Vec::with_capacity(Buffer::default_capacity(num_words)))
     }
 
+    /// Converts into the underlying `Vec`.
+    #[inline]
+    pub(crate) fn into_vec(self) -> Vec<Word> {
+        self.0
+    }
+
+    /// Creates a `Buffer` containing the two words of a `DoubleWord`.
+    pub(crate) fn from_double_word(dw: DoubleWord) -> Buffer {
+        let (lo, hi) = split_double_word(dw);
+        let mut buffer = Buffer::allocate(2);
+        buffer.push(lo);
+        buffer.push(hi);
+        buffer
+    }
+
     /// Ensure there is enough capacity in the buffer for `num_words`. Will reallocate if there is
     /// not enough.
     #[inline]
@@ -3,18 +3,107 @@
 use crate::{
     arch::word::Word,
     ibig::IBig,
+    primitive::WORD_BITS_USIZE,
     sign::Sign::*,
     ubig::{Repr::*, UBig},
 };
 use core::cmp::Ordering;
 
+impl UBig {
+    /// Check whether the value is 0.
+    ///
+    /// Comparisons with small constants such as `x > ubig!(0)` are equally cheap: they only look
+    /// at the representation of `x` and never allocate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// assert!(ubig!(0).is_zero());
+    /// assert!(!ubig!(_0x100000000000000000000000000000000).is_zero());
+    /// ```
+    #[inline]
+    pub fn is_zero(&self) -> bool {
+        matches!(self.repr(), Small(0))
+    }
+
+    /// Check whether the value is 1.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// assert!(ubig!(1).is_one());
+    /// assert!(!ubig!(0).is_one());
+    /// ```
+    #[inline]
+    pub fn is_one(&self) -> bool {
+        matches!(self.repr(), Small(1))
+    }
+}
+
+impl IBig {
+    /// Check whether the value is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ibig;
+    /// assert!(ibig!(0).is_zero());
+    /// assert!(!ibig!(-1).is_zero());
+    /// ```
+    #[inline]
+    pub fn is_zero(&self) -> bool {
+        self.magnitude().is_zero()
+    }
+
+    /// Check whether the value is 1.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ibig;
+    /// assert!(ibig!(1).is_one());
+    /// assert!(!ibig!(-1).is_one());
+    /// ```
+    #[inline]
+    pub fn is_one(&self) -> bool {
+        self.sign() == Positive && self.magnitude().is_one()
+    }
+
+    /// Compare absolute values: `|self|` with `|other|`.
+    ///
+    /// Same as `self.magnitude().cmp(other.magnitude())`, without cloning.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use core::cmp::Ordering;
+    /// # use ibig::ibig;
+    /// assert_eq!(ibig!(-5).cmp_magnitude(&ibig!(3)), Ordering::Greater);
+    /// assert_eq!(ibig!(-5).cmp_magnitude(&ibig!(5)), Ordering::Equal);
+    /// assert_eq!(ibig!(2).cmp_magnitude(&ibig!(-3)), Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn cmp_magnitude(&self, other: &IBig) -> Ordering {
+        self.magnitude().cmp(other.magnitude())
+    }
+}
+
+/// Numeric order.
+///
+/// Numbers of different lengths in words are ordered by length alone, in O(1) time. Numbers of
+/// the same length are compared word by word starting from the most significant word, in O(len)
+/// time in the worst case. This makes [UBig] a cheap key for ordered collections such as
+/// `BTreeMap`.
 impl Ord for UBig {
     #[inline]
     fn cmp(&self, other: &UBig) -> Ordering {
         match (self.repr(), other.repr()) {
             (Small(word), Small(other_word)) => word.cmp(other_word),
-            (Small(_), Large(_)) => Ordering::Less,
-            (Large(_), Small(_)) => Ordering::Greater,
+            (Small(_), Double(_) | Large(_)) | (Double(_), Large(_)) => Ordering::Less,
+            (Double(_) | Large(_), Small(_)) | (Large(_), Double(_)) => Ordering::Greater,
+            (Double(dword), Double(other_dword)) => dword.cmp(other_dword),
             (Large(buffer), Large(other_buffer)) => buffer
                 .len()
                 .cmp(&other_buffer.len())
@@ -30,6 +119,10 @@ impl PartialOrd for UBig {
     }
 }
 
+/// Numeric order.
+///
+/// Numbers of different signs are ordered in O(1) time. Otherwise the magnitudes are compared
+/// with the same complexity as for [UBig].
 impl Ord for IBig {
     #[inline]
     fn cmp(&self, other: &IBig) -> Ordering {
@@ -49,6 +142,36 @@ impl PartialOrd for IBig {
     }
 }
 
+macro_rules! impl_ubig_eq_unsigned {
+    ($t:ty) => {
+        /// Compares the representation directly, without converting the primitive to [UBig].
+        ///
+        /// Only `UBig == primitive` is provided. The reverse would make comparisons between
+        /// primitives, such as `vec_of_u64 == []`, ambiguous for type inference.
+        impl PartialEq<$t> for UBig {
+            #[inline]
+            #[allow(clippy::unnecessary_cast)]
+            fn eq(&self, other: &$t) -> bool {
+                match self.repr() {
+                    Small(word) => *word as u128 == *other as u128,
+                    Double(dword) => *dword as u128 == *other as u128,
+                    Large(buffer) => {
+                        buffer.len() * WORD_BITS_USIZE <= <$t>::BITS as usize
+                            && self.try_to_unsigned::<$t>() == Ok(*other)
+                    }
+                }
+            }
+        }
+    };
+}
+
+impl_ubig_eq_unsigned!(u8);
+impl_ubig_eq_unsigned!(u16);
+impl_ubig_eq_unsigned!(u32);
+impl_ubig_eq_unsigned!(u64);
+impl_ubig_eq_unsigned!(u128);
+impl_ubig_eq_unsigned!(usize);
+
 /// Compare lhs with rhs as numbers.
 pub(crate) fn cmp_same_len(lhs: &[Word], rhs: &[Word]) -> Ordering {
     assert!(lhs.len() == rhs.len());
@@ -6,7 +6,7 @@ use crate::{
     error::OutOfBoundsError,
     ibig::IBig,
     primitive::{self, PrimitiveSigned, PrimitiveUnsigned, WORD_BITS, WORD_BYTES},
-    sign::Sign::*,
+    sign::Sign::{self, *},
     ubig::{Repr::*, UBig},
 };
 use alloc::vec::Vec;
@@ -106,6 +106,11 @@ impl UBig {
                 let skip_bytes = x.leading_zeros() as usize / 8;
                 bytes[..WORD_BYTES - skip_bytes].to_vec()
             }
+            Double(x) => {
+                let bytes = x.to_le_bytes();
+                let skip_bytes = x.leading_zeros() as usize / 8;
+                bytes[..2 * WORD_BYTES - skip_bytes].to_vec()
+            }
             Large(buffer) => {
                 let n = buffer.len();
                 let last = buffer[n - 1];
@@ -121,6 +126,24 @@ impl UBig {
         }
     }
 
+    /// Iterate over little-endian bytes without allocating.
+    ///
+    /// Yields the same bytes as [to_le_bytes](UBig::to_le_bytes): nothing for 0, otherwise up to
+    /// the most significant non-zero byte.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// assert_eq!(ubig!(0).le_bytes_iter().next(), None);
+    /// assert!(ubig!(0x010203).le_bytes_iter().eq([3, 2, 1]));
+    /// ```
+    pub fn le_bytes_iter(&self) -> impl ExactSizeIterator<Item = u8> + '_ {
+        let words = self.as_words();
+        let len = (self.bit_len() + 7) / 8;
+        (0..len).map(move |i| (words[i / WORD_BYTES] >> (i % WORD_BYTES * 8)) as u8)
+    }
+
     /// Return big-endian bytes.
     ///
     /// # Examples
@@ -137,6 +160,11 @@ impl UBig {
                 let skip_bytes = x.leading_zeros() as usize / 8;
                 bytes[skip_bytes..].to_vec()
             }
+            Double(x) => {
+                let bytes = x.to_be_bytes();
+                let skip_bytes = x.leading_zeros() as usize / 8;
+                bytes[skip_bytes..].to_vec()
+            }
             Large(buffer) => {
                 let n = buffer.len();
                 let last = buffer[n - 1];
@@ -152,6 +180,97 @@ impl UBig {
         }
     }
 
+    /// Return little-endian bytes, padded with zeros to exactly `len` bytes.
+    ///
+    /// Returns [OutOfBoundsError] if the number doesn't fit in `len` bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::{error::OutOfBoundsError, ubig};
+    /// assert_eq!(ubig!(0x010203).to_le_bytes_padded(5), Ok(vec![3, 2, 1, 0, 0]));
+    /// assert_eq!(ubig!(0x010203).to_le_bytes_padded(2), Err(OutOfBoundsError));
+    /// ```
+    pub fn to_le_bytes_padded(&self, len: usize) -> Result<Vec<u8>, OutOfBoundsError> {
+        let mut bytes = self.to_le_bytes();
+        if bytes.len() > len {
+            return Err(OutOfBoundsError);
+        }
+        bytes.resize(len, 0);
+        Ok(bytes)
+    }
+
+    /// Return big-endian bytes, padded with zeros to exactly `len` bytes.
+    ///
+    /// Returns [OutOfBoundsError] if the number doesn't fit in `len` bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::{error::OutOfBoundsError, ubig};
+    /// assert_eq!(ubig!(0x010203).to_be_bytes_padded(5), Ok(vec![0, 0, 1, 2, 3]));
+    /// assert_eq!(ubig!(0x010203).to_be_bytes_padded(2), Err(OutOfBoundsError));
+    /// ```
+    pub fn to_be_bytes_padded(&self, len: usize) -> Result<Vec<u8>, OutOfBoundsError> {
+        let mut bytes = self.to_le_bytes_padded(len)?;
+        bytes.reverse();
+        Ok(bytes)
+    }
+
+    /// Construct from little-endian `u64` chunks.
+    ///
+    /// Unlike the native word size, `u64` is the same on every target, so this is a portable
+    /// interchange format.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::{ubig, UBig};
+    /// assert_eq!(UBig::from_le_u64_chunks(&[3, 1]), ubig!(0x1_0000000000000003));
+    /// assert_eq!(UBig::from_le_u64_chunks(&[]), ubig!(0));
+    /// ```
+    pub fn from_le_u64_chunks(chunks: &[u64]) -> UBig {
+        match chunks {
+            [] => UBig::from_word(0),
+            [x] => UBig::from(*x),
+            _ => {
+                let words_per_chunk = (u64::BITS / WORD_BITS) as usize;
+                let mut buffer = Buffer::allocate(chunks.len() * words_per_chunk);
+                for &chunk in chunks {
+                    for i in 0..words_per_chunk {
+                        buffer.push((chunk >> (i * WORD_BITS as usize)) as Word);
+                    }
+                }
+                buffer.into()
+            }
+        }
+    }
+
+    /// Return little-endian `u64` chunks.
+    ///
+    /// The result has no trailing zero chunks, so 0 is represented by an empty vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// assert!(ubig!(0).to_le_u64_chunks().is_empty());
+    /// assert_eq!(ubig!(0x1_0000000000000003).to_le_u64_chunks(), [3, 1]);
+    /// ```
+    pub fn to_le_u64_chunks(&self) -> Vec<u64> {
+        let words_per_chunk = (u64::BITS / WORD_BITS) as usize;
+        self.as_words()
+            .chunks(words_per_chunk)
+            .map(|words| {
+                let mut bytes = [0; 8];
+                for (dst, word) in bytes.chunks_mut(WORD_BYTES).zip(words) {
+                    dst.copy_from_slice(&word.to_le_bytes());
+                }
+                u64::from_le_bytes(bytes)
+            })
+            .collect()
+    }
+
     /// Convert to f32.
     ///
     /// Round to nearest, breaking ties to even last bit.
@@ -166,7 +285,7 @@ impl UBig {
     pub fn to_f32(&self) -> f32 {
         match self.repr() {
             Small(word) => *word as f32,
-            Large(_) => match u32::try_from(self) {
+            Double(_) | Large(_) => match u32::try_from(self) {
                 Ok(val) => val as f32,
                 Err(_) => self.to_f32_slow(),
             },
@@ -189,7 +308,7 @@ impl UBig {
             let value = ((exponent + 126) << 23) + mantissa;
 
             // Calculate round-to-even adjustment.
-            let extra_bit = self.are_low_bits_nonzero(n - 25);
+            let extra_bit = self.any_bit_set(0..n - 25);
             // low bit of mantissa and two extra bits
             let low_bits = ((mantissa25 & 0b11) << 1) | u32::from(extra_bit);
             let adjustment = round_to_even_adjustment(low_bits);
@@ -217,7 +336,7 @@ impl UBig {
     pub fn to_f64(&self) -> f64 {
         match self.repr() {
             Small(word) => *word as f64,
-            Large(_) => match u64::try_from(self) {
+            Double(_) | Large(_) => match u64::try_from(self) {
                 Ok(val) => val as f64,
                 Err(_) => self.to_f64_slow(),
             },
@@ -240,7 +359,7 @@ impl UBig {
             let value = ((exponent + 1022) << 52) + mantissa;
 
             // Calculate round-to-even adjustment.
-            let extra_bit = self.are_low_bits_nonzero(n - 54);
+            let extra_bit = self.any_bit_set(0..n - 54);
             // low bit of mantissa and two extra bits
             let low_bits = (((mantissa54 & 0b11) as u32) << 1) | u32::from(extra_bit);
             let adjustment = round_to_even_adjustment(low_bits);
@@ -256,6 +375,22 @@ impl UBig {
 }
 
 impl IBig {
+    /// Construct from a sign and big-endian magnitude bytes.
+    ///
+    /// A zero magnitude always gives positive zero, even with a `Negative` sign.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::{ibig, IBig, Sign};
+    /// assert_eq!(IBig::from_sign_magnitude_bytes(Sign::Negative, &[1, 2]), ibig!(-0x102));
+    /// assert_eq!(IBig::from_sign_magnitude_bytes(Sign::Negative, &[0, 0]).sign(), Sign::Positive);
+    /// ```
+    #[inline]
+    pub fn from_sign_magnitude_bytes(sign: Sign, bytes: &[u8]) -> IBig {
+        IBig::from_sign_magnitude(sign, UBig::from_be_bytes(bytes))
+    }
+
     /// Convert to f32.
     ///
     /// Round to nearest, breaking ties to even last bit.
@@ -476,6 +611,14 @@ impl From<&UBig> for IBig {
     }
 }
 
+impl From<(Sign, &[u8])> for IBig {
+    /// Same as [IBig::from_sign_magnitude_bytes].
+    #[inline]
+    fn from((sign, bytes): (Sign, &[u8])) -> IBig {
+        IBig::from_sign_magnitude_bytes(sign, bytes)
+    }
+}
+
 impl TryFrom<IBig> for UBig {
     type Error = OutOfBoundsError;
 
@@ -500,6 +643,95 @@ impl TryFrom<&IBig> for UBig {
     }
 }
 
+/// A fixed-size little-endian byte array.
+///
+/// Plain `[u8; N]` arrays convert to and from [UBig] as big-endian. Wrap an array in `LeBytes`
+/// to convert it as little-endian instead.
+///
+/// # Examples
+///
+/// ```
+/// # use ibig::{ubig, LeBytes, UBig};
+/// assert_eq!(UBig::from([1, 2, 3]), ubig!(0x010203));
+/// assert_eq!(UBig::from(LeBytes([3, 2, 1])), ubig!(0x010203));
+/// let LeBytes(bytes) = LeBytes::<4>::try_from(ubig!(0x010203)).unwrap();
+/// assert_eq!(bytes, [3, 2, 1, 0]);
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct LeBytes<const N: usize>(pub [u8; N]);
+
+impl<const N: usize> From<[u8; N]> for UBig {
+    /// Construct from big-endian bytes.
+    #[inline]
+    fn from(bytes: [u8; N]) -> UBig {
+        UBig::from_be_bytes(&bytes)
+    }
+}
+
+impl<const N: usize> From<LeBytes<N>> for UBig {
+    /// Construct from little-endian bytes.
+    #[inline]
+    fn from(LeBytes(bytes): LeBytes<N>) -> UBig {
+        UBig::from_le_bytes(&bytes)
+    }
+}
+
+impl<const N: usize> TryFrom<&UBig> for LeBytes<N> {
+    type Error = OutOfBoundsError;
+
+    /// Little-endian bytes, padded with zeros.
+    ///
+    /// Returns [OutOfBoundsError] if the number doesn't fit in `N` bytes.
+    fn try_from(x: &UBig) -> Result<LeBytes<N>, OutOfBoundsError> {
+        if x.bit_len() > N * 8 {
+            return Err(OutOfBoundsError);
+        }
+        let mut bytes = [0; N];
+        for (dst, word) in bytes.chunks_mut(WORD_BYTES).zip(x.as_words().iter()) {
+            dst.copy_from_slice(&word.to_le_bytes()[..dst.len()]);
+        }
+        Ok(LeBytes(bytes))
+    }
+}
+
+impl<const N: usize> TryFrom<UBig> for LeBytes<N> {
+    type Error = OutOfBoundsError;
+
+    /// Little-endian bytes, padded with zeros.
+    ///
+    /// Returns [OutOfBoundsError] if the number doesn't fit in `N` bytes.
+    #[inline]
+    fn try_from(x: UBig) -> Result<LeBytes<N>, OutOfBoundsError> {
+        LeBytes::try_from(&x)
+    }
+}
+
+impl<const N: usize> TryFrom<&UBig> for [u8; N] {
+    type Error = OutOfBoundsError;
+
+    /// Big-endian bytes, padded with zeros.
+    ///
+    /// Returns [OutOfBoundsError] if the number doesn't fit in `N` bytes.
+    #[inline]
+    fn try_from(x: &UBig) -> Result<[u8; N], OutOfBoundsError> {
+        let LeBytes(mut bytes) = LeBytes::try_from(x)?;
+        bytes.reverse();
+        Ok(bytes)
+    }
+}
+
+impl<const N: usize> TryFrom<UBig> for [u8; N] {
+    type Error = OutOfBoundsError;
+
+    /// Big-endian bytes, padded with zeros.
+    ///
+    /// Returns [OutOfBoundsError] if the number doesn't fit in `N` bytes.
+    #[inline]
+    fn try_from(x: UBig) -> Result<[u8; N], OutOfBoundsError> {
+        <[u8; N]>::try_from(&x)
+    }
+}
+
 impl UBig {
     /// Convert an unsigned primitive to [UBig].
     #[inline]
@@ -509,10 +741,13 @@ impl UBig {
     {
         match x.try_into() {
             Ok(w) => UBig::from_word(w),
-            Err(_) => {
-                let repr = x.to_le_bytes();
-                UBig::from_le_bytes(repr.as_ref())
-            }
+            Err(_) => match x.try_into() {
+                Ok(dw) => UBig::from_double_word(dw),
+                Err(_) => {
+                    let repr = x.to_le_bytes();
+                    UBig::from_le_bytes(repr.as_ref())
+                }
+            },
         }
     }
 
@@ -539,6 +774,10 @@ impl UBig {
                 Ok(val) => Ok(val),
                 Err(_) => Err(OutOfBoundsError),
             },
+            Double(dw) => match T::try_from(*dw) {
+                Ok(val) => Ok(val),
+                Err(_) => Err(OutOfBoundsError),
+            },
             Large(buffer) => unsigned_from_words(buffer),
         }
     }
@@ -551,8 +790,8 @@ impl UBig {
     {
         match self.repr() {
             Small(w) => T::try_from(*w).map_err(|_| OutOfBoundsError),
-            Large(buffer) => {
-                let u: T::Unsigned = unsigned_from_words(buffer)?;
+            Double(_) | Large(_) => {
+                let u: T::Unsigned = self.try_to_unsigned()?;
                 u.try_into().map_err(|_| OutOfBoundsError)
             }
         }
@@ -577,7 +816,7 @@ fn unsigned_from_words<T>(words: &[Word]) -> Result<T, OutOfBoundsError>
 where
     T: PrimitiveUnsigned,
 {
-    debug_assert!(words.len() >= 2);
+    debug_assert!(words.len() >= 3);
     let t_words = T::BYTE_SIZE / WORD_BYTES;
     if t_words <= 1 || words.len() > t_words {
         Err(OutOfBoundsError)
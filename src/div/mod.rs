@@ -1,13 +1,13 @@
 //! Division functions.
 
 use crate::{
-    arch::word::Word,
+    arch::word::{DoubleWord, Word},
     fast_divide::FastDivideNormalized,
     memory::{self, Memory},
-    primitive::{double_word, extend_word},
+    primitive::{double_word, extend_word, split_double_word},
     shift,
 };
-use alloc::alloc::Layout;
+use alloc::{alloc::Layout, vec, vec::Vec};
 
 mod divide_conquer;
 mod simple;
@@ -68,6 +68,77 @@ pub(crate) fn fast_div_by_word_in_place(
     rem >> shift
 }
 
+/// words = words / rhs
+///
+/// rhs must not fit in a single `Word`.
+///
+/// Returns words % rhs.
+#[must_use]
+pub(crate) fn div_by_double_word_in_place(words: &mut [Word], rhs: DoubleWord) -> DoubleWord {
+    debug_assert!(rhs > extend_word(Word::MAX));
+    let shift = rhs.leading_zeros();
+    let rhs = rhs << shift;
+    let fast_div_rhs_hi = FastDivideNormalized::new(split_double_word(rhs).1);
+    // The carry is less than one word, so it is less than the normalized rhs.
+    let mut rem = extend_word(shift::shl_in_place(words, shift));
+
+    // Top words that are less than rhs give zero quotient words.
+    let mut len = words.len();
+    while len > 0 && rem <= extend_word(Word::MAX) {
+        let next = double_word(words[len - 1], rem as Word);
+        if next >= rhs {
+            break;
+        }
+        rem = next;
+        words[len - 1] = 0;
+        len -= 1;
+    }
+
+    for word in words[..len].iter_mut().rev() {
+        let (q, r) = div_rem_by_normalized_double_word(rem, *word, rhs, fast_div_rhs_hi);
+        *word = q;
+        rem = r;
+    }
+    rem >> shift
+}
+
+/// ([hi, lo] / rhs, [hi, lo] % rhs) for a normalized `rhs` and `hi < rhs`.
+///
+/// `fast_div_rhs_hi` must be prepared for the top word of `rhs`.
+#[inline]
+fn div_rem_by_normalized_double_word(
+    hi: DoubleWord,
+    lo: Word,
+    rhs: DoubleWord,
+    fast_div_rhs_hi: FastDivideNormalized,
+) -> (Word, DoubleWord) {
+    // The Art of Computer Programming, algorithm 4.3.1D with a two-word divisor.
+    let (n1, n2) = split_double_word(hi);
+    let (rhs_lo, rhs_hi) = split_double_word(rhs);
+
+    // Approximate by the top words. Because rhs is normalized, q is at most 2 too large.
+    let mut q = if n2 < rhs_hi {
+        fast_div_rhs_hi.div_rem(hi).0
+    } else {
+        Word::MAX
+    };
+
+    // The three-word product q * rhs as (top word, low double word).
+    let (p0, carry) = split_double_word(extend_word(q) * extend_word(rhs_lo));
+    let (p1, mut p_top) =
+        split_double_word(extend_word(q) * extend_word(rhs_hi) + extend_word(carry));
+    let mut p_low = double_word(p0, p1);
+    let n_low = double_word(lo, n1);
+
+    while (p_top, p_low) > (n2, n_low) {
+        q -= 1;
+        let (diff, borrow) = p_low.overflowing_sub(rhs);
+        p_low = diff;
+        p_top -= borrow as Word;
+    }
+    (q, n_low.wrapping_sub(p_low))
+}
+
 /// words % rhs
 pub(crate) fn rem_by_word(words: &[Word], rhs: Word) -> Word {
     debug_assert!(rhs != 0);
@@ -78,8 +149,19 @@ pub(crate) fn rem_by_word(words: &[Word], rhs: Word) -> Word {
         return words[0] & (rhs - 1);
     }
 
+    let fast_div_rhs = FastDivideNormalized::new(rhs << rhs.leading_zeros());
+    fast_rem_by_word(words, rhs, fast_div_rhs)
+}
+
+/// words % rhs
+///
+/// `fast_div_rhs` must be prepared for `rhs` shifted left by `rhs.leading_zeros()`.
+pub(crate) fn fast_rem_by_word(
+    words: &[Word],
+    rhs: Word,
+    fast_div_rhs: FastDivideNormalized,
+) -> Word {
     let shift = rhs.leading_zeros();
-    let fast_div_rhs = FastDivideNormalized::new(rhs << shift);
     let rem = fast_rem_by_normalized_word(words, fast_div_rhs);
     let a = extend_word(rem) << shift;
     let (_, rem) = fast_div_rhs.div_rem(a);
@@ -108,6 +190,37 @@ pub(crate) fn fast_rem_by_normalized_word(
     rem
 }
 
+/// words % rhs for every rhs in moduli.
+///
+/// The normalization of each modulus is computed once, and then all the remainders are updated
+/// in a single pass over words.
+///
+/// All moduli must be non-zero.
+pub(crate) fn rem_by_many_words(words: &[Word], moduli: &[Word]) -> Vec<Word> {
+    let prepared: Vec<(u32, FastDivideNormalized)> = moduli
+        .iter()
+        .map(|rhs| {
+            debug_assert!(*rhs != 0);
+            let shift = rhs.leading_zeros();
+            (shift, FastDivideNormalized::new(rhs << shift))
+        })
+        .collect();
+
+    // rems[i] = words % (moduli[i] << shift)
+    let mut rems = vec![0; moduli.len()];
+    for word in words.iter().rev() {
+        for (rem, (_, fast_div_rhs)) in rems.iter_mut().zip(&prepared) {
+            *rem = fast_div_rhs.div_rem(double_word(*word, *rem)).1;
+        }
+    }
+
+    for (rem, (shift, fast_div_rhs)) in rems.iter_mut().zip(&prepared) {
+        let a = extend_word(*rem) << shift;
+        *rem = fast_div_rhs.div_rem(a).1 >> shift;
+    }
+    rems
+}
+
 /// Memory requirement for division.
 pub(crate) fn memory_requirement_exact(lhs_len: usize, rhs_len: usize) -> Layout {
     assert!(lhs_len >= rhs_len && rhs_len >= 2);
@@ -1,17 +1,21 @@
 //! Division operators.
 
 use crate::{
-    arch::word::Word,
+    arch::word::{DoubleWord, Word},
     buffer::Buffer,
     div, helper_macros,
     ibig::IBig,
-    memory::MemoryAllocation,
+    math,
+    memory::{self, MemoryAllocation},
     ops::{Abs, DivEuclid, DivRem, DivRemEuclid, RemEuclid},
-    primitive::{PrimitiveSigned, PrimitiveUnsigned},
+    primitive::{
+        double_word, split_double_word, PrimitiveSigned, PrimitiveUnsigned, WORD_BITS_USIZE,
+    },
     shift,
     sign::Sign::*,
     ubig::{Repr::*, UBig},
 };
+use alloc::vec::Vec;
 use core::{
     fmt::Debug,
     mem,
@@ -24,9 +28,12 @@ impl Div<UBig> for UBig {
     #[inline]
     fn div(self, rhs: UBig) -> UBig {
         match (self.into_repr(), rhs.into_repr()) {
-            (Small(word0), Small(word1)) => UBig::div_word(word0, word1),
-            (Small(_), Large(_)) => UBig::from_word(0),
+            (Small(word0), Small(word1)) => UBig::div_word_word(word0, word1),
+            (Small(_), Double(_) | Large(_)) | (Double(_), Large(_)) => UBig::from_word(0),
+            (Double(dword0), Small(word1)) => UBig::div_double_word_word(dword0, word1),
+            (Double(dword0), Double(dword1)) => UBig::div_double_word(dword0, dword1),
             (Large(buffer0), Small(word1)) => UBig::div_large_word(buffer0, word1),
+            (Large(buffer0), Double(dword1)) => UBig::div_large_double_word(buffer0, dword1),
             (Large(buffer0), Large(buffer1)) => {
                 if buffer0.len() >= buffer1.len() {
                     UBig::div_large(buffer0, buffer1)
@@ -44,9 +51,12 @@ impl Div<&UBig> for UBig {
     #[inline]
     fn div(self, rhs: &UBig) -> UBig {
         match (self.into_repr(), rhs.repr()) {
-            (Small(word0), Small(word1)) => UBig::div_word(word0, *word1),
-            (Small(_), Large(_)) => UBig::from_word(0),
+            (Small(word0), Small(word1)) => UBig::div_word_word(word0, *word1),
+            (Small(_), Double(_) | Large(_)) | (Double(_), Large(_)) => UBig::from_word(0),
+            (Double(dword0), Small(word1)) => UBig::div_double_word_word(dword0, *word1),
+            (Double(dword0), Double(dword1)) => UBig::div_double_word(dword0, *dword1),
             (Large(buffer0), Small(word1)) => UBig::div_large_word(buffer0, *word1),
+            (Large(buffer0), Double(dword1)) => UBig::div_large_double_word(buffer0, *dword1),
             (Large(buffer0), Large(buffer1)) => {
                 if buffer0.len() >= buffer1.len() {
                     UBig::div_large(buffer0, buffer1.clone())
@@ -64,9 +74,14 @@ impl Div<UBig> for &UBig {
     #[inline]
     fn div(self, rhs: UBig) -> UBig {
         match (self.repr(), rhs.into_repr()) {
-            (Small(word0), Small(word1)) => UBig::div_word(*word0, word1),
-            (Small(_), Large(_)) => UBig::from_word(0),
+            (Small(word0), Small(word1)) => UBig::div_word_word(*word0, word1),
+            (Small(_), Double(_) | Large(_)) | (Double(_), Large(_)) => UBig::from_word(0),
+            (Double(dword0), Small(word1)) => UBig::div_double_word_word(*dword0, word1),
+            (Double(dword0), Double(dword1)) => UBig::div_double_word(*dword0, dword1),
             (Large(buffer0), Small(word1)) => UBig::div_large_word(buffer0.clone(), word1),
+            (Large(buffer0), Double(dword1)) => {
+                UBig::div_large_double_word(buffer0.clone(), dword1)
+            }
             (Large(buffer0), Large(buffer1)) => {
                 if buffer0.len() >= buffer1.len() {
                     UBig::div_large(buffer0.clone(), buffer1)
@@ -84,9 +99,14 @@ impl Div<&UBig> for &UBig {
     #[inline]
     fn div(self, rhs: &UBig) -> UBig {
         match (self.repr(), rhs.repr()) {
-            (Small(word0), Small(word1)) => UBig::div_word(*word0, *word1),
-            (Small(_), Large(_)) => UBig::from_word(0),
+            (Small(word0), Small(word1)) => UBig::div_word_word(*word0, *word1),
+            (Small(_), Double(_) | Large(_)) | (Double(_), Large(_)) => UBig::from_word(0),
+            (Double(dword0), Small(word1)) => UBig::div_double_word_word(*dword0, *word1),
+            (Double(dword0), Double(dword1)) => UBig::div_double_word(*dword0, *dword1),
             (Large(buffer0), Small(word1)) => UBig::div_large_word(buffer0.clone(), *word1),
+            (Large(buffer0), Double(dword1)) => {
+                UBig::div_large_double_word(buffer0.clone(), *dword1)
+            }
             (Large(buffer0), Large(buffer1)) => {
                 if buffer0.len() >= buffer1.len() {
                     UBig::div_large(buffer0.clone(), buffer1.clone())
@@ -118,9 +138,13 @@ impl Rem<UBig> for UBig {
     #[inline]
     fn rem(self, rhs: UBig) -> UBig {
         match (self.into_repr(), rhs.into_repr()) {
-            (Small(word0), Small(word1)) => UBig::rem_word(word0, word1),
-            (Small(word0), Large(_)) => UBig::from_word(word0),
+            (Small(word0), Small(word1)) => UBig::rem_word_word(word0, word1),
+            (Small(word0), Double(_) | Large(_)) => UBig::from_word(word0),
+            (Double(dword0), Small(word1)) => UBig::rem_double_word_word(dword0, word1),
+            (Double(dword0), Double(dword1)) => UBig::rem_double_word(dword0, dword1),
+            (Double(dword0), Large(_)) => UBig::from_double_word(dword0),
             (Large(buffer0), Small(word1)) => UBig::rem_large_word(&buffer0, word1),
+            (Large(buffer0), Double(dword1)) => UBig::rem_large_double_word(buffer0, dword1),
             (Large(buffer0), Large(buffer1)) => {
                 if buffer0.len() >= buffer1.len() {
                     UBig::rem_large(buffer0, buffer1)
@@ -138,12 +162,16 @@ impl Rem<&UBig> for UBig {
     #[inline]
     fn rem(self, rhs: &UBig) -> UBig {
         match (self.into_repr(), rhs.repr()) {
-            (Small(word0), Small(word1)) => UBig::rem_word(word0, *word1),
-            (Small(word0), Large(_)) => UBig::from_word(word0),
+            (Small(word0), Small(word1)) => UBig::rem_word_word(word0, *word1),
+            (Small(word0), Double(_) | Large(_)) => UBig::from_word(word0),
+            (Double(dword0), Small(word1)) => UBig::rem_double_word_word(dword0, *word1),
+            (Double(dword0), Double(dword1)) => UBig::rem_double_word(dword0, *dword1),
+            (Double(dword0), Large(_)) => UBig::from_double_word(dword0),
             (Large(buffer0), Small(word1)) => UBig::rem_large_word(&buffer0, *word1),
+            (Large(buffer0), Double(dword1)) => UBig::rem_large_double_word(buffer0, *dword1),
             (Large(buffer0), Large(buffer1)) => {
                 if buffer0.len() >= buffer1.len() {
-                    UBig::rem_large(buffer0, buffer1.clone())
+                    UBig::rem_large_in_lhs(buffer0, buffer1)
                 } else {
                     buffer0.into()
                 }
@@ -158,9 +186,15 @@ impl Rem<UBig> for &UBig {
     #[inline]
     fn rem(self, rhs: UBig) -> UBig {
         match (self.repr(), rhs.into_repr()) {
-            (Small(word0), Small(word1)) => UBig::rem_word(*word0, word1),
-            (Small(word0), Large(_)) => UBig::from_word(*word0),
+            (Small(word0), Small(word1)) => UBig::rem_word_word(*word0, word1),
+            (Small(word0), Double(_) | Large(_)) => UBig::from_word(*word0),
+            (Double(dword0), Small(word1)) => UBig::rem_double_word_word(*dword0, word1),
+            (Double(dword0), Double(dword1)) => UBig::rem_double_word(*dword0, dword1),
+            (Double(dword0), Large(_)) => UBig::from_double_word(*dword0),
             (Large(buffer0), Small(word1)) => UBig::rem_large_word(buffer0, word1),
+            (Large(buffer0), Double(dword1)) => {
+                UBig::rem_large_double_word(buffer0.clone(), dword1)
+            }
             (Large(buffer0), Large(mut buffer1)) => {
                 if buffer0.len() >= buffer1.len() {
                     UBig::rem_large(buffer0.clone(), buffer1)
@@ -180,9 +214,15 @@ impl Rem<&UBig> for &UBig {
     #[inline]
     fn rem(self, rhs: &UBig) -> UBig {
         match (self.repr(), rhs.repr()) {
-            (Small(word0), Small(word1)) => UBig::rem_word(*word0, *word1),
-            (Small(word0), Large(_)) => UBig::from_word(*word0),
+            (Small(word0), Small(word1)) => UBig::rem_word_word(*word0, *word1),
+            (Small(word0), Double(_) | Large(_)) => UBig::from_word(*word0),
+            (Double(dword0), Small(word1)) => UBig::rem_double_word_word(*dword0, *word1),
+            (Double(dword0), Double(dword1)) => UBig::rem_double_word(*dword0, *dword1),
+            (Double(dword0), Large(_)) => UBig::from_double_word(*dword0),
             (Large(buffer0), Small(word1)) => UBig::rem_large_word(buffer0, *word1),
+            (Large(buffer0), Double(dword1)) => {
+                UBig::rem_large_double_word(buffer0.clone(), *dword1)
+            }
             (Large(buffer0), Large(buffer1)) => {
                 if buffer0.len() >= buffer1.len() {
                     UBig::rem_large(buffer0.clone(), buffer1.clone())
@@ -215,9 +255,13 @@ impl DivRem<UBig> for UBig {
     #[inline]
     fn div_rem(self, rhs: UBig) -> (UBig, UBig) {
         match (self.into_repr(), rhs.into_repr()) {
-            (Small(word0), Small(word1)) => UBig::div_rem_word(word0, word1),
-            (Small(word0), Large(_)) => (UBig::from_word(0), UBig::from_word(word0)),
+            (Small(word0), Small(word1)) => UBig::div_rem_word_word(word0, word1),
+            (Small(word0), Double(_) | Large(_)) => (UBig::from_word(0), UBig::from_word(word0)),
+            (Double(dword0), Small(word1)) => UBig::div_rem_double_word_word(dword0, word1),
+            (Double(dword0), Double(dword1)) => UBig::div_rem_double_word(dword0, dword1),
+            (Double(dword0), Large(_)) => (UBig::from_word(0), UBig::from_double_word(dword0)),
             (Large(buffer0), Small(word1)) => UBig::div_rem_large_word(buffer0, word1),
+            (Large(buffer0), Double(dword1)) => UBig::div_rem_large_double_word(buffer0, dword1),
             (Large(buffer0), Large(buffer1)) => {
                 if buffer0.len() >= buffer1.len() {
                     UBig::div_rem_large(buffer0, buffer1)
@@ -236,9 +280,13 @@ impl DivRem<&UBig> for UBig {
     #[inline]
     fn div_rem(self, rhs: &UBig) -> (UBig, UBig) {
         match (self.into_repr(), rhs.repr()) {
-            (Small(word0), Small(word1)) => UBig::div_rem_word(word0, *word1),
-            (Small(word0), Large(_)) => (UBig::from_word(0), UBig::from_word(word0)),
+            (Small(word0), Small(word1)) => UBig::div_rem_word_word(word0, *word1),
+            (Small(word0), Double(_) | Large(_)) => (UBig::from_word(0), UBig::from_word(word0)),
+            (Double(dword0), Small(word1)) => UBig::div_rem_double_word_word(dword0, *word1),
+            (Double(dword0), Double(dword1)) => UBig::div_rem_double_word(dword0, *dword1),
+            (Double(dword0), Large(_)) => (UBig::from_word(0), UBig::from_double_word(dword0)),
             (Large(buffer0), Small(word1)) => UBig::div_rem_large_word(buffer0, *word1),
+            (Large(buffer0), Double(dword1)) => UBig::div_rem_large_double_word(buffer0, *dword1),
             (Large(buffer0), Large(buffer1)) => {
                 if buffer0.len() >= buffer1.len() {
                     UBig::div_rem_large(buffer0, buffer1.clone())
@@ -257,9 +305,15 @@ impl DivRem<UBig> for &UBig {
     #[inline]
     fn div_rem(self, rhs: UBig) -> (UBig, UBig) {
         match (self.repr(), rhs.into_repr()) {
-            (Small(word0), Small(word1)) => UBig::div_rem_word(*word0, word1),
-            (Small(word0), Large(_)) => (UBig::from_word(0), UBig::from_word(*word0)),
+            (Small(word0), Small(word1)) => UBig::div_rem_word_word(*word0, word1),
+            (Small(word0), Double(_) | Large(_)) => (UBig::from_word(0), UBig::from_word(*word0)),
+            (Double(dword0), Small(word1)) => UBig::div_rem_double_word_word(*dword0, word1),
+            (Double(dword0), Double(dword1)) => UBig::div_rem_double_word(*dword0, dword1),
+            (Double(dword0), Large(_)) => (UBig::from_word(0), UBig::from_double_word(*dword0)),
             (Large(buffer0), Small(word1)) => UBig::div_rem_large_word(buffer0.clone(), word1),
+            (Large(buffer0), Double(dword1)) => {
+                UBig::div_rem_large_double_word(buffer0.clone(), dword1)
+            }
             (Large(buffer0), Large(mut buffer1)) => {
                 if buffer0.len() >= buffer1.len() {
                     UBig::div_rem_large(buffer0.clone(), buffer1)
@@ -280,9 +334,15 @@ impl DivRem<&UBig> for &UBig {
     #[inline]
     fn div_rem(self, rhs: &UBig) -> (UBig, UBig) {
         match (self.repr(), rhs.repr()) {
-            (Small(word0), Small(word1)) => UBig::div_rem_word(*word0, *word1),
-            (Small(word0), Large(_)) => (UBig::from_word(0), UBig::from_word(*word0)),
+            (Small(word0), Small(word1)) => UBig::div_rem_word_word(*word0, *word1),
+            (Small(word0), Double(_) | Large(_)) => (UBig::from_word(0), UBig::from_word(*word0)),
+            (Double(dword0), Small(word1)) => UBig::div_rem_double_word_word(*dword0, *word1),
+            (Double(dword0), Double(dword1)) => UBig::div_rem_double_word(*dword0, *dword1),
+            (Double(dword0), Large(_)) => (UBig::from_word(0), UBig::from_double_word(*dword0)),
             (Large(buffer0), Small(word1)) => UBig::div_rem_large_word(buffer0.clone(), *word1),
+            (Large(buffer0), Double(dword1)) => {
+                UBig::div_rem_large_double_word(buffer0.clone(), *dword1)
+            }
             (Large(buffer0), Large(buffer1)) => {
                 if buffer0.len() >= buffer1.len() {
                     UBig::div_rem_large(buffer0.clone(), buffer1.clone())
@@ -1276,10 +1336,256 @@ impl_div_ibig_signed!(i64);
 impl_div_ibig_signed!(i128);
 impl_div_ibig_signed!(isize);
 
+impl UBig {
+    /// Quotient and remainder at the same time.
+    ///
+    /// Equivalent to [DivRem::div_rem] on `&UBig`, without the need to import the trait. `rhs`
+    /// can be anything that [DivRem] accepts: a [UBig], a reference, or a primitive integer.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// assert_eq!(ubig!(23).div_rem(&ubig!(10)), (ubig!(2), ubig!(3)));
+    /// assert_eq!(ubig!(23).div_rem(10u8), (ubig!(2), 3u8));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` is 0.
+    #[inline]
+    #[allow(clippy::type_complexity)]
+    pub fn div_rem<'a, Rhs>(
+        &'a self,
+        rhs: Rhs,
+    ) -> (
+        <&'a UBig as DivRem<Rhs>>::OutputDiv,
+        <&'a UBig as DivRem<Rhs>>::OutputRem,
+    )
+    where
+        &'a UBig: DivRem<Rhs>,
+    {
+        DivRem::div_rem(self, rhs)
+    }
+
+    /// Check whether `self` is a multiple of `d`.
+    ///
+    /// Equivalent to `self % d == ubig!(0)`, but doesn't construct the remainder when `d` is a
+    /// power of two or fits in a single word. Only 0 is a multiple of 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// assert!(ubig!(12).is_multiple_of(&ubig!(4)));
+    /// assert!(!ubig!(12).is_multiple_of(&ubig!(5)));
+    /// assert!(ubig!(0).is_multiple_of(&ubig!(0)));
+    /// assert!(!ubig!(12).is_multiple_of(&ubig!(0)));
+    /// ```
+    pub fn is_multiple_of(&self, d: &UBig) -> bool {
+        if d.is_zero() || *self < *d {
+            return self.is_zero();
+        }
+        if d.is_power_of_two() {
+            return !self.any_bit_set(0..d.bit_len() - 1);
+        }
+        match d.repr() {
+            Small(word) => div::rem_by_word(&self.as_words(), *word) == 0,
+            _ => (self % d).is_zero(),
+        }
+    }
+
+    /// Remainder modulo a `u64`.
+    ///
+    /// Equivalent to `self % m`, but skips the conversion of `m` to a [UBig].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// assert_eq!(ubig!(_0x123456789123456789123456789).rem_word(1000), 385);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `m` is 0.
+    #[allow(clippy::useless_conversion)]
+    pub fn rem_word(&self, m: u64) -> u64 {
+        match Word::try_from(m) {
+            Ok(0) => panic_divide_by_0(),
+            Ok(m_word) => div::rem_by_word(&self.as_words(), m_word).into(),
+            Err(_) => self % m,
+        }
+    }
+
+    /// Remainders modulo many `u64`s.
+    ///
+    /// Returns `self % m` for every `m` in `moduli`.
+    ///
+    /// The words of `self` are only read once: the precomputed division constants for each
+    /// modulus are reused for every word. This makes it suitable for sieving a large number by
+    /// many small primes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// let x = ubig!(_0x123456789123456789123456789);
+    /// assert_eq!(x.rem_words(&[2, 3, 5, 7]), [1, 0, 0, 1]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if any modulus is 0.
+    #[allow(clippy::useless_conversion)]
+    pub fn rem_words(&self, moduli: &[u64]) -> Vec<u64> {
+        if moduli.contains(&0) {
+            panic_divide_by_0();
+        }
+        match moduli
+            .iter()
+            .map(|m| Word::try_from(*m))
+            .collect::<Result<Vec<Word>, _>>()
+        {
+            Ok(word_moduli) => div::rem_by_many_words(&self.as_words(), &word_moduli)
+                .into_iter()
+                .map(u64::from)
+                .collect(),
+            Err(_) => moduli.iter().map(|m| self % *m).collect(),
+        }
+    }
+
+    /// Quotient and remainder, written into existing numbers.
+    ///
+    /// Equivalent to `(*quotient, *remainder) = self.div_rem(rhs)`, but reuses the memory
+    /// already allocated by `quotient` and `remainder` when they are large enough, and keeps it
+    /// for the next call. This avoids allocations in loops where the destinations persist across
+    /// iterations. Results of at most two words are stored inline, which releases the memory.
+    ///
+    /// The outputs can't alias the inputs or each other: the borrow checker rejects that. To
+    /// divide a number in place, use `/=` or `%=`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// let mut q = ubig!(0);
+    /// let mut r = ubig!(0);
+    /// ubig!(23).div_rem_into(&ubig!(10), &mut q, &mut r);
+    /// assert_eq!((q, r), (ubig!(2), ubig!(3)));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` is 0.
+    pub fn div_rem_into(&self, rhs: &UBig, quotient: &mut UBig, remainder: &mut UBig) {
+        match (self.repr(), rhs.repr()) {
+            (Large(lhs), Large(rhs))
+                if lhs.len() >= rhs.len() && !UBig::is_power_of_two_large(rhs) =>
+            {
+                // Divide in the quotient's buffer and copy only the remainder out, keeping the
+                // capacity of both destinations for the next call.
+                let n = rhs.len();
+                let mut buffer = UBig::take_buffer(quotient, lhs.len() + 1);
+                buffer.extend(&lhs[..]);
+                let (shift, overflow) = UBig::div_rem_in_lhs_ref_rhs(&mut buffer, rhs);
+                let mut rem_buffer = UBig::take_buffer(remainder, n);
+                rem_buffer.extend(&buffer[..n]);
+                let low_bits = shift::shr_in_place(&mut rem_buffer, shift);
+                debug_assert!(low_bits == 0);
+                buffer.erase_front(n);
+                if overflow {
+                    buffer.push(1);
+                }
+                *quotient = UBig::from_buffer_keep_capacity(buffer);
+                *remainder = UBig::from_buffer_keep_capacity(rem_buffer);
+            }
+            _ => {
+                let (q, r) = self.div_rem(rhs);
+                *quotient = q;
+                *remainder = r;
+            }
+        }
+    }
+
+    /// Take the buffer of `x` for reuse, empty and with capacity for `num_words`.
+    ///
+    /// Allocates a new buffer if `x` is not large.
+    fn take_buffer(x: &mut UBig, num_words: usize) -> Buffer {
+        match mem::take(x).into_repr() {
+            Large(mut buffer) => {
+                buffer.truncate(0);
+                buffer.ensure_capacity(num_words);
+                buffer
+            }
+            Small(_) | Double(_) => Buffer::allocate(num_words),
+        }
+    }
+}
+
+impl IBig {
+    /// Quotient and remainder at the same time.
+    ///
+    /// Equivalent to [DivRem::div_rem] on `&IBig`, without the need to import the trait. `rhs`
+    /// can be anything that [DivRem] accepts: an [IBig], a reference, or a primitive integer.
+    ///
+    /// The quotient is rounded towards zero, and the remainder has the sign of `self`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ibig::ibig;
+    /// assert_eq!(ibig!(-23).div_rem(&ibig!(10)), (ibig!(-2), ibig!(-3)));
+    /// assert_eq!(ibig!(-23).div_rem(10i8), (ibig!(-2), -3i8));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` is 0.
+    #[inline]
+    #[allow(clippy::type_complexity)]
+    pub fn div_rem<'a, Rhs>(
+        &'a self,
+        rhs: Rhs,
+    ) -> (
+        <&'a IBig as DivRem<Rhs>>::OutputDiv,
+        <&'a IBig as DivRem<Rhs>>::OutputRem,
+    )
+    where
+        &'a IBig: DivRem<Rhs>,
+    {
+        DivRem::div_rem(self, rhs)
+    }
+
+    /// Euclidean remainder modulo a [UBig].
+    ///
+    /// Equivalent to `self.rem_euclid(IBig::from(modulus.clone()))`, but returns the
+    /// remainder directly as a [UBig], since it is never negative.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::{ibig, ubig};
+    /// assert_eq!(ibig!(7).rem_euclid_ubig(&ubig!(3)), ubig!(1));
+    /// assert_eq!(ibig!(-7).rem_euclid_ubig(&ubig!(3)), ubig!(2));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `modulus` is 0.
+    pub fn rem_euclid_ubig(&self, modulus: &UBig) -> UBig {
+        let r = self.magnitude() % modulus;
+        match self.sign() {
+            Negative if r != UBig::from_word(0) => modulus - r,
+            _ => r,
+        }
+    }
+}
+
 impl UBig {
     /// `lhs / rhs`
     #[inline]
-    fn div_word(lhs: Word, rhs: Word) -> UBig {
+    fn div_word_word(lhs: Word, rhs: Word) -> UBig {
         match lhs.checked_div(rhs) {
             Some(res) => UBig::from_word(res),
             None => panic_divide_by_0(),
@@ -1288,7 +1594,7 @@ impl UBig {
 
     /// `lhs % rhs`
     #[inline]
-    fn rem_word(lhs: Word, rhs: Word) -> UBig {
+    fn rem_word_word(lhs: Word, rhs: Word) -> UBig {
         match lhs.checked_rem(rhs) {
             Some(res) => UBig::from_word(res),
             None => panic_divide_by_0(),
@@ -1297,7 +1603,7 @@ impl UBig {
 
     /// (lhs / rhs, lhs % rhs)
     #[inline]
-    fn div_rem_word(lhs: Word, rhs: Word) -> (UBig, UBig) {
+    fn div_rem_word_word(lhs: Word, rhs: Word) -> (UBig, UBig) {
         // If division works, remainder also works.
         match lhs.checked_div(rhs) {
             Some(res) => (UBig::from_word(res), UBig::from_word(lhs % rhs)),
@@ -1305,6 +1611,70 @@ impl UBig {
         }
     }
 
+    /// `lhs / rhs`
+    #[inline]
+    fn div_double_word(lhs: DoubleWord, rhs: DoubleWord) -> UBig {
+        match lhs.checked_div(rhs) {
+            Some(res) => UBig::from_double_word(res),
+            None => panic_divide_by_0(),
+        }
+    }
+
+    /// `lhs % rhs`
+    #[inline]
+    fn rem_double_word(lhs: DoubleWord, rhs: DoubleWord) -> UBig {
+        match lhs.checked_rem(rhs) {
+            Some(res) => UBig::from_double_word(res),
+            None => panic_divide_by_0(),
+        }
+    }
+
+    /// (lhs / rhs, lhs % rhs)
+    #[inline]
+    fn div_rem_double_word(lhs: DoubleWord, rhs: DoubleWord) -> (UBig, UBig) {
+        // If division works, remainder also works.
+        match lhs.checked_div(rhs) {
+            Some(res) => (
+                UBig::from_double_word(res),
+                UBig::from_double_word(lhs % rhs),
+            ),
+            None => panic_divide_by_0(),
+        }
+    }
+
+    /// `lhs / rhs`
+    #[inline]
+    fn div_double_word_word(lhs: DoubleWord, rhs: Word) -> UBig {
+        let (q, _) = UBig::div_rem_double_word_word_raw(lhs, rhs);
+        UBig::from_double_word(q)
+    }
+
+    /// `lhs % rhs`
+    #[inline]
+    fn rem_double_word_word(lhs: DoubleWord, rhs: Word) -> UBig {
+        let (_, r) = UBig::div_rem_double_word_word_raw(lhs, rhs);
+        UBig::from_word(r)
+    }
+
+    /// (lhs / rhs, lhs % rhs)
+    #[inline]
+    fn div_rem_double_word_word(lhs: DoubleWord, rhs: Word) -> (UBig, UBig) {
+        let (q, r) = UBig::div_rem_double_word_word_raw(lhs, rhs);
+        (UBig::from_double_word(q), UBig::from_word(r))
+    }
+
+    /// (lhs / rhs, lhs % rhs) using word-by-word division rather than a full `DoubleWord`
+    /// division.
+    fn div_rem_double_word_word_raw(lhs: DoubleWord, rhs: Word) -> (DoubleWord, Word) {
+        if rhs == 0 {
+            panic_divide_by_0();
+        }
+        let (lo, hi) = split_double_word(lhs);
+        let mut words = [lo, hi];
+        let rem = div::div_by_word_in_place(&mut words, rhs);
+        (double_word(words[0], words[1]), rem)
+    }
+
     /// `lhs / rhs`
     fn div_large_word(lhs: Buffer, rhs: Word) -> UBig {
         let (q, _) = UBig::div_rem_large_word(lhs, rhs);
@@ -1329,7 +1699,12 @@ impl UBig {
     }
 
     /// `lhs / rhs`
+    ///
+    /// Division by a power of 2 is done by shifting.
     fn div_large(mut lhs: Buffer, mut rhs: Buffer) -> UBig {
+        if UBig::is_power_of_two_large(&rhs) {
+            return UBig::shr_large(lhs, UBig::trailing_zeros_large(&rhs));
+        }
         let _shift = UBig::div_rem_in_lhs(&mut lhs, &mut rhs);
         lhs.erase_front(rhs.len());
         lhs.into()
@@ -1337,6 +1712,9 @@ impl UBig {
 
     /// `lhs % rhs`
     fn rem_large(mut lhs: Buffer, mut rhs: Buffer) -> UBig {
+        if UBig::is_power_of_two_large(&rhs) {
+            return UBig::low_bits_large(lhs, UBig::trailing_zeros_large(&rhs));
+        }
         let shift = UBig::div_rem_in_lhs(&mut lhs, &mut rhs);
         let n = rhs.len();
         rhs.copy_from_slice(&lhs[..n]);
@@ -1345,8 +1723,31 @@ impl UBig {
         rhs.into()
     }
 
+    /// `lhs % rhs`, reusing the buffer of `lhs` for the remainder.
+    ///
+    /// The normalized copy of `rhs` is kept in temporary memory rather than a new `Buffer`, so
+    /// repeated `x %= &m` doesn't allocate a new buffer each time.
+    fn rem_large_in_lhs(mut lhs: Buffer, rhs: &[Word]) -> UBig {
+        if UBig::is_power_of_two_large(rhs) {
+            return UBig::low_bits_large(lhs, UBig::trailing_zeros_large(rhs));
+        }
+        let n = rhs.len();
+        // The quotient, including its overflow, is discarded.
+        let (shift, _overflow) = UBig::div_rem_in_lhs_ref_rhs(&mut lhs, rhs);
+        lhs.truncate(n);
+        let low_bits = shift::shr_in_place(&mut lhs, shift);
+        debug_assert!(low_bits == 0);
+        lhs.into()
+    }
+
     /// `(lhs / rhs, lhs % rhs)`
     fn div_rem_large(mut lhs: Buffer, mut rhs: Buffer) -> (UBig, UBig) {
+        if UBig::is_power_of_two_large(&rhs) {
+            let n = UBig::trailing_zeros_large(&rhs);
+            rhs.truncate(0);
+            rhs.extend(&lhs[..math::ceil_div_usize(n, WORD_BITS_USIZE)]);
+            return (UBig::shr_large(lhs, n), UBig::low_bits_large(rhs, n));
+        }
         let shift = UBig::div_rem_in_lhs(&mut lhs, &mut rhs);
         let n = rhs.len();
         rhs.copy_from_slice(&lhs[..n]);
@@ -1356,10 +1757,64 @@ impl UBig {
         (lhs.into(), rhs.into())
     }
 
+    /// The lowest `n` bits of `buffer`, i.e. `buffer % 2^n`.
+    fn low_bits_large(mut buffer: Buffer, n: usize) -> UBig {
+        let len = math::ceil_div_usize(n, WORD_BITS_USIZE);
+        if len <= buffer.len() {
+            buffer.truncate(len);
+            let top_bits = (n % WORD_BITS_USIZE) as u32;
+            if top_bits != 0 {
+                *buffer.last_mut().unwrap() &= math::ones_word(top_bits);
+            }
+        }
+        buffer.into()
+    }
+
+    /// `lhs / rhs`
+    fn div_large_double_word(mut lhs: Buffer, rhs: DoubleWord) -> UBig {
+        let _rem = div::div_by_double_word_in_place(&mut lhs, rhs);
+        lhs.into()
+    }
+
+    /// `lhs % rhs`
+    fn rem_large_double_word(mut lhs: Buffer, rhs: DoubleWord) -> UBig {
+        let rem = div::div_by_double_word_in_place(&mut lhs, rhs);
+        UBig::from_double_word(rem)
+    }
+
+    /// `(lhs / rhs, lhs % rhs)`
+    fn div_rem_large_double_word(mut lhs: Buffer, rhs: DoubleWord) -> (UBig, UBig) {
+        let rem = div::div_by_double_word_in_place(&mut lhs, rhs);
+        (lhs.into(), UBig::from_double_word(rem))
+    }
+
+    /// Like `div_rem_in_lhs`, but `rhs` is borrowed. A normalized copy of `rhs` is kept in
+    /// temporary memory.
+    ///
+    /// Returns the shift and whether the quotient overflowed `lhs`. The top word 1 of the quotient
+    /// is not pushed to `lhs` on overflow.
+    fn div_rem_in_lhs_ref_rhs(lhs: &mut Buffer, rhs: &[Word]) -> (u32, bool) {
+        let n = rhs.len();
+        let shift = rhs.last().unwrap().leading_zeros();
+        let lhs_carry = shift::shl_in_place(lhs, shift);
+        if lhs_carry != 0 {
+            lhs.push_may_reallocate(lhs_carry);
+        }
+        let mut allocation = MemoryAllocation::new(memory::add_layout(
+            memory::array_layout::<Word>(n),
+            div::memory_requirement_exact(lhs.len(), n),
+        ));
+        let mut memory = allocation.memory();
+        let (rhs, mut memory) = memory.allocate_slice_copy(rhs);
+        let (_, fast_div_rhs_top) = div::normalize_large(rhs);
+        let overflow = div::div_rem_in_place(lhs, rhs, fast_div_rhs_top, &mut memory);
+        (shift, overflow)
+    }
+
     /// lhs = (lhs / rhs, lhs % rhs)
     ///
     /// Returns shift.
-    fn div_rem_in_lhs(lhs: &mut Buffer, rhs: &mut Buffer) -> u32 {
+    fn div_rem_in_lhs(lhs: &mut Buffer, rhs: &mut [Word]) -> u32 {
         let (shift, fast_div_rhs_top) = div::normalize_large(rhs);
         let lhs_carry = shift::shl_in_place(lhs, shift);
         if lhs_carry != 0 {
@@ -0,0 +1,205 @@
+//! Format in scientific notation.
+
+use crate::{
+    ibig::IBig,
+    radix::{self, Digit},
+    sign::Sign::{self, *},
+    ubig::UBig,
+};
+use alloc::{
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use core::{
+    cmp::Ordering,
+    fmt::{self, Formatter, LowerExp, UpperExp, Write},
+};
+
+impl LowerExp for UBig {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        fmt_exp(Positive, self, f, 'e')
+    }
+}
+
+impl UpperExp for UBig {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        fmt_exp(Positive, self, f, 'E')
+    }
+}
+
+impl LowerExp for IBig {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        fmt_exp(self.sign(), self.magnitude(), f, 'e')
+    }
+}
+
+impl UpperExp for IBig {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        fmt_exp(self.sign(), self.magnitude(), f, 'E')
+    }
+}
+
+/// Format in decimal scientific notation, the same way as primitive integers.
+///
+/// Without precision, all significant digits are written. With precision, the number is rounded
+/// to `precision` digits after the decimal point, with ties rounded to even.
+fn fmt_exp(sign: Sign, magnitude: &UBig, f: &mut Formatter, exp_char: char) -> fmt::Result {
+    let (digits, exponent) = match f.precision() {
+        None => {
+            let mut digits = magnitude.to_string();
+            let exponent = digits.len() - 1;
+            let len = digits.trim_end_matches('0').len().max(1);
+            digits.truncate(len);
+            (digits, exponent)
+        }
+        Some(precision) => rounded_digits(magnitude, precision + 1),
+    };
+
+    let mut s = String::with_capacity(digits.len() + 22);
+    s.push_str(&digits[..1]);
+    if digits.len() > 1 {
+        s.push('.');
+        s.push_str(&digits[1..]);
+    }
+    write!(s, "{}{}", exp_char, exponent)?;
+    f.pad_integral(sign == Positive, "", &s)
+}
+
+impl UBig {
+    /// The top `n` digits in a given radix, and the total number of digits.
+    ///
+    /// The digits are returned as values, most significant first, truncated rather than rounded.
+    /// If the number has fewer than `n` digits, all of them are returned. 0 has a single digit.
+    ///
+    /// Only the top digits are computed: the rest is removed by a single division by a power of
+    /// the radix, or by a shift for power of two radices. This is faster than converting the whole
+    /// number to a string when it is large.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// assert_eq!(ubig!(123456).significant_digits(3, 10), (vec![1, 2, 3], 6));
+    /// assert_eq!(ubig!(0xff).significant_digits(5, 16), (vec![15, 15], 2));
+    /// assert_eq!(ubig!(0).significant_digits(2, 10), (vec![0], 1));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is not between 2 and 36 inclusive.
+    pub fn significant_digits(&self, n: usize, radix: u32) -> (Vec<u8>, usize) {
+        radix::check_radix_valid(radix);
+        if self.is_zero() {
+            return (if n == 0 { Vec::new() } else { vec![0] }, 1);
+        }
+        let (high, _, exponent) = split_high_digits(self, n, radix);
+        let digits = if n == 0 {
+            Vec::new()
+        } else {
+            high.in_radix(radix)
+                .to_string()
+                .chars()
+                .map(|c| c.to_digit(radix).unwrap() as u8)
+                .collect()
+        };
+        (digits, exponent + 1)
+    }
+}
+
+/// Split non-zero `x` into the top `n` digits in `radix` and the rest.
+///
+/// Returns `(high, rounding, exponent)` where `high` is the top `n` digits, `rounding` compares
+/// the rest to half of the dropped place value, and `exponent` is the number of digits minus 1.
+fn split_high_digits(x: &UBig, n: usize, radix: Digit) -> (UBig, Ordering, usize) {
+    debug_assert!(!x.is_zero());
+    if radix.is_power_of_two() {
+        return split_high_digits_power_two(x, n, radix);
+    }
+
+    let exponent_lower = floor_log_lower_bound(x, radix);
+    let (mut high, mut rest, mut divisor, mut dropped) = if exponent_lower < n {
+        (x.clone(), UBig::from_word(0), UBig::from_word(1), 0)
+    } else {
+        // Remove exponent_lower + 1 - n digits. This leaves at least n digits.
+        let dropped = exponent_lower + 1 - n;
+        let divisor = UBig::from(radix).pow(dropped);
+        let (high, rest) = x.div_rem(&divisor);
+        (high, rest, divisor, dropped)
+    };
+
+    // The estimate is at most a digit or two short, so this rarely loops.
+    let limit = UBig::from(radix).pow(n);
+    while high >= limit {
+        let (q, digit) = high.div_rem(radix);
+        rest += &divisor * digit;
+        divisor *= radix;
+        high = q;
+        dropped += 1;
+    }
+
+    let exponent = if dropped == 0 {
+        // x has at most n digits, and at least exponent_lower + 1.
+        let mut exponent = exponent_lower;
+        let mut power = UBig::from(radix).pow(exponent + 1);
+        while power <= *x {
+            power *= radix;
+            exponent += 1;
+        }
+        exponent
+    } else {
+        dropped + n - 1
+    };
+    let rounding = (rest << 1).cmp(&divisor);
+    (high, rounding, exponent)
+}
+
+/// `split_high_digits` for a power of two `radix`, reading the bits directly.
+fn split_high_digits_power_two(x: &UBig, n: usize, radix: Digit) -> (UBig, Ordering, usize) {
+    let log_radix = radix.trailing_zeros() as usize;
+    let exponent = (x.bit_len() - 1) / log_radix;
+    if exponent < n {
+        return (x.clone(), Ordering::Less, exponent);
+    }
+    let dropped_bits = (exponent + 1 - n) * log_radix;
+    let rounding = if !x.bit(dropped_bits - 1) {
+        Ordering::Less
+    } else if x.any_bit_set(0..dropped_bits - 1) {
+        Ordering::Greater
+    } else {
+        Ordering::Equal
+    };
+    (x >> dropped_bits, rounding, exponent)
+}
+
+/// A lower bound for `floor(log_radix(x))`, short by at most a few for huge `x`.
+///
+/// Uses `x >= 2^(bit_len - 1)` and an upper bound of `log2(radix)`.
+fn floor_log_lower_bound(x: &UBig, radix: Digit) -> usize {
+    let log2_radix = radix::radix_info(radix).log2_radix_ceil;
+    let lower = ((x.bit_len() as u128 - 1) << radix::LOG2_RADIX_FRAC_BITS) / log2_radix as u128;
+    lower as usize
+}
+
+/// The top `n` decimal digits of `x` rounded half to even, and the decimal exponent.
+fn rounded_digits(x: &UBig, n: usize) -> (String, usize) {
+    debug_assert!(n >= 1);
+    if x.is_zero() {
+        return ("0".repeat(n), 0);
+    }
+
+    let (mut q, rounding, mut exponent) = split_high_digits(x, n, 10);
+    if rounding == Ordering::Greater || (rounding == Ordering::Equal && q.bit(0)) {
+        q += UBig::from_word(1);
+    }
+    let mut digits = q.to_string();
+    if digits.len() > n.min(exponent + 1) {
+        // Rounded up to a power of 10.
+        digits.truncate(digits.len() - 1);
+        exponent += 1;
+    }
+    while digits.len() < n {
+        digits.push('0');
+    }
+    (digits, exponent)
+}
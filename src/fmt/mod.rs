@@ -4,7 +4,12 @@ use crate::{
     ibig::IBig,
     radix::{self, Digit, DigitCase},
     sign::Sign::{self, *},
-    ubig::UBig,
+    ubig::{Repr::*, UBig},
+};
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
 };
 use core::fmt::{
     self, Alignment, Binary, Debug, Display, Formatter, LowerHex, Octal, UpperHex, Write,
@@ -12,6 +17,7 @@ use core::fmt::{
 use digit_writer::DigitWriter;
 
 mod digit_writer;
+mod exp;
 mod non_power_two;
 mod power_two;
 
@@ -28,9 +34,29 @@ impl Display for UBig {
     }
 }
 
+/// Same as `Display`.
+///
+/// The alternate form `{:#?}` also shows the internal representation, for example
+/// `UBig::Large { value: 1606938044258990275541962092341162602522202993782792835301376,
+/// words: 4, capacity: 6 }`. Its exact format is not stable.
 impl Debug for UBig {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        Display::fmt(self, f)
+        if !f.alternate() {
+            return Display::fmt(self, f);
+        }
+        match self.repr() {
+            Small(word) => f.debug_struct("UBig::Small").field("value", word).finish(),
+            Double(dword) => f
+                .debug_struct("UBig::Double")
+                .field("value", dword)
+                .finish(),
+            Large(buffer) => f
+                .debug_struct("UBig::Large")
+                .field("value", &format_args!("{}", self))
+                .field("words", &buffer.len())
+                .field("capacity", &buffer.capacity())
+                .finish(),
+        }
     }
 }
 
@@ -99,9 +125,18 @@ impl Display for IBig {
     }
 }
 
+/// Same as `Display`.
+///
+/// The alternate form `{:#?}` shows the sign and the internal representation of the magnitude.
 impl Debug for IBig {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        Display::fmt(self, f)
+        if !f.alternate() {
+            return Display::fmt(self, f);
+        }
+        f.debug_struct("IBig")
+            .field("sign", &self.sign())
+            .field("magnitude", self.magnitude())
+            .finish()
     }
 }
 
@@ -178,8 +213,90 @@ impl UBig {
             sign: Positive,
             magnitude: self,
             radix,
+            radix_powers: None,
         }
     }
+
+    /// String representation in a given radix.
+    ///
+    /// Shorthand for `self.in_radix(radix).to_string()`. Digits 10-35 are lower-case letters.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is not between 2 and 36 inclusive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// assert_eq!(ubig!(83).to_radix_string(3), "10002");
+    /// assert_eq!(ubig!(0xabc).to_radix_string(16), "abc");
+    /// ```
+    #[inline]
+    pub fn to_radix_string(&self, radix: u32) -> String {
+        self.in_radix(radix).to_string()
+    }
+
+    /// String representation in a given radix, with upper-case letters.
+    ///
+    /// Shorthand for `format!("{:#}", self.in_radix(radix))`. Digits 10-35 are upper-case letters.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is not between 2 and 36 inclusive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// assert_eq!(ubig!(0xabc).to_radix_string_upper(16), "ABC");
+    /// ```
+    #[inline]
+    pub fn to_radix_string_upper(&self, radix: u32) -> String {
+        format!("{:#}", self.in_radix(radix))
+    }
+
+    /// Write the digits in a given radix into `out`, most significant first.
+    ///
+    /// `out` is cleared first, then filled with digit values in `0..radix` rather than ASCII
+    /// characters. 0 is written as a single 0 digit. Reusing the same `out` for many numbers
+    /// avoids allocating for each of them.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is not between 2 and 36 inclusive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// let mut digits = Vec::new();
+    /// ubig!(83).write_radix_digits(3, &mut digits);
+    /// assert_eq!(digits, [1, 0, 0, 0, 2]);
+    /// ubig!(0).write_radix_digits(10, &mut digits);
+    /// assert_eq!(digits, [0]);
+    /// ```
+    pub fn write_radix_digits(&self, radix: u32, out: &mut Vec<u8>) {
+        out.clear();
+        write!(RawDigits(out), "{}", self.in_radix(radix)).unwrap();
+    }
+}
+
+/// Collects formatted digits as digit values rather than ASCII characters.
+struct RawDigits<'a>(&'a mut Vec<u8>);
+
+impl Write for RawDigits<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        // The digits are formatted as 0-9 and lower-case a-z.
+        self.0.extend(s.bytes().map(|byte| {
+            if byte <= b'9' {
+                byte - b'0'
+            } else {
+                byte - b'a' + 10
+            }
+        }));
+        Ok(())
+    }
 }
 
 impl IBig {
@@ -203,8 +320,47 @@ impl IBig {
             sign: self.sign(),
             magnitude: self.magnitude(),
             radix,
+            radix_powers: None,
         }
     }
+
+    /// String representation in a given radix.
+    ///
+    /// Shorthand for `self.in_radix(radix).to_string()`. Digits 10-35 are lower-case letters.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is not between 2 and 36 inclusive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ibig;
+    /// assert_eq!(ibig!(-83).to_radix_string(3), "-10002");
+    /// ```
+    #[inline]
+    pub fn to_radix_string(&self, radix: u32) -> String {
+        self.in_radix(radix).to_string()
+    }
+
+    /// String representation in a given radix, with upper-case letters.
+    ///
+    /// Shorthand for `format!("{:#}", self.in_radix(radix))`. Digits 10-35 are upper-case letters.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is not between 2 and 36 inclusive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ibig;
+    /// assert_eq!(ibig!(-0xabc).to_radix_string_upper(16), "-ABC");
+    /// ```
+    #[inline]
+    pub fn to_radix_string_upper(&self, radix: u32) -> String {
+        format!("{:#}", self.in_radix(radix))
+    }
 }
 
 /// Representation of a [UBig] or [IBig] in any radix between 2 and 36 inclusive.
@@ -214,12 +370,19 @@ impl IBig {
 /// The default format uses lower-case letters a-z for digits 10-35.
 /// The "alternative" format (`{:#}`) uses upper-case letters.
 ///
+/// Precision, as in `{:.5}`, is the minimum number of digits: shorter numbers are padded with
+/// leading zeros after the sign. Width, as in `{:5}`, then pads the whole result including the
+/// sign. The same applies to `Display`, `Binary`, `Octal`, `LowerHex` and `UpperHex` for [UBig]
+/// and [IBig].
+///
 /// # Examples
 ///
 /// ```
 /// # use ibig::{ibig, ubig};
 /// assert_eq!(format!("{}", ubig!(83).in_radix(3)), "10002");
 /// assert_eq!(format!("{:+010}", ubig!(35).in_radix(36)), "+00000000z");
+/// assert_eq!(format!("{:.4}", ibig!(-35).in_radix(36)), "-000z");
+/// assert_eq!(format!("{:>7.4}", ibig!(-35).in_radix(36)), "  -000z");
 /// // For bases 2, 8, 10, 16 we don't have to use `InRadix`:
 /// assert_eq!(format!("{:x}", ubig!(3000)), "bb8");
 /// assert_eq!(format!("{:x}", ibig!(-3000)), "-bb8");
@@ -228,6 +391,79 @@ pub struct InRadix<'a> {
     sign: Sign,
     magnitude: &'a UBig,
     radix: Digit,
+    // Powers of the radix cached by a `RadixFormatter`.
+    radix_powers: Option<&'a [UBig]>,
+}
+
+/// A formatter for many numbers in the same radix.
+///
+/// Formatting a large number in a radix that is not a power of 2 divides it by a tree of powers
+/// of the radix. [UBig::in_radix] computes those powers anew for every number. A
+/// `RadixFormatter` caches them, so that formatting many large numbers only computes them once.
+///
+/// The cache grows lazily to fit the largest number formatted so far, and is kept until the
+/// `RadixFormatter` is dropped.
+///
+/// # Examples
+///
+/// ```
+/// # use ibig::{fmt::RadixFormatter, ubig};
+/// let mut formatter = RadixFormatter::new(3);
+/// assert_eq!(format!("{}", formatter.format(&ubig!(83))), "10002");
+/// assert_eq!(format!("{:>6}", formatter.format(&ubig!(80))), "  2222");
+///
+/// let mut formatter = RadixFormatter::new(36);
+/// assert_eq!(format!("{:#}", formatter.format(&ubig!(35))), "Z");
+/// ```
+#[derive(Clone, Debug)]
+pub struct RadixFormatter {
+    radix: Digit,
+    // radix^((digits_per_word * CHUNK_LEN) << i)
+    radix_powers: Vec<UBig>,
+}
+
+impl RadixFormatter {
+    /// Create a formatter for a given radix.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is not between 2 and 36 inclusive.
+    #[inline]
+    pub fn new(radix: u32) -> RadixFormatter {
+        radix::check_radix_valid(radix);
+        RadixFormatter {
+            radix,
+            radix_powers: Vec::new(),
+        }
+    }
+
+    /// The radix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::fmt::RadixFormatter;
+    /// assert_eq!(RadixFormatter::new(7).radix(), 7);
+    /// ```
+    #[inline]
+    pub fn radix(&self) -> u32 {
+        self.radix
+    }
+
+    /// Representation of `x` in the radix, formatted the same way as [UBig::in_radix].
+    ///
+    /// Extends the cached powers of the radix if `x` needs more of them.
+    pub fn format<'a>(&'a mut self, x: &'a UBig) -> InRadix<'a> {
+        if !self.radix.is_power_of_two() {
+            non_power_two::extend_radix_powers(x, self.radix, &mut self.radix_powers);
+        }
+        InRadix {
+            sign: Positive,
+            magnitude: x,
+            radix: self.radix,
+            radix_powers: Some(&self.radix_powers),
+        }
+    }
 }
 
 /// Representation in a given radix with a prefix and digit case.
@@ -256,16 +492,25 @@ impl Display for InRadix<'_> {
             prefix: "",
             digit_case,
         }
-        .fmt(f)
+        .fmt_with_radix_powers(f, self.radix_powers)
     }
 }
 
 impl InRadixFull<'_> {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        self.fmt_with_radix_powers(f, None)
+    }
+
+    /// Format, using cached powers of the radix if available.
+    fn fmt_with_radix_powers(
+        &self,
+        f: &mut Formatter,
+        radix_powers: Option<&[UBig]>,
+    ) -> fmt::Result {
         if self.radix.is_power_of_two() {
             self.fmt_power_two(f)
         } else {
-            self.fmt_non_power_two(f)
+            self.fmt_non_power_two(f, radix_powers)
         }
     }
 
@@ -275,10 +520,13 @@ impl InRadixFull<'_> {
         f: &mut Formatter,
         prepared: &mut dyn PreparedForFormatting,
     ) -> fmt::Result {
-        let mut width = prepared.width();
+        let num_digits = prepared.width();
+        // Precision is the minimum number of digits.
+        let leading_zeros = f.precision().map_or(0, |p| p.saturating_sub(num_digits));
+        let mut width = num_digits + leading_zeros;
 
-        // Adding sign and prefix to width will not overflow, because Buffer::MAX_CAPACITY leaves
-        // (WORD_BITS - 1) spare bits before we would hit overflow.
+        // The precision can make width as large as usize::MAX, so adding sign and prefix may
+        // overflow. A saturated width still exceeds any requested minimum width.
         let sign = if self.sign == Negative {
             "-"
         } else if f.sign_plus() {
@@ -287,9 +535,12 @@ impl InRadixFull<'_> {
             ""
         };
         // In bytes, but it's OK because everything is ASCII.
-        width += sign.len() + self.prefix.len();
+        width = width.saturating_add(sign.len() + self.prefix.len());
 
-        let mut write_digits = |f| {
+        let mut write_digits = |f: &mut Formatter| {
+            for _ in 0..leading_zeros {
+                f.write_str("0")?;
+            }
             let mut digit_writer = DigitWriter::new(f, self.digit_case);
             prepared.write(&mut digit_writer)?;
             digit_writer.flush()
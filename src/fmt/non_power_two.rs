@@ -19,21 +19,36 @@ use static_assertions::const_assert;
 const CHUNK_LEN: usize = 16;
 
 impl InRadixFull<'_> {
-    pub(crate) fn fmt_non_power_two(&self, f: &mut Formatter) -> fmt::Result {
+    /// Format in a non-power-of-two radix.
+    ///
+    /// `radix_powers`, if given, must have been extended by `extend_radix_powers` for this number.
+    pub(crate) fn fmt_non_power_two(
+        &self,
+        f: &mut Formatter,
+        radix_powers: Option<&[UBig]>,
+    ) -> fmt::Result {
         debug_assert!(radix::is_radix_valid(self.radix) && !self.radix.is_power_of_two());
         match self.magnitude.repr() {
             Small(word) => {
                 let mut prepared = PreparedWord::new(*word, self.radix, 1);
                 self.format_prepared(f, &mut prepared)
             }
-            Large(buffer) => {
-                let radix_info = radix::radix_info(self.radix);
-                let max_digits = buffer.len() * (radix_info.digits_per_word + 1);
-                if max_digits <= CHUNK_LEN * radix_info.digits_per_word {
+            Double(_) | Large(_) => {
+                if is_medium(self.magnitude, self.radix) {
                     let mut prepared = PreparedMedium::new(self.magnitude, self.radix);
                     self.format_prepared(f, &mut prepared)
                 } else {
-                    let mut prepared = PreparedLarge::new(self.magnitude, self.radix);
+                    let local_powers;
+                    let radix_powers = match radix_powers {
+                        Some(radix_powers) => radix_powers,
+                        None => {
+                            let mut powers = Vec::new();
+                            extend_radix_powers(self.magnitude, self.radix, &mut powers);
+                            local_powers = powers;
+                            &local_powers
+                        }
+                    };
+                    let mut prepared = PreparedLarge::new(self.magnitude, self.radix, radix_powers);
                     self.format_prepared(f, &mut prepared)
                 }
             }
@@ -41,6 +56,47 @@ impl InRadixFull<'_> {
     }
 }
 
+/// Whether a number is certain to have no more than CHUNK_LEN * digits_per_word digits.
+fn is_medium(number: &UBig, radix: Digit) -> bool {
+    let radix_info = radix::radix_info(radix);
+    let max_digits = number.len() * (radix_info.digits_per_word + 1);
+    max_digits <= CHUNK_LEN * radix_info.digits_per_word
+}
+
+/// Extend `radix_powers` so that it contains every `radix^((digits_per_word * CHUNK_LEN) << i)`
+/// not exceeding `number`.
+///
+/// `radix_powers` may already contain some of the powers, possibly more than `number` needs.
+pub(crate) fn extend_radix_powers(number: &UBig, radix: Digit, radix_powers: &mut Vec<UBig>) {
+    debug_assert!(radix::is_radix_valid(radix) && !radix.is_power_of_two());
+    if is_medium(number, radix) {
+        return;
+    }
+    if radix_powers.is_empty() {
+        let radix_info = radix::radix_info(radix);
+        let chunk_power = UBig::from_word(radix_info.range_per_word).pow(CHUNK_LEN);
+        if chunk_power > *number {
+            return;
+        }
+        radix_powers.push(chunk_power);
+    }
+    loop {
+        let prev = radix_powers.last().unwrap();
+        // Avoid multiplication if we know prev * prev > number just by looking at lengths.
+        if 2 * prev.len() - 1 > number.len() {
+            break;
+        }
+        // 2 * prev.len() is at most 1 larger than number.len().
+        // It won't overflow because UBig::MAX_LEN is even.
+        const_assert!(UBig::MAX_LEN % 2 == 0);
+        let new = prev * prev;
+        if new > *number {
+            break;
+        }
+        radix_powers.push(new);
+    }
+}
+
 /// A `Word` prepared for formatting.
 struct PreparedWord {
     // digits[start_index..] actually used.
@@ -146,26 +202,26 @@ impl PreparedForFormatting for PreparedMedium {
 }
 
 /// A large number prepared for formatting.
-struct PreparedLarge {
+struct PreparedLarge<'a> {
     top_chunk: PreparedMedium,
     // radix^((digits_per_word * CHUNK_LEN) << i)
-    radix_powers: Vec<UBig>,
+    radix_powers: &'a [UBig],
     // little endian chunks: (i, (digits_per_word * CHUNK_LEN)<<i digit number)
     // decreasing in size, so there is a logarithmic number of them
     big_chunks: Vec<(usize, UBig)>,
     radix: Digit,
 }
 
-impl PreparedLarge {
-    /// Prepare a medium number for formatting in a non-power-of-2 radix.
-    fn new(number: &UBig, radix: Digit) -> PreparedLarge {
+impl<'a> PreparedLarge<'a> {
+    /// Prepare a large number for formatting in a non-power-of-2 radix.
+    ///
+    /// `radix_powers` must contain all the powers not exceeding `number`, and may contain more.
+    fn new(number: &UBig, radix: Digit, radix_powers: &'a [UBig]) -> PreparedLarge<'a> {
         debug_assert!(radix::is_radix_valid(radix) && !radix.is_power_of_two());
-        let radix_info = radix::radix_info(radix);
 
-        let mut radix_powers = Vec::new();
+        let radix_powers = &radix_powers[..radix_powers.partition_point(|p| p <= number)];
         let mut big_chunks = Vec::new();
-        let chunk_power = UBig::from_word(radix_info.range_per_word).pow(CHUNK_LEN);
-        if chunk_power > *number {
+        if radix_powers.is_empty() {
             return PreparedLarge {
                 top_chunk: PreparedMedium::new(number, radix),
                 radix_powers,
@@ -174,23 +230,6 @@ impl PreparedLarge {
             };
         }
 
-        radix_powers.push(chunk_power);
-        loop {
-            let prev = radix_powers.last().unwrap();
-            // Avoid multiplication if we know prev * prev > number just by looking at lengths.
-            if 2 * prev.len() - 1 > number.len() {
-                break;
-            }
-            // 2 * prev.len() is at most 1 larger than number.len().
-            // It won't overflow because UBig::MAX_LEN is even.
-            const_assert!(UBig::MAX_LEN % 2 == 0);
-            let new = prev * prev;
-            if new > *number {
-                break;
-            }
-            radix_powers.push(new);
-        }
-
         let mut power_iter = radix_powers.iter().enumerate().rev();
         let mut x = {
             let (i, p) = power_iter.next().unwrap();
@@ -253,7 +292,7 @@ impl PreparedLarge {
     }
 }
 
-impl PreparedForFormatting for PreparedLarge {
+impl PreparedForFormatting for PreparedLarge<'_> {
     fn width(&self) -> usize {
         let mut num_digits = self.top_chunk.width();
         let radix_info = radix::radix_info(self.radix);
@@ -278,6 +317,6 @@ fn ubig_to_chunk_buffer(x: &UBig) -> ([Word; CHUNK_LEN], usize) {
     let mut buffer = [0; CHUNK_LEN];
     let words = x.as_words();
     let buffer_len = words.len();
-    buffer[..buffer_len].copy_from_slice(words);
+    buffer[..buffer_len].copy_from_slice(&words);
     (buffer, buffer_len)
 }
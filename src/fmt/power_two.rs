@@ -4,7 +4,7 @@ use crate::{
     arch::word::Word,
     fmt::{digit_writer::DigitWriter, InRadixFull, PreparedForFormatting},
     math,
-    primitive::{WORD_BITS, WORD_BITS_USIZE},
+    primitive::{split_double_word, WORD_BITS, WORD_BITS_USIZE},
     radix::{self, Digit},
     ubig::Repr::*,
 };
@@ -19,6 +19,12 @@ impl InRadixFull<'_> {
                 let mut prepared = PreparedWord::new(*word, self.radix);
                 self.format_prepared(f, &mut prepared)
             }
+            Double(dword) => {
+                let (lo, hi) = split_double_word(*dword);
+                let words = [lo, hi];
+                let mut prepared = PreparedLarge::new(&words, self.radix);
+                self.format_prepared(f, &mut prepared)
+            }
             Large(buffer) => {
                 let mut prepared = PreparedLarge::new(buffer, self.radix);
                 self.format_prepared(f, &mut prepared)
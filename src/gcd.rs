@@ -1,8 +1,12 @@
 //! Greatest common divisor.
 
-use crate::{ibig::IBig, ops::DivRem, ubig::UBig};
+use crate::{ibig::IBig, ubig::UBig};
 use core::mem;
 
+/// In binary GCD, when the operands differ by more than this many bits, reduce the larger one
+/// by division rather than subtraction.
+const GCD_EUCLID_THRESHOLD_BITS: usize = 32;
+
 impl UBig {
     /// Greatest common divisor.
     ///
@@ -48,7 +52,12 @@ impl UBig {
             if a < b {
                 mem::swap(&mut a, &mut b);
             }
-            a -= &b;
+            if a.bit_len() - b.bit_len() > GCD_EUCLID_THRESHOLD_BITS {
+                // Subtraction would only remove a few bits per step: divide instead.
+                a %= &b;
+            } else {
+                a -= &b;
+            }
         }
 
         b << zeros
@@ -78,21 +87,65 @@ impl UBig {
     ///
     /// `ubig!(0).extended_gcd(&ubig!(0))` panics.
     pub fn extended_gcd(&self, rhs: &UBig) -> (UBig, IBig, IBig) {
+        let mut g = UBig::from_word(0);
+        let mut x = IBig::from(0u8);
+        let mut y = IBig::from(0u8);
+        self.extended_gcd_into(rhs, &mut g, &mut x, &mut y);
+        (g, x, y)
+    }
+
+    /// Greatest common divisors and the Bézout coefficients, written into existing numbers.
+    ///
+    /// Equivalent to `(*g, *x, *y) = self.extended_gcd(rhs)`. The previous values of `g`, `x`
+    /// and `y` are only overwritten; the computation still allocates its own temporaries, and
+    /// the memory of the outputs is not guaranteed to be reused.
+    ///
+    /// The outputs can't alias the inputs or each other: the borrow checker rejects that. To
+    /// replace an input by the gcd, compute into a separate number and swap it in with
+    /// [core::mem::swap].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ibig::{ibig, ubig};
+    /// let mut g = ubig!(0);
+    /// let mut x = ibig!(0);
+    /// let mut y = ibig!(0);
+    /// ubig!(12).extended_gcd_into(&ubig!(18), &mut g, &mut x, &mut y);
+    /// assert_eq!(g, ubig!(6));
+    /// assert_eq!(x * ibig!(12) + y * ibig!(18), ibig!(6));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if both `self` and `rhs` are 0.
+    pub fn extended_gcd_into(&self, rhs: &UBig, g: &mut UBig, x: &mut IBig, y: &mut IBig) {
         let zeros = match (self.trailing_zeros(), rhs.trailing_zeros()) {
             (None, None) => panic!("extended_gcd(0, 0)"),
-            (None, Some(_)) => return (rhs.clone(), 0u8.into(), 1u8.into()),
-            (Some(_), None) => return (self.clone(), 1u8.into(), 0u8.into()),
+            (None, Some(_)) => {
+                g.clone_from(rhs);
+                *x = IBig::from(0u8);
+                *y = IBig::from(1u8);
+                return;
+            }
+            (Some(_), None) => {
+                g.clone_from(self);
+                *x = IBig::from(1u8);
+                *y = IBig::from(0u8);
+                return;
+            }
             (Some(a_zeros), Some(b_zeros)) => a_zeros.min(b_zeros),
         };
 
         let u = self >> zeros;
         let v = rhs >> zeros;
         let mut a;
-        let mut b;
+        // Start b, bx, by from the outputs, so that the first round can use their buffers.
+        let mut b = mem::take(g);
         let mut ax;
         let mut ay;
-        let mut bx;
-        let mut by;
+        let (_, mut bx) = mem::take(x).into_sign_magnitude();
+        let (_, mut by) = mem::take(y).into_sign_magnitude();
 
         // Invariants:
         // gcd(a, b) == gcd(u, v)
@@ -103,26 +156,25 @@ impl UBig {
 
         // One round of Euclidean algorithm.
         if u <= v {
-            let (q, r) = (&v).div_rem(&u);
+            let mut q = UBig::from_word(0);
+            v.div_rem_into(&u, &mut q, &mut b);
             // u = 1 * u - 0 * v
             // r = v - q * u = (v-q) * u - (u-1) * v
             a = u.clone();
             ax = UBig::from_word(1);
             ay = UBig::from_word(0);
-            b = r;
-            bx = &v - q;
-            by = &u - UBig::from_word(1);
+            bx.clone_from(&v);
+            bx -= q;
+            by.clone_from(&u);
+            by -= UBig::from_word(1);
         } else {
-            let (q, r) = (&u).div_rem(&v);
+            u.div_rem_into(&v, &mut by, &mut b);
             // v = 0 * u + 1 * v = v * u - (u-1) * v
             // r = 1 * u - q * v
             a = v.clone();
             ax = v.clone();
             ay = &u - UBig::from_word(1);
-
-            b = r;
             bx = UBig::from_word(1);
-            by = q;
         }
 
         // At least one of a and b is odd (because gcd(u, v) is odd). Make b odd.
@@ -170,7 +222,149 @@ impl UBig {
             // ay >= 0
         }
 
-        (b << zeros, IBig::from(bx), -IBig::from(by))
+        b <<= zeros;
+        *g = b;
+        *x = IBig::from(bx);
+        *y = -IBig::from(by);
+    }
+
+    /// Modular inverse.
+    ///
+    /// Returns `x` such that `0 <= x < modulus` and `x * self` is congruent to 1 modulo `modulus`,
+    /// or `None` if `self` and `modulus` are not coprime.
+    ///
+    /// This is faster than [UBig::extended_gcd] because only one Bézout coefficient is computed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// assert_eq!(ubig!(7).mod_inverse(&ubig!(10)), Some(ubig!(3)));
+    /// assert_eq!(ubig!(2).mod_inverse(&ubig!(10)), None);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `modulus` is 0.
+    pub fn mod_inverse(&self, modulus: &UBig) -> Option<UBig> {
+        if *modulus == UBig::from_word(0) {
+            panic!("mod_inverse with modulus 0");
+        }
+
+        // Euclidean algorithm.
+        //
+        // Invariants:
+        // a ≡ (-1)^(k+1) * ax * self (mod modulus)
+        // b ≡ (-1)^k * bx * self (mod modulus)
+        // where k is the number of steps so far.
+        let mut a = modulus.clone();
+        let mut b = self % modulus;
+        let mut ax = UBig::from_word(0);
+        let mut bx = UBig::from_word(1);
+        let mut odd_steps = false;
+        while b != UBig::from_word(0) {
+            let (q, r) = a.div_rem(&b);
+            a = mem::replace(&mut b, r);
+            let x = ax + q * &bx;
+            ax = mem::replace(&mut bx, x);
+            odd_steps = !odd_steps;
+        }
+
+        if a != UBig::from_word(1) {
+            None
+        } else if odd_steps || ax == UBig::from_word(0) {
+            Some(ax)
+        } else {
+            Some(modulus - ax)
+        }
+    }
+
+    /// Least common multiple.
+    ///
+    /// The least common multiple of 0 and any number is 0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// assert_eq!(ubig!(12).lcm(&ubig!(18)), ubig!(36));
+    /// assert_eq!(ubig!(0).lcm(&ubig!(18)), ubig!(0));
+    /// ```
+    pub fn lcm(&self, rhs: &UBig) -> UBig {
+        self.gcd_lcm(rhs).1
+    }
+
+    /// Greatest common divisor and least common multiple.
+    ///
+    /// Faster than calling [UBig::gcd] and [UBig::lcm] separately because the greatest common
+    /// divisor is only computed once.
+    ///
+    /// Unlike [UBig::gcd], this doesn't panic when both numbers are 0: it returns `(0, 0)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// assert_eq!(ubig!(12).gcd_lcm(&ubig!(18)), (ubig!(6), ubig!(36)));
+    /// assert_eq!(ubig!(0).gcd_lcm(&ubig!(0)), (ubig!(0), ubig!(0)));
+    /// ```
+    pub fn gcd_lcm(&self, rhs: &UBig) -> (UBig, UBig) {
+        if self.is_zero() || rhs.is_zero() {
+            return (self + rhs, UBig::from_word(0));
+        }
+        let gcd = self.gcd(rhs);
+        let lcm = self / &gcd * rhs;
+        (gcd, lcm)
+    }
+
+    /// Greatest common divisor of all numbers from an iterator.
+    ///
+    /// Zeros are skipped, so the result is 0 only if there are no non-zero numbers, including
+    /// when the iterator is empty. Stops consuming the iterator once the result reaches 1.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ibig::{ubig, UBig};
+    /// assert_eq!(UBig::gcd_all([ubig!(12), ubig!(18), ubig!(27)]), ubig!(3));
+    /// assert_eq!(UBig::gcd_all([]), ubig!(0));
+    /// ```
+    pub fn gcd_all<I: IntoIterator<Item = UBig>>(iter: I) -> UBig {
+        let mut res = UBig::from_word(0);
+        for x in iter {
+            if res.is_zero() {
+                res = x;
+            } else if !x.is_zero() {
+                res = res.gcd(&x);
+            }
+            if res.is_one() {
+                break;
+            }
+        }
+        res
+    }
+
+    /// Least common multiple of all numbers from an iterator.
+    ///
+    /// The least common multiple of an empty iterator is 1. Stops consuming the iterator once the
+    /// result reaches 0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ibig::{ubig, UBig};
+    /// assert_eq!(UBig::lcm_all([ubig!(4), ubig!(6), ubig!(10)]), ubig!(60));
+    /// assert_eq!(UBig::lcm_all([]), ubig!(1));
+    /// ```
+    pub fn lcm_all<I: IntoIterator<Item = UBig>>(iter: I) -> UBig {
+        let mut res = UBig::from_word(1);
+        for x in iter {
+            res = res.lcm(&x);
+            if res.is_zero() {
+                break;
+            }
+        }
+        res
     }
 }
 
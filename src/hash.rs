@@ -0,0 +1,58 @@
+//! Stable hashing.
+
+use crate::{ibig::IBig, sign::Sign::*, ubig::UBig};
+use core::hash::Hasher;
+
+impl UBig {
+    /// Feed the number into `state` in a documented, stable format.
+    ///
+    /// Unlike the [Hash](core::hash::Hash) implementation, which depends on the internal
+    /// representation and may differ between versions and word sizes, this always writes
+    /// exactly:
+    /// 1. the length of [to_le_bytes](UBig::to_le_bytes) as a `u64` in little-endian order,
+    /// 2. the bytes of [to_le_bytes](UBig::to_le_bytes),
+    ///
+    /// each in a single call to [Hasher::write]. This format will not change, so combined with
+    /// a stable [Hasher] it can be used for content addressing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// # use std::hash::Hasher;
+    /// #[derive(Default)]
+    /// struct Bytes(Vec<u8>);
+    ///
+    /// impl Hasher for Bytes {
+    ///     fn write(&mut self, bytes: &[u8]) {
+    ///         self.0.extend_from_slice(bytes);
+    ///     }
+    ///     fn finish(&self) -> u64 {
+    ///         0
+    ///     }
+    /// }
+    ///
+    /// let mut state = Bytes::default();
+    /// ubig!(0x1234).stable_hash(&mut state);
+    /// assert_eq!(state.0, [2, 0, 0, 0, 0, 0, 0, 0, 0x34, 0x12]);
+    /// ```
+    pub fn stable_hash<H: Hasher>(&self, state: &mut H) {
+        let bytes = self.to_le_bytes();
+        state.write(&(bytes.len() as u64).to_le_bytes());
+        state.write(&bytes);
+    }
+}
+
+impl IBig {
+    /// Feed the number into `state` in a documented, stable format.
+    ///
+    /// Writes a single byte, 1 for negative numbers and 0 otherwise, followed by
+    /// [UBig::stable_hash] of the magnitude. This format will not change.
+    pub fn stable_hash<H: Hasher>(&self, state: &mut H) {
+        state.write(&[match self.sign() {
+            Positive => 0,
+            Negative => 1,
+        }]);
+        self.magnitude().stable_hash(state);
+    }
+}
@@ -23,11 +23,16 @@ use crate::{
 /// ```
 #[derive(Clone, Eq, Hash, PartialEq)]
 pub struct IBig {
+    /// Always `Positive` for zero.
     sign: Sign,
     magnitude: UBig,
 }
 
 impl IBig {
+    /// Create an `IBig` from a sign and magnitude.
+    ///
+    /// Zero is normalized to have a `Positive` sign, so that equality, ordering and hashing don't
+    /// depend on how a zero was produced.
     #[inline]
     pub(crate) fn from_sign_magnitude(mut sign: Sign, magnitude: UBig) -> IBig {
         if magnitude == UBig::from_word(0) {
@@ -36,16 +41,52 @@ impl IBig {
         IBig { sign, magnitude }
     }
 
+    /// The sign of `self`. Zero is [Sign::Positive].
+    ///
+    /// # Examples
+    /// ```
+    /// # use ibig::{ibig, Sign};
+    /// assert_eq!(ibig!(-500).sign(), Sign::Negative);
+    /// assert_eq!(ibig!(0).sign(), Sign::Positive);
+    /// ```
     #[inline]
-    pub(crate) fn sign(&self) -> Sign {
+    pub fn sign(&self) -> Sign {
         self.sign
     }
 
+    /// The absolute value, borrowed.
+    ///
+    /// Unlike [IBig::unsigned_abs], this doesn't consume `self`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ibig::{ibig, ubig};
+    /// let a = ibig!(-5);
+    /// assert_eq!(*a.magnitude(), ubig!(5));
+    /// ```
     #[inline]
-    pub(crate) fn magnitude(&self) -> &UBig {
+    pub fn magnitude(&self) -> &UBig {
         &self.magnitude
     }
 
+    /// Negate in place.
+    ///
+    /// Only the sign changes, so this never allocates.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ibig::ibig;
+    /// let mut a = ibig!(5);
+    /// a.negate();
+    /// assert_eq!(a, ibig!(-5));
+    /// ```
+    #[inline]
+    pub fn negate(&mut self) {
+        if self.magnitude != UBig::from_word(0) {
+            self.sign = -self.sign;
+        }
+    }
+
     #[inline]
     pub(crate) fn into_sign_magnitude(self) -> (Sign, UBig) {
         (self.sign, self.magnitude)
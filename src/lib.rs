@@ -63,11 +63,21 @@
 extern crate alloc;
 
 pub use crate::{
+    bits::BitView,
+    convert::LeBytes,
     ibig::IBig,
-    modular::{convert::IntoModulo, modulo::Modulo, modulo_ring::ModuloRing},
+    modular::{
+        convert::IntoModulo, crt::CrtBasis, modulo::Modulo, modulo_ring::ModuloRing,
+        pow_table::PowTable,
+    },
+    prepared_divisor::PreparedDivisor,
+    sign::Sign,
     ubig::UBig,
 };
 
+#[cfg(feature = "serde")]
+pub use crate::serde::BoundedUBig;
+
 mod add;
 mod add_ops;
 mod arch;
@@ -81,6 +91,7 @@ pub mod error;
 mod fast_divide;
 pub mod fmt;
 mod gcd;
+mod hash;
 mod helper_macros;
 mod ibig;
 mod macros;
@@ -90,9 +101,11 @@ mod modular;
 mod mul;
 mod mul_ops;
 mod num_traits;
+mod number_theory;
 pub mod ops;
 mod parse;
 mod pow;
+mod prepared_divisor;
 mod primitive;
 mod radix;
 mod shift;
@@ -2,16 +2,72 @@
 
 use crate::{
     add, cmp,
+    ibig::IBig,
     modular::{
         modulo::{Modulo, ModuloLarge, ModuloRepr, ModuloSmall, ModuloSmallRaw},
         modulo_ring::ModuloRingSmall,
     },
+    ubig::UBig,
 };
 use core::{
     cmp::Ordering,
+    iter::Sum,
     ops::{Add, AddAssign, Neg, Sub, SubAssign},
 };
 
+impl UBig {
+    /// Modular addition: `(self + rhs) mod modulus`.
+    ///
+    /// The result is in `0..modulus`. The operands don't need to be reduced.
+    ///
+    /// This is for one-off operations. For many operations with the same modulus, a
+    /// [ModuloRing](crate::ModuloRing) is faster because it precomputes the reduction.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `modulus` is 0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// assert_eq!(ubig!(7).add_mod(&ubig!(5), &ubig!(10)), ubig!(2));
+    /// ```
+    #[inline]
+    pub fn add_mod(&self, rhs: &UBig, modulus: &UBig) -> UBig {
+        (self + rhs) % modulus
+    }
+
+    /// Modular subtraction: `(self - rhs) mod modulus`.
+    ///
+    /// The result is in `0..modulus`, wrapping around when `self` is less than `rhs`. The
+    /// operands don't need to be reduced.
+    ///
+    /// This is for one-off operations. For many operations with the same modulus, a
+    /// [ModuloRing](crate::ModuloRing) is faster because it precomputes the reduction.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `modulus` is 0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// assert_eq!(ubig!(7).sub_mod(&ubig!(5), &ubig!(10)), ubig!(2));
+    /// assert_eq!(ubig!(5).sub_mod(&ubig!(7), &ubig!(10)), ubig!(8));
+    /// ```
+    pub fn sub_mod(&self, rhs: &UBig, modulus: &UBig) -> UBig {
+        let lhs = self % modulus;
+        let rhs = rhs % modulus;
+        if lhs >= rhs {
+            lhs - rhs
+        } else {
+            modulus - rhs + lhs
+        }
+    }
+}
+
 impl<'a> Neg for Modulo<'a> {
     type Output = Modulo<'a>;
 
@@ -161,6 +217,108 @@ impl<'a> SubAssign<&Modulo<'a>> for Modulo<'a> {
     }
 }
 
+/// Sum of values from the same ring.
+///
+/// # Panics
+///
+/// Panics if the iterator is empty, because then the ring is unknown.
+impl<'a> Sum for Modulo<'a> {
+    fn sum<I: Iterator<Item = Modulo<'a>>>(mut iter: I) -> Modulo<'a> {
+        let first = iter
+            .next()
+            .expect("sum of an empty iterator of Modulo values: the ring is unknown");
+        iter.fold(first, |acc, x| acc + x)
+    }
+}
+
+/// Sum of values from the same ring.
+///
+/// # Panics
+///
+/// Panics if the iterator is empty, because then the ring is unknown.
+impl<'a, 'b> Sum<&'b Modulo<'a>> for Modulo<'a> {
+    fn sum<I: Iterator<Item = &'b Modulo<'a>>>(mut iter: I) -> Modulo<'a> {
+        let first = iter
+            .next()
+            .expect("sum of an empty iterator of Modulo values: the ring is unknown");
+        iter.fold(first.clone(), |acc, x| acc + x)
+    }
+}
+
+/// Implement `Add`, `Sub`, `AddAssign` and `SubAssign` of a primitive type for `Modulo`.
+///
+/// `$convert` converts the primitive into the ring of a given `Modulo`.
+macro_rules! impl_add_sub_modulo_primitive {
+    ($t:ty, $convert:ident, $big:ty) => {
+        impl<'a> Add<$t> for Modulo<'a> {
+            type Output = Modulo<'a>;
+
+            #[inline]
+            fn add(mut self, rhs: $t) -> Modulo<'a> {
+                self.add_assign(rhs);
+                self
+            }
+        }
+
+        impl<'a> Add<$t> for &Modulo<'a> {
+            type Output = Modulo<'a>;
+
+            #[inline]
+            fn add(self, rhs: $t) -> Modulo<'a> {
+                self.clone().add(rhs)
+            }
+        }
+
+        impl<'a> AddAssign<$t> for Modulo<'a> {
+            #[inline]
+            fn add_assign(&mut self, rhs: $t) {
+                let rhs = self.$convert(&<$big>::from(rhs));
+                self.add_assign(&rhs)
+            }
+        }
+
+        impl<'a> Sub<$t> for Modulo<'a> {
+            type Output = Modulo<'a>;
+
+            #[inline]
+            fn sub(mut self, rhs: $t) -> Modulo<'a> {
+                self.sub_assign(rhs);
+                self
+            }
+        }
+
+        impl<'a> Sub<$t> for &Modulo<'a> {
+            type Output = Modulo<'a>;
+
+            #[inline]
+            fn sub(self, rhs: $t) -> Modulo<'a> {
+                self.clone().sub(rhs)
+            }
+        }
+
+        impl<'a> SubAssign<$t> for Modulo<'a> {
+            #[inline]
+            fn sub_assign(&mut self, rhs: $t) {
+                let rhs = self.$convert(&<$big>::from(rhs));
+                self.sub_assign(&rhs)
+            }
+        }
+    };
+}
+
+impl_add_sub_modulo_primitive!(u8, same_ring_from_ubig, UBig);
+impl_add_sub_modulo_primitive!(u16, same_ring_from_ubig, UBig);
+impl_add_sub_modulo_primitive!(u32, same_ring_from_ubig, UBig);
+impl_add_sub_modulo_primitive!(u64, same_ring_from_ubig, UBig);
+impl_add_sub_modulo_primitive!(u128, same_ring_from_ubig, UBig);
+impl_add_sub_modulo_primitive!(usize, same_ring_from_ubig, UBig);
+impl_add_sub_modulo_primitive!(i8, same_ring_from_ibig, IBig);
+impl_add_sub_modulo_primitive!(i16, same_ring_from_ibig, IBig);
+impl_add_sub_modulo_primitive!(i32, same_ring_from_ibig, IBig);
+impl_add_sub_modulo_primitive!(i64, same_ring_from_ibig, IBig);
+impl_add_sub_modulo_primitive!(i128, same_ring_from_ibig, IBig);
+impl_add_sub_modulo_primitive!(isize, same_ring_from_ibig, IBig);
+
 impl ModuloSmallRaw {
     /// -self
     #[inline]
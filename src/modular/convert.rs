@@ -10,7 +10,7 @@ use crate::{
         modulo::{Modulo, ModuloLarge, ModuloRepr, ModuloSmall, ModuloSmallRaw},
         modulo_ring::{ModuloRing, ModuloRingLarge, ModuloRingRepr, ModuloRingSmall},
     },
-    primitive::extend_word,
+    primitive::{extend_word, split_double_word},
     shift,
     sign::Sign::*,
     ubig::{Repr, UBig},
@@ -51,6 +51,25 @@ impl ModuloRing {
     pub fn from<T: IntoModulo>(&self, x: T) -> Modulo {
         x.into_modulo(self)
     }
+
+    /// Create an element of the ring from a possibly negative [IBig].
+    ///
+    /// `x` is reduced by Euclidean remainder, so the [residue](Modulo::residue) of the result is
+    /// the canonical non-negative residue in range `0..n`, even for negative `x`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::{ibig, ubig, ModuloRing};
+    /// let ring = ModuloRing::new(&ubig!(100));
+    /// assert_eq!(ring.from_ibig(&ibig!(-1)).residue(), ubig!(99));
+    /// assert_eq!(ring.from_ibig(&ibig!(-1234)).residue(), ubig!(66));
+    /// assert_eq!(ring.from_ibig(&ibig!(1234)).residue(), ubig!(34));
+    /// ```
+    #[inline]
+    pub fn from_ibig(&self, x: &IBig) -> Modulo<'_> {
+        x.rem_euclid_ubig(&self.modulus()).into_modulo(self)
+    }
 }
 
 impl ModuloRingSmall {
@@ -89,6 +108,14 @@ impl Modulo<'_> {
             ModuloRepr::Large(self_large) => self_large.residue(),
         }
     }
+
+    /// The modulus of the ring.
+    pub(crate) fn modulus(&self) -> UBig {
+        match self.repr() {
+            ModuloRepr::Small(self_small) => UBig::from_word(self_small.ring().modulus()),
+            ModuloRepr::Large(self_large) => self_large.ring().modulus(),
+        }
+    }
 }
 
 impl ModuloSmallRaw {
@@ -185,11 +212,36 @@ impl IntoModulo for &IBig {
     }
 }
 
+impl<'a> Modulo<'a> {
+    /// Convert `x` into the ring of `self`.
+    pub(crate) fn same_ring_from_ubig(&self, x: &UBig) -> Modulo<'a> {
+        match self.repr() {
+            ModuloRepr::Small(self_small) => ModuloSmall::from_ubig(x, self_small.ring()).into(),
+            ModuloRepr::Large(self_large) => {
+                ModuloLarge::from_ubig(x.clone(), self_large.ring()).into()
+            }
+        }
+    }
+
+    /// Convert `x` into the ring of `self`.
+    pub(crate) fn same_ring_from_ibig(&self, x: &IBig) -> Modulo<'a> {
+        let modulo = self.same_ring_from_ubig(x.magnitude());
+        match x.sign() {
+            Positive => modulo,
+            Negative => -modulo,
+        }
+    }
+}
+
 impl<'a> ModuloSmall<'a> {
     #[inline]
     pub(crate) fn from_ubig(x: &UBig, ring: &'a ModuloRingSmall) -> ModuloSmall<'a> {
         let raw = match x.repr() {
             Repr::Small(word) => ModuloSmallRaw::from_word(*word, ring),
+            Repr::Double(dword) => {
+                let (lo, hi) = split_double_word(*dword);
+                ModuloSmallRaw::from_large(&[lo, hi], ring)
+            }
             Repr::Large(words) => ModuloSmallRaw::from_large(words, ring),
         };
         ModuloSmall::new(raw, ring)
@@ -201,25 +253,26 @@ impl<'a> ModuloLarge<'a> {
         x <<= ring.shift() as usize;
         let modulus = ring.normalized_modulus();
         let mut vec = Vec::with_capacity(modulus.len());
-        match x.into_repr() {
-            Repr::Small(word) => vec.push(word),
-            Repr::Large(mut words) => {
-                if words.len() < modulus.len() {
-                    vec.extend(&*words);
-                } else {
-                    let mut allocation = MemoryAllocation::new(div::memory_requirement_exact(
-                        words.len(),
-                        modulus.len(),
-                    ));
-                    let mut memory = allocation.memory();
-                    let _overflow = div::div_rem_in_place(
-                        &mut words,
-                        modulus,
-                        ring.fast_div_top(),
-                        &mut memory,
-                    );
-                    vec.extend(&words[..modulus.len()]);
-                }
+        let words = match x.into_repr() {
+            Repr::Small(word) => {
+                vec.push(word);
+                None
+            }
+            Repr::Double(dword) => Some(Buffer::from_double_word(dword)),
+            Repr::Large(words) => Some(words),
+        };
+        if let Some(mut words) = words {
+            if words.len() < modulus.len() {
+                vec.extend(&*words);
+            } else {
+                let mut allocation = MemoryAllocation::new(div::memory_requirement_exact(
+                    words.len(),
+                    modulus.len(),
+                ));
+                let mut memory = allocation.memory();
+                let _overflow =
+                    div::div_rem_in_place(&mut words, modulus, ring.fast_div_top(), &mut memory);
+                vec.extend(&words[..modulus.len()]);
             }
         }
         vec.extend(iter::repeat(0).take(modulus.len() - vec.len()));
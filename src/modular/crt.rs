@@ -0,0 +1,143 @@
+//! Chinese remainder theorem.
+
+use crate::{ibig::IBig, modular::modulo_ring::ModuloRing, ops::RemEuclid, ubig::UBig};
+use alloc::vec::Vec;
+
+impl UBig {
+    /// Chinese remainder theorem.
+    ///
+    /// Given congruences `x ≡ residue (mod modulus)` as `(residue, modulus)` pairs, returns the
+    /// unique `x` such that `0 <= x < product of moduli`.
+    ///
+    /// Returns `None` if the moduli are not pairwise coprime.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::{ubig, UBig};
+    /// let x = UBig::crt(&[(ubig!(2), ubig!(3)), (ubig!(3), ubig!(5)), (ubig!(2), ubig!(7))]);
+    /// assert_eq!(x, Some(ubig!(23)));
+    /// assert_eq!(UBig::crt(&[(ubig!(1), ubig!(4)), (ubig!(2), ubig!(6))]), None);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if any modulus is 0.
+    pub fn crt(congruences: &[(UBig, UBig)]) -> Option<UBig> {
+        let mut result = UBig::from_word(0);
+        let mut product = UBig::from_word(1);
+        for (residue, modulus) in congruences {
+            if *modulus == UBig::from_word(0) {
+                panic!("crt with modulus 0");
+            }
+            // x * product + _ * modulus == gcd
+            let (gcd, x, _) = (&product % modulus).extended_gcd(modulus);
+            if gcd != UBig::from_word(1) {
+                return None;
+            }
+            // result + product * t ≡ residue (mod modulus)
+            let diff = IBig::from(residue % modulus) - IBig::from(&result % modulus);
+            let t: UBig = (diff * x)
+                .rem_euclid(IBig::from(modulus))
+                .try_into()
+                .unwrap();
+            result += &product * t;
+            product *= modulus;
+        }
+        Some(result)
+    }
+}
+
+/// A fixed set of pairwise coprime moduli for repeated application of the Chinese remainder
+/// theorem.
+///
+/// Uses Garner's algorithm: the modular inverses are computed once in [CrtBasis::new], so that
+/// [CrtBasis::combine] only needs multiplications and remainders.
+///
+/// # Examples
+///
+/// ```
+/// # use ibig::{ubig, CrtBasis};
+/// let basis = CrtBasis::new(&[ubig!(3), ubig!(5), ubig!(7)]).unwrap();
+/// assert_eq!(basis.product(), &ubig!(105));
+/// assert_eq!(basis.combine(&[ubig!(2), ubig!(3), ubig!(2)]), ubig!(23));
+/// assert_eq!(basis.combine(&[ubig!(1), ubig!(1), ubig!(1)]), ubig!(1));
+/// ```
+pub struct CrtBasis {
+    /// Rings for each modulus.
+    rings: Vec<ModuloRing>,
+    /// `prefix_products[i]` is the product of the first `i` moduli.
+    prefix_products: Vec<UBig>,
+    /// `inverses[i]` is the inverse of `prefix_products[i]` modulo the `i`-th modulus.
+    inverses: Vec<UBig>,
+    /// Product of all moduli.
+    product: UBig,
+}
+
+impl CrtBasis {
+    /// Create a basis from a list of moduli.
+    ///
+    /// Returns `None` if the moduli are not pairwise coprime.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::{ubig, CrtBasis};
+    /// assert!(CrtBasis::new(&[ubig!(4), ubig!(9), ubig!(25)]).is_some());
+    /// assert!(CrtBasis::new(&[ubig!(4), ubig!(9), ubig!(15)]).is_none());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if any modulus is 0.
+    pub fn new(moduli: &[UBig]) -> Option<CrtBasis> {
+        let mut rings = Vec::with_capacity(moduli.len());
+        let mut prefix_products = Vec::with_capacity(moduli.len());
+        let mut inverses = Vec::with_capacity(moduli.len());
+        let mut product = UBig::from_word(1);
+        for modulus in moduli {
+            let ring = ModuloRing::new(modulus);
+            let inverse = ring.from(&product).inverse()?.residue();
+            rings.push(ring);
+            inverses.push(inverse);
+            let next_product = &product * modulus;
+            prefix_products.push(product);
+            product = next_product;
+        }
+        Some(CrtBasis {
+            rings,
+            prefix_products,
+            inverses,
+            product,
+        })
+    }
+
+    /// The product of all moduli.
+    ///
+    /// Results of [CrtBasis::combine] are less than this.
+    #[inline]
+    pub fn product(&self) -> &UBig {
+        &self.product
+    }
+
+    /// Find the unique `x` such that `0 <= x < self.product()` and `x` is congruent to
+    /// `residues[i]` modulo the `i`-th modulus.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of residues is different from the number of moduli.
+    pub fn combine(&self, residues: &[UBig]) -> UBig {
+        assert!(
+            residues.len() == self.rings.len(),
+            "CrtBasis::combine: wrong number of residues"
+        );
+        let mut result = UBig::from_word(0);
+        for (i, residue) in residues.iter().enumerate() {
+            let ring = &self.rings[i];
+            // result + prefix_product * t ≡ residue (mod modulus)
+            let t = (ring.from(residue) - ring.from(&result)) * ring.from(&self.inverses[i]);
+            result += &self.prefix_products[i] * t.residue();
+        }
+        result
+    }
+}
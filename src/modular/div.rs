@@ -1,8 +1,7 @@
 use crate::{
     arch::word::Word,
-    ibig::IBig,
     modular::modulo::{Modulo, ModuloLarge, ModuloRepr, ModuloSmall, ModuloSmallRaw},
-    ops::RemEuclid,
+    primitive::{WORD_BITS, WORD_BITS_USIZE},
     ubig::UBig,
 };
 use core::ops::{Div, DivAssign};
@@ -26,6 +25,38 @@ impl<'a> Modulo<'a> {
             ModuloRepr::Large(self_large) => self_large.inverse().map(Into::into),
         }
     }
+
+    /// Checked division.
+    ///
+    /// The ring doesn't have to be a field, so not every non-zero value has an inverse.
+    /// Returns `None` if `rhs` is not invertible.
+    ///
+    /// The `/` operator panics in that case instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::{ModuloRing, ubig};
+    /// let ring = ModuloRing::new(&ubig!(10));
+    /// assert_eq!(ring.from(2).checked_div(&ring.from(3)), Some(ring.from(4)));
+    /// assert_eq!(ring.from(2).checked_div(&ring.from(4)), None);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` and `rhs` are from different rings.
+    pub fn checked_div(&self, rhs: &Modulo<'a>) -> Option<Modulo<'a>> {
+        match (self.repr(), rhs.repr()) {
+            (ModuloRepr::Small(self_small), ModuloRepr::Small(rhs_small)) => {
+                self_small.check_same_ring(rhs_small)
+            }
+            (ModuloRepr::Large(self_large), ModuloRepr::Large(rhs_large)) => {
+                self_large.check_same_ring(rhs_large)
+            }
+            _ => Modulo::panic_different_rings(),
+        }
+        rhs.inverse().map(|inv_rhs| self * inv_rhs)
+    }
 }
 
 impl<'a> Div<Modulo<'a>> for Modulo<'a> {
@@ -60,11 +91,9 @@ impl<'a> Div<&Modulo<'a>> for &Modulo<'a> {
 
     #[inline]
     fn div(self, rhs: &Modulo<'a>) -> Modulo<'a> {
-        // Clippy doesn't like that division is implemented using multiplication.
-        #[allow(clippy::suspicious_arithmetic_impl)]
-        match rhs.inverse() {
+        match self.checked_div(rhs) {
             None => panic!("Division by a non-invertible Modulo"),
-            Some(inv_rhs) => self * inv_rhs,
+            Some(res) => res,
         }
     }
 }
@@ -87,19 +116,23 @@ impl<'a> ModuloSmall<'a> {
     /// Inverse.
     fn inverse(&self) -> Option<ModuloSmall<'a>> {
         let a = self.residue();
-        let b = self.ring().modulus();
-        // TODO: Optimized `extended_gcd` for `Word`s.
-        // x * a + _ * b == gcd
-        let (gcd, x, _) = UBig::from(a).extended_gcd(&UBig::from(b));
-        if gcd == UBig::from_word(1) {
-            let res: Word = x.rem_euclid(IBig::from(b)).try_into().unwrap();
-            Some(ModuloSmall::new(
-                ModuloSmallRaw::from_word(res, self.ring()),
-                self.ring(),
-            ))
+        let m = self.ring().modulus();
+        let res = if m.is_power_of_two() && m != 1 {
+            if a & 1 == 0 {
+                return None;
+            }
+            inverse_word_mod_power_of_two(a) & (m - 1)
         } else {
-            None
-        }
+            // TODO: Optimized `mod_inverse` for `Word`s.
+            UBig::from(a)
+                .mod_inverse(&UBig::from(m))?
+                .try_into()
+                .unwrap()
+        };
+        Some(ModuloSmall::new(
+            ModuloSmallRaw::from_word(res, self.ring()),
+            self.ring(),
+        ))
     }
 }
 
@@ -107,14 +140,52 @@ impl<'a> ModuloLarge<'a> {
     /// Inverse.
     fn inverse(&self) -> Option<ModuloLarge<'a>> {
         let a = self.residue();
-        let b = self.ring().modulus();
-        let (gcd, x, _) = a.extended_gcd(&b);
-        if gcd == UBig::from_word(1) {
-            // TODO: Get rid of redundant remainder computations.
-            let res: UBig = x.rem_euclid(IBig::from(b)).try_into().unwrap();
-            Some(ModuloLarge::from_ubig(res, self.ring()))
+        let m = self.ring().modulus();
+        let res = if m.is_power_of_two() {
+            if !a.bit(0) {
+                return None;
+            }
+            inverse_mod_power_of_two(&a, m.trailing_zeros().unwrap())
         } else {
-            None
+            a.mod_inverse(&m)?
+        };
+        Some(ModuloLarge::from_ubig(res, self.ring()))
+    }
+}
+
+/// Inverse of an odd `a` modulo `2^WORD_BITS`.
+///
+/// Uses Newton's iteration `x' = x * (2 - a * x)`, which doubles the number of correct low bits
+/// in each step.
+fn inverse_word_mod_power_of_two(a: Word) -> Word {
+    debug_assert!(a & 1 == 1);
+    // a * a ≡ 1 (mod 8) for odd a, so the first 3 bits are correct.
+    let mut x = a;
+    let mut bits = 3;
+    while bits < WORD_BITS {
+        x = x.wrapping_mul(Word::wrapping_sub(2, a.wrapping_mul(x)));
+        bits *= 2;
+    }
+    x
+}
+
+/// Inverse of an odd `a` modulo `2^k`.
+///
+/// Uses Newton's iteration `x' = x * (2 - a * x)` starting from the inverse modulo
+/// `2^WORD_BITS`.
+fn inverse_mod_power_of_two(a: &UBig, k: usize) -> UBig {
+    debug_assert!(a.bit(0));
+    let mut x = UBig::from_word(inverse_word_mod_power_of_two(a.as_words()[0]));
+    let mut bits = WORD_BITS_USIZE;
+    loop {
+        bits = (2 * bits).min(k);
+        let modulus = UBig::from_word(1) << bits;
+        let mask = &modulus - UBig::from_word(1);
+        // 2 - a * x (mod 2^bits)
+        let t = (modulus + UBig::from_word(2) - ((a * &x) & &mask)) & &mask;
+        x = (x * t) & mask;
+        if bits == k {
+            return x;
         }
     }
 }
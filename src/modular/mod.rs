@@ -3,9 +3,11 @@
 mod add;
 mod cmp;
 pub(crate) mod convert;
+pub(crate) mod crt;
 mod div;
 mod fmt;
 pub(crate) mod modulo;
 pub(crate) mod modulo_ring;
 mod mul;
 mod pow;
+pub(crate) mod pow_table;
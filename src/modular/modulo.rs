@@ -4,11 +4,20 @@ use crate::{
     arch::word::Word,
     math,
     modular::modulo_ring::{ModuloRingLarge, ModuloRingSmall},
+    ubig::UBig,
 };
 use alloc::vec::Vec;
 
 /// Modular arithmetic.
 ///
+/// The modulus doesn't have to be prime, so division is only defined for invertible divisors.
+/// The `/` operator panics if the divisor is not invertible; use [Modulo::checked_div] to
+/// handle that case.
+///
+/// Operations on two `Modulo` values panic unless both come from the same
+/// [ModuloRing](crate::ModuloRing) instance, even if another ring has the same modulus. To move
+/// a value to another ring, convert its residue: `ring2.from(x.residue())`.
+///
 /// # Examples
 ///
 /// ```
@@ -58,8 +67,27 @@ impl<'a> Modulo<'a> {
     }
 
     /// Panics when trying to do operations on [Modulo] values from different rings.
+    ///
+    /// Used when the moduli are known to differ.
     pub(crate) fn panic_different_rings() -> ! {
-        panic!("Modulo values from different rings")
+        panic!("Modulo values from different rings: the moduli differ")
+    }
+
+    /// Panics when trying to do operations on [Modulo] values from different rings with the given
+    /// moduli.
+    pub(crate) fn panic_different_ring_instances(lhs_modulus: UBig, rhs_modulus: UBig) -> ! {
+        if lhs_modulus == rhs_modulus {
+            panic!(
+                "Modulo values from different ModuloRing instances with the same modulus {}; \
+                values must come from the same ModuloRing",
+                lhs_modulus
+            )
+        } else {
+            panic!(
+                "Modulo values from different rings: modulus {} vs {}",
+                lhs_modulus, rhs_modulus
+            )
+        }
     }
 }
 
@@ -122,7 +150,10 @@ impl<'a> ModuloSmall<'a> {
     #[inline]
     pub(crate) fn check_same_ring(&self, other: &ModuloSmall) {
         if self.ring() != other.ring() {
-            Modulo::panic_different_rings();
+            Modulo::panic_different_ring_instances(
+                UBig::from_word(self.ring().modulus()),
+                UBig::from_word(other.ring().modulus()),
+            );
         }
     }
 }
@@ -162,7 +193,7 @@ impl<'a> ModuloLarge<'a> {
     /// Checks that two values are from the same ring.
     pub(crate) fn check_same_ring(&self, other: &ModuloLarge) {
         if self.ring() != other.ring() {
-            Modulo::panic_different_rings();
+            Modulo::panic_different_ring_instances(self.ring().modulus(), other.ring().modulus());
         }
     }
 }
@@ -12,12 +12,23 @@ use core::cmp::Ordering;
 
 /// A ring of integers modulo a positive integer.
 ///
+/// Rings are compared by identity: two rings created separately are never equal, even with the
+/// same modulus, and their [Modulo](crate::Modulo) values can't be mixed. Compare
+/// [ModuloRing::modulus] to check for the same modulus.
+///
 /// # Examples
 ///
 /// ```
 /// # use ibig::{ModuloRing, ubig};
 /// let ring = ModuloRing::new(&ubig!(100));
 /// assert_eq!(ring.modulus(), ubig!(100));
+///
+/// let ring2 = ModuloRing::new(&ubig!(100));
+/// assert!(ring != ring2);
+/// assert_eq!(ring.modulus(), ring2.modulus());
+/// let x = ring.from(7);
+/// let y = ring2.from(x.residue());
+/// assert_eq!(y, ring2.from(7));
 /// ```
 pub struct ModuloRing(ModuloRingRepr);
 
@@ -54,6 +65,11 @@ impl ModuloRing {
     /// assert_eq!(ring.modulus(), ubig!(100));
     /// ```
     ///
+    /// The reciprocal of the modulus used for reduction is computed here once, and reused by
+    /// every operation in the ring.
+    ///
+    /// To move an owned modulus into the ring without copying it, use `n.into()`.
+    ///
     /// # Panics
     ///
     /// Panics if `n` is zero.
@@ -62,7 +78,9 @@ impl ModuloRing {
         match n.repr() {
             Repr::Small(0) => panic!("ModuloRing::new(0)"),
             Repr::Small(word) => ModuloRing(ModuloRingRepr::Small(ModuloRingSmall::new(*word))),
-            Repr::Large(words) => ModuloRing(ModuloRingRepr::Large(ModuloRingLarge::new(words))),
+            Repr::Double(_) | Repr::Large(_) => ModuloRing(ModuloRingRepr::Large(
+                ModuloRingLarge::from_vec(n.as_words().to_vec()),
+            )),
         }
     }
 
@@ -72,6 +90,35 @@ impl ModuloRing {
     }
 }
 
+impl From<UBig> for ModuloRing {
+    /// Create a new ring of integers modulo `n`, reusing the memory of `n`.
+    ///
+    /// Same as [ModuloRing::new], but avoids copying a large modulus. Because [ModuloRing] has an
+    /// inherent method named `from`, call this as `n.into()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::{ModuloRing, ubig};
+    /// let ring: ModuloRing = ubig!(100).into();
+    /// assert_eq!(ring.modulus(), ubig!(100));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is zero.
+    #[inline]
+    fn from(n: UBig) -> ModuloRing {
+        match n.into_repr() {
+            Repr::Large(buffer) => ModuloRing(ModuloRingRepr::Large(ModuloRingLarge::from_vec(
+                buffer.into_vec(),
+            ))),
+            Repr::Small(word) => ModuloRing::new(&UBig::from_word(word)),
+            Repr::Double(dword) => ModuloRing::new(&UBig::from_double_word(dword)),
+        }
+    }
+}
+
 impl ModuloRingSmall {
     /// Create a new small ring of integers modulo `n`.
     #[inline]
@@ -104,9 +151,8 @@ impl ModuloRingSmall {
 }
 
 impl ModuloRingLarge {
-    /// Create a new large ring of integers modulo `n`.
-    fn new(n: &[Word]) -> ModuloRingLarge {
-        let mut normalized_modulus = n.to_vec();
+    /// Create a new large ring of integers modulo `n`, normalizing `n` in place.
+    fn from_vec(mut normalized_modulus: Vec<Word>) -> ModuloRingLarge {
         let (shift, fast_div_top) = div::normalize_large(&mut normalized_modulus);
         ModuloRingLarge {
             normalized_modulus,
@@ -1,6 +1,7 @@
 use crate::{
     arch::word::Word,
     div,
+    ibig::IBig,
     memory::{self, Memory, MemoryAllocation},
     modular::{
         modulo::{Modulo, ModuloLarge, ModuloRepr, ModuloSmall, ModuloSmallRaw},
@@ -10,9 +11,38 @@ use crate::{
     primitive::extend_word,
     shift,
     sign::Sign::Positive,
+    ubig::UBig,
 };
 use alloc::alloc::Layout;
-use core::ops::{Mul, MulAssign};
+use core::{
+    iter::Product,
+    ops::{Mul, MulAssign},
+};
+
+impl UBig {
+    /// Modular multiplication: `(self * rhs) mod modulus`.
+    ///
+    /// The result is in `0..modulus`. The operands don't need to be reduced.
+    ///
+    /// This is for one-off operations. Each call divides the full product by the modulus; for
+    /// many operations with the same modulus, a [ModuloRing](crate::ModuloRing) is faster
+    /// because it precomputes the reduction.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `modulus` is 0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// assert_eq!(ubig!(7).mul_mod(&ubig!(5), &ubig!(10)), ubig!(5));
+    /// ```
+    #[inline]
+    pub fn mul_mod(&self, rhs: &UBig, modulus: &UBig) -> UBig {
+        (self * rhs) % modulus
+    }
+}
 
 impl<'a> Mul<Modulo<'a>> for Modulo<'a> {
     type Output = Modulo<'a>;
@@ -78,6 +108,84 @@ impl<'a> MulAssign<&Modulo<'a>> for Modulo<'a> {
     }
 }
 
+/// Product of values from the same ring.
+///
+/// # Panics
+///
+/// Panics if the iterator is empty, because then the ring is unknown.
+impl<'a> Product for Modulo<'a> {
+    fn product<I: Iterator<Item = Modulo<'a>>>(mut iter: I) -> Modulo<'a> {
+        let first = iter
+            .next()
+            .expect("product of an empty iterator of Modulo values: the ring is unknown");
+        iter.fold(first, |acc, x| acc * x)
+    }
+}
+
+/// Product of values from the same ring.
+///
+/// # Panics
+///
+/// Panics if the iterator is empty, because then the ring is unknown.
+impl<'a, 'b> Product<&'b Modulo<'a>> for Modulo<'a> {
+    fn product<I: Iterator<Item = &'b Modulo<'a>>>(mut iter: I) -> Modulo<'a> {
+        let first = iter
+            .next()
+            .expect("product of an empty iterator of Modulo values: the ring is unknown");
+        iter.fold(first.clone(), |acc, x| acc * x)
+    }
+}
+
+/// Implement `Mul` and `MulAssign` of a primitive type for `Modulo`.
+///
+/// `$convert` converts the primitive into the ring of a given `Modulo`.
+macro_rules! impl_mul_modulo_primitive {
+    ($t:ty, $convert:ident, $big:ty) => {
+        impl<'a> Mul<$t> for Modulo<'a> {
+            type Output = Modulo<'a>;
+
+            /// Multiply by a scalar, which is interpreted modulo the ring modulus.
+            #[inline]
+            fn mul(mut self, rhs: $t) -> Modulo<'a> {
+                self.mul_assign(rhs);
+                self
+            }
+        }
+
+        impl<'a> Mul<$t> for &Modulo<'a> {
+            type Output = Modulo<'a>;
+
+            /// Multiply by a scalar, which is interpreted modulo the ring modulus.
+            #[inline]
+            fn mul(self, rhs: $t) -> Modulo<'a> {
+                self.clone().mul(rhs)
+            }
+        }
+
+        impl<'a> MulAssign<$t> for Modulo<'a> {
+            /// Multiply by a scalar, which is interpreted modulo the ring modulus.
+            #[inline]
+            fn mul_assign(&mut self, rhs: $t) {
+                let rhs = self.$convert(&<$big>::from(rhs));
+                self.mul_assign(&rhs)
+            }
+        }
+    };
+}
+
+impl_mul_modulo_primitive!(u8, same_ring_from_ubig, UBig);
+impl_mul_modulo_primitive!(u16, same_ring_from_ubig, UBig);
+impl_mul_modulo_primitive!(u32, same_ring_from_ubig, UBig);
+impl_mul_modulo_primitive!(u64, same_ring_from_ubig, UBig);
+impl_mul_modulo_primitive!(u128, same_ring_from_ubig, UBig);
+impl_mul_modulo_primitive!(usize, same_ring_from_ubig, UBig);
+impl_mul_modulo_primitive!(i8, same_ring_from_ibig, IBig);
+impl_mul_modulo_primitive!(i16, same_ring_from_ibig, IBig);
+impl_mul_modulo_primitive!(i32, same_ring_from_ibig, IBig);
+impl_mul_modulo_primitive!(i64, same_ring_from_ibig, IBig);
+impl_mul_modulo_primitive!(i128, same_ring_from_ibig, IBig);
+impl_mul_modulo_primitive!(isize, same_ring_from_ibig, IBig);
+
 impl ModuloSmallRaw {
     #[inline]
     pub(crate) const fn mul(self, other: ModuloSmallRaw, ring: &ModuloRingSmall) -> ModuloSmallRaw {
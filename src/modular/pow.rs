@@ -1,17 +1,145 @@
 use crate::{
     arch::word::Word,
     ibig::IBig,
-    math,
     memory::{self, MemoryAllocation},
     modular::{
         modulo::{Modulo, ModuloLarge, ModuloRepr, ModuloSmall, ModuloSmallRaw},
-        modulo_ring::ModuloRingSmall,
+        modulo_ring::{ModuloRing, ModuloRingSmall},
     },
-    primitive::{double_word, split_double_word, WORD_BITS, WORD_BITS_USIZE},
+    primitive::{WORD_BITS, WORD_BITS_USIZE},
     sign::Sign::*,
     ubig::{Repr::*, UBig},
 };
 
+impl UBig {
+    /// Modular exponentiation: `self^exp mod modulus`.
+    ///
+    /// The result is in `0..modulus`.
+    ///
+    /// Odd and even moduli use the same [ModuloRing] arithmetic, which reduces by a precomputed
+    /// reciprocal of the modulus and doesn't require the modulus to be odd. A power of two
+    /// modulus `2^k` is handled separately by keeping the low `k` bits of each product.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `modulus` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// assert_eq!(ubig!(3).pow_mod(&ubig!(4), &ubig!(10)), ubig!(1));
+    /// assert_eq!(ubig!(3).pow_mod(&ubig!(5), &ubig!(64)), ubig!(51));
+    /// ```
+    pub fn pow_mod(&self, exp: &UBig, modulus: &UBig) -> UBig {
+        if modulus.is_power_of_two() {
+            let k = modulus.trailing_zeros().unwrap();
+            self.pow_mod_power_of_two(exp, k)
+        } else {
+            let ring = ModuloRing::new(modulus);
+            ring.from(self).pow(exp).residue()
+        }
+    }
+
+    /// Modular exponentiation with the exponent given as big-endian bytes:
+    /// `self^exp mod modulus`.
+    ///
+    /// Equivalent to `self.pow_mod(&UBig::from_be_bytes(exp_be), modulus)`, but walks the bits of
+    /// the exponent directly from the bytes, most significant first, without constructing a
+    /// [UBig] exponent. Leading zero bytes are ignored; an empty slice is the exponent 0.
+    ///
+    /// The result is in `0..modulus`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `modulus` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// // 3^0x0100 = 3^256
+    /// assert_eq!(ubig!(3).pow_mod_bytes(&[0x01, 0x00], &ubig!(1000)), ubig!(521));
+    /// assert_eq!(ubig!(3).pow_mod_bytes(&[], &ubig!(10)), ubig!(1));
+    /// ```
+    pub fn pow_mod_bytes(&self, exp_be: &[u8], modulus: &UBig) -> UBig {
+        let ring = ModuloRing::new(modulus);
+        let base = ring.from(self);
+        let start = exp_be
+            .iter()
+            .position(|&byte| byte != 0)
+            .unwrap_or(exp_be.len());
+        let exp_be = &exp_be[start..];
+        match base.repr() {
+            ModuloRepr::Small(base_small) => {
+                let ring = base_small.ring();
+                let mut val = match exp_be.first() {
+                    None => ModuloSmallRaw::from_word(1, ring),
+                    Some(&top) => base_small.raw().pow_word(Word::from(top), ring),
+                };
+                for &byte in exp_be.iter().skip(1) {
+                    val = val.pow_helper(8, base_small.raw(), Word::from(byte), ring);
+                }
+                UBig::from_word(ModuloSmall::new(val, ring).residue())
+            }
+            ModuloRepr::Large(base_large) => {
+                let bit_len = match exp_be.first() {
+                    None => return UBig::from_word(1),
+                    Some(top) => exp_be.len() * 8 - top.leading_zeros() as usize,
+                };
+                if bit_len == 1 {
+                    return base_large.residue();
+                }
+                let bit = |i: usize| exp_be[exp_be.len() - 1 - i / 8] & (1 << (i % 8)) != 0;
+                base_large.pow_bits(bit_len, bit).residue()
+            }
+        }
+    }
+
+    /// `self^exp mod 2^k`.
+    fn pow_mod_power_of_two(&self, exp: &UBig, k: usize) -> UBig {
+        let mut res = UBig::from_word(1).truncate_bits(k);
+        let mut base = self.truncate_bits(k);
+        let exp_bits = exp.bit_len();
+        for i in 0..exp_bits {
+            if i != 0 {
+                base = (&base * &base).truncate_bits(k);
+            }
+            // An even base vanishes after at most log2(k) squarings. The top bit of exp is set,
+            // so the result is then 0.
+            if base.is_zero() {
+                return base;
+            }
+            if exp.bit(i) {
+                res = (&res * &base).truncate_bits(k);
+            }
+        }
+        res
+    }
+}
+
+impl IBig {
+    /// Modular exponentiation: `self^exp mod modulus`.
+    ///
+    /// A negative base is first reduced to its Euclidean remainder, so the result is the
+    /// canonical non-negative residue in `0..modulus`. See [UBig::pow_mod].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `modulus` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::{ibig, ubig};
+    /// assert_eq!(ibig!(-2).pow_mod(&ubig!(3), &ubig!(10)), ubig!(2));
+    /// assert_eq!(ibig!(8).pow_mod(&ubig!(3), &ubig!(10)), ubig!(2));
+    /// ```
+    pub fn pow_mod(&self, exp: &UBig, modulus: &UBig) -> UBig {
+        self.rem_euclid_ubig(modulus).pow_mod(exp, modulus)
+    }
+}
+
 impl<'a> Modulo<'a> {
     /// Exponentiation.
     ///
@@ -58,6 +186,47 @@ impl<'a> Modulo<'a> {
             },
         }
     }
+
+    /// Multiplicative order: the smallest positive `k` such that `self^k == 1`.
+    ///
+    /// Returns `None` if `self` is not invertible.
+    ///
+    /// The order divides Euler's totient of the modulus, so this factors the modulus and then its
+    /// totient with [UBig::factor]. Both factorizations must be feasible for this to finish in
+    /// reasonable time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::{ModuloRing, ubig};
+    /// let ring = ModuloRing::new(&ubig!(7));
+    /// assert_eq!(ring.from(2).order(), Some(ubig!(3)));
+    /// assert_eq!(ring.from(3).order(), Some(ubig!(6)));
+    /// assert_eq!(ring.from(0).order(), None);
+    /// ```
+    pub fn order(&self) -> Option<UBig> {
+        let one = UBig::from_word(1);
+        let modulus = self.modulus();
+        if modulus == one {
+            return Some(one);
+        }
+        if self.residue().gcd(&modulus) != one {
+            return None;
+        }
+
+        let unit = self.same_ring_from_ubig(&one);
+        let mut order = modulus.euler_phi();
+        for (p, multiplicity) in order.factor() {
+            for _ in 0..multiplicity {
+                let candidate = &order / &p;
+                if self.pow(&candidate) != unit {
+                    break;
+                }
+                order = candidate;
+            }
+        }
+        Some(order)
+    }
 }
 
 impl ModuloSmallRaw {
@@ -139,8 +308,21 @@ impl<'a> ModuloLarge<'a> {
     fn pow_nontrivial(&self, exp: &UBig) -> ModuloLarge<'a> {
         debug_assert!(*exp >= UBig::from_word(2));
 
+        let exp_words = exp.as_words();
+        self.pow_bits(exp.bit_len(), |i| {
+            exp_words[i / WORD_BITS_USIZE] & (1 << (i % WORD_BITS_USIZE)) != 0
+        })
+    }
+
+    /// Exponentiation by an exponent of `bit_len >= 2` bits given by `bit(i)`.
+    fn pow_bits<F>(&self, bit_len: usize, bit: F) -> ModuloLarge<'a>
+    where
+        F: Fn(usize) -> bool,
+    {
+        debug_assert!(bit_len >= 2 && bit(bit_len - 1));
+
         let n = self.ring().normalized_modulus().len();
-        let window_len = ModuloLarge::choose_pow_window_len(exp.bit_len());
+        let window_len = ModuloLarge::choose_pow_window_len(bit_len);
 
         // Precomputed table of small odd powers up to 2^window_len, starting from self^3.
         #[allow(clippy::redundant_closure)]
@@ -175,27 +357,17 @@ impl<'a> ModuloLarge<'a> {
             ));
         }
 
-        let exp_words = exp.as_words();
         // We already have self^2 in val.
-        // exp.bit_len() >= 2 because exp >= 2.
-        let mut bit = exp.bit_len() - 2;
+        let mut bit_idx = bit_len - 2;
 
         loop {
-            // val = self ^ exp[bit..] ignoring the lowest bit
-            let word_idx = bit / WORD_BITS_USIZE;
-            let bit_idx = (bit % WORD_BITS_USIZE) as u32;
-            let cur_word = exp_words[word_idx];
-            if cur_word & (1 << bit_idx) != 0 {
-                let next_word = if word_idx == 0 {
-                    0
-                } else {
-                    exp_words[word_idx - 1]
-                };
+            // val = self ^ exp[bit_idx..] ignoring the lowest bit
+            if bit(bit_idx) {
                 // Get a window of window_len bits, with top bit of 1.
-                let (mut window, _) = split_double_word(
-                    double_word(next_word, cur_word) >> (bit_idx + 1 + WORD_BITS - window_len),
-                );
-                window &= math::ones::<Word>(window_len);
+                let mut window: Word = 0;
+                for i in 0..window_len as usize {
+                    window = window << 1 | Word::from(i <= bit_idx && bit(bit_idx - i));
+                }
                 // Shift right to make the window odd.
                 let num_bits = window_len - window.trailing_zeros();
                 window >>= window_len - num_bits;
@@ -203,8 +375,8 @@ impl<'a> ModuloLarge<'a> {
                 for _ in 0..num_bits - 1 {
                     val.square_in_place(&mut memory);
                 }
-                bit -= (num_bits as usize) - 1;
-                // Now val = self ^ exp[bit..] ignoring the num_bits lowest bits.
+                bit_idx -= (num_bits as usize) - 1;
+                // Now val = self ^ exp[bit_idx..] ignoring the num_bits lowest bits.
                 // val = val * self^window from precomputed table.
                 debug_assert!(window & 1 == 1);
                 let entry_idx = (window >> 1) as usize;
@@ -215,11 +387,11 @@ impl<'a> ModuloLarge<'a> {
                 };
                 val.mul_normalized_in_place(entry, &mut memory);
             }
-            // val = self ^ exp[bit..]
-            if bit == 0 {
+            // val = self ^ exp[bit_idx..]
+            if bit_idx == 0 {
                 break;
             }
-            bit -= 1;
+            bit_idx -= 1;
             val.square_in_place(&mut memory);
         }
         val
@@ -0,0 +1,109 @@
+//! Exponentiation of a fixed base.
+
+use crate::{modular::modulo::Modulo, ubig::UBig};
+use alloc::vec::Vec;
+
+/// Precomputed powers `base^(2^i)` of a fixed base for repeated exponentiation.
+///
+/// [Modulo::pow] squares once per bit of the exponent and multiplies by the base about once per
+/// window of bits. With the squares precomputed by [Modulo::precompute_powers],
+/// [PowTable::pow] only multiplies together the powers for the 1 bits of the exponent, which is
+/// about half as many multiplications as there are bits.
+///
+/// The table holds as many powers as the modulus has bits, so it takes about as much memory as
+/// `modulus.bit_len()` residues, and computing it costs about as much as one exponentiation.
+/// It pays off when the same base is raised to many exponents. Exponents longer than the
+/// modulus are still supported, but their excess bits are handled by [Modulo::pow].
+///
+/// # Examples
+///
+/// ```
+/// # use ibig::{ubig, ModuloRing};
+/// let ring = ModuloRing::new(&ubig!(1000003));
+/// let g = ring.from(2);
+/// let table = g.precompute_powers();
+/// for exp in [ubig!(0), ubig!(17), ubig!(999999), ubig!(1) << 100] {
+///     assert_eq!(table.pow(&exp), g.pow(&exp));
+/// }
+/// ```
+#[derive(Clone)]
+pub struct PowTable<'a> {
+    /// `powers[i]` is `base^(2^i)`. Never empty.
+    powers: Vec<Modulo<'a>>,
+}
+
+impl<'a> Modulo<'a> {
+    /// Precompute `self^(2^i)` for fast exponentiation of `self` by many exponents.
+    ///
+    /// See [PowTable] for the time and memory tradeoff.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::{ubig, ModuloRing};
+    /// let ring = ModuloRing::new(&ubig!(100));
+    /// let table = ring.from(3).precompute_powers();
+    /// assert_eq!(table.pow(&ubig!(4)), ring.from(81));
+    /// ```
+    pub fn precompute_powers(&self) -> PowTable<'a> {
+        let len = self.modulus().bit_len().max(1);
+        let mut powers = Vec::with_capacity(len);
+        powers.push(self.clone());
+        while powers.len() < len {
+            let prev = powers.last().unwrap();
+            let next = prev * prev;
+            powers.push(next);
+        }
+        PowTable { powers }
+    }
+}
+
+impl<'a> PowTable<'a> {
+    /// The base.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::{ubig, ModuloRing};
+    /// let ring = ModuloRing::new(&ubig!(100));
+    /// assert_eq!(ring.from(3).precompute_powers().base(), &ring.from(3));
+    /// ```
+    #[inline]
+    pub fn base(&self) -> &Modulo<'a> {
+        &self.powers[0]
+    }
+
+    /// Exponentiation of the base: `base^exp`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::{ubig, ModuloRing};
+    /// let ring = ModuloRing::new(&ubig!(100));
+    /// let table = ring.from(3).precompute_powers();
+    /// assert_eq!(table.pow(&ubig!(0)), ring.from(1));
+    /// assert_eq!(table.pow(&ubig!(5)), ring.from(43));
+    /// ```
+    pub fn pow(&self, exp: &UBig) -> Modulo<'a> {
+        let len = self.powers.len();
+        let mut res: Option<Modulo<'a>> = None;
+        for (i, power) in self.powers.iter().enumerate().take(exp.bit_len()) {
+            if exp.bit(i) {
+                res = Some(match res {
+                    None => power.clone(),
+                    Some(res) => res * power,
+                });
+            }
+        }
+        if exp.bit_len() > len {
+            // base^exp = base^(exp mod 2^len) * (base^(2^len))^(exp >> len)
+            let last = &self.powers[len - 1];
+            let high = (last * last).pow(&(exp >> len));
+            res = Some(match res {
+                None => high,
+                Some(res) => res * high,
+            });
+        }
+        res.unwrap_or_else(|| self.base().same_ring_from_ubig(&UBig::from_word(1)))
+    }
+}
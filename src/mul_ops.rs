@@ -1,16 +1,17 @@
 //! Multiplication operators.
 
 use crate::{
-    arch::word::Word,
+    arch::word::{DoubleWord, Word},
     buffer::Buffer,
     helper_macros,
     ibig::IBig,
     memory::MemoryAllocation,
     mul,
-    primitive::{extend_word, PrimitiveSigned, PrimitiveUnsigned},
+    primitive::{double_word, extend_word, split_double_word, PrimitiveSigned, PrimitiveUnsigned},
     sign::Sign::{self, *},
     ubig::{Repr::*, UBig},
 };
+use alloc::vec::Vec;
 use core::{
     mem,
     ops::{Mul, MulAssign},
@@ -24,8 +25,13 @@ impl Mul<UBig> for UBig {
     fn mul(self, rhs: UBig) -> UBig {
         match (self.into_repr(), rhs.into_repr()) {
             (Small(word0), Small(word1)) => UBig::mul_word(word0, word1),
+            (Small(word0), Double(dword1)) => UBig::mul_double_word_word(dword1, word0),
             (Small(word0), Large(buffer1)) => UBig::mul_large_word(buffer1, word0),
+            (Double(dword0), Small(word1)) => UBig::mul_double_word_word(dword0, word1),
+            (Double(dword0), Double(dword1)) => UBig::mul_double_word(dword0, dword1),
+            (Double(dword0), Large(buffer1)) => UBig::mul_large_double_word(&buffer1, dword0),
             (Large(buffer0), Small(word1)) => UBig::mul_large_word(buffer0, word1),
+            (Large(buffer0), Double(dword1)) => UBig::mul_large_double_word(&buffer0, dword1),
             (Large(buffer0), Large(buffer1)) => UBig::mul_large(&buffer0, &buffer1),
         }
     }
@@ -38,8 +44,13 @@ impl Mul<&UBig> for UBig {
     fn mul(self, rhs: &UBig) -> UBig {
         match (self.into_repr(), rhs.repr()) {
             (Small(word0), Small(word1)) => UBig::mul_word(word0, *word1),
+            (Small(word0), Double(dword1)) => UBig::mul_double_word_word(*dword1, word0),
             (Small(word0), Large(buffer1)) => UBig::mul_large_word(buffer1.clone(), word0),
+            (Double(dword0), Small(word1)) => UBig::mul_double_word_word(dword0, *word1),
+            (Double(dword0), Double(dword1)) => UBig::mul_double_word(dword0, *dword1),
+            (Double(dword0), Large(buffer1)) => UBig::mul_large_double_word(buffer1, dword0),
             (Large(buffer0), Small(word1)) => UBig::mul_large_word(buffer0, *word1),
+            (Large(buffer0), Double(dword1)) => UBig::mul_large_double_word(&buffer0, *dword1),
             (Large(buffer0), Large(buffer1)) => UBig::mul_large(&buffer0, buffer1),
         }
     }
@@ -61,8 +72,13 @@ impl Mul<&UBig> for &UBig {
     fn mul(self, rhs: &UBig) -> UBig {
         match (self.repr(), rhs.repr()) {
             (Small(word0), Small(word1)) => UBig::mul_word(*word0, *word1),
+            (Small(word0), Double(dword1)) => UBig::mul_double_word_word(*dword1, *word0),
             (Small(word0), Large(buffer1)) => UBig::mul_large_word(buffer1.clone(), *word0),
+            (Double(dword0), Small(word1)) => UBig::mul_double_word_word(*dword0, *word1),
+            (Double(dword0), Double(dword1)) => UBig::mul_double_word(*dword0, *dword1),
+            (Double(dword0), Large(buffer1)) => UBig::mul_large_double_word(buffer1, *dword0),
             (Large(buffer0), Small(word1)) => UBig::mul_large_word(buffer0.clone(), *word1),
+            (Large(buffer0), Double(dword1)) => UBig::mul_large_double_word(buffer0, *dword1),
             (Large(buffer0), Large(buffer1)) => UBig::mul_large(buffer0, buffer1),
         }
     }
@@ -298,11 +314,144 @@ impl_mul_ibig_primitive!(i64);
 impl_mul_ibig_primitive!(i128);
 impl_mul_ibig_primitive!(isize);
 
+impl UBig {
+    /// Checked multiplication.
+    ///
+    /// Returns `None` if the product would exceed [UBig::MAX_BIT_LEN] bits, where `*` would
+    /// panic.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// assert_eq!(ubig!(2).checked_mul(&ubig!(3)), Some(ubig!(6)));
+    /// ```
+    pub fn checked_mul(&self, rhs: &UBig) -> Option<UBig> {
+        // The product has either self_bits + rhs_bits or one fewer bits.
+        let bits = self.bit_len() + rhs.bit_len();
+        if bits <= UBig::MAX_BIT_LEN {
+            Some(self * rhs)
+        } else if bits - 1 > UBig::MAX_BIT_LEN {
+            None
+        } else {
+            // Borderline: self * rhs = 2 * (self * (rhs >> 1)) + self * (rhs & 1), where the first
+            // product always fits.
+            let half = self * (rhs >> 1);
+            if half.bit_len() >= UBig::MAX_BIT_LEN {
+                return None;
+            }
+            let double = half << 1;
+            if rhs.bit(0) {
+                double.checked_add(self)
+            } else {
+                Some(double)
+            }
+        }
+    }
+
+    /// Product of many numbers.
+    ///
+    /// The factors are multiplied in a balanced binary tree, so that operands of similar sizes
+    /// are combined and fast multiplication algorithms apply. This is much faster than a left
+    /// fold when there are many similarly sized factors, e.g. for `2 * 3 * ... * n`. Only
+    /// `O(log n)` partial products are held at a time.
+    ///
+    /// The product of no factors is 1.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ibig::{ubig, UBig};
+    /// assert_eq!(UBig::product_of((1..=10u8).map(UBig::from)), ubig!(3628800));
+    /// ```
+    pub fn product_of<I>(factors: I) -> UBig
+    where
+        I: IntoIterator<Item = UBig>,
+    {
+        // Partial products of 2^k consecutive factors, with strictly decreasing k.
+        let mut stack: Vec<(UBig, u32)> = Vec::new();
+        for factor in factors {
+            let mut product = factor;
+            let mut log_count = 0;
+            while let Some((top, top_log_count)) = stack.pop() {
+                if top_log_count != log_count {
+                    stack.push((top, top_log_count));
+                    break;
+                }
+                product = top * product;
+                log_count += 1;
+            }
+            stack.push((product, log_count));
+        }
+        stack
+            .into_iter()
+            .rev()
+            .fold(UBig::from_word(1), |acc, (product, _)| product * acc)
+    }
+}
+
 impl UBig {
     /// Multiply two `Word`s.
     #[inline]
     fn mul_word(a: Word, b: Word) -> UBig {
-        UBig::from(extend_word(a) * extend_word(b))
+        UBig::from_double_word(extend_word(a) * extend_word(b))
+    }
+
+    /// Multiply a `DoubleWord` by a `Word`.
+    ///
+    /// The result has up to 3 words.
+    fn mul_double_word_word(a: DoubleWord, b: Word) -> UBig {
+        let (a_lo, a_hi) = split_double_word(a);
+        let (r0, carry) = split_double_word(extend_word(a_lo) * extend_word(b));
+        let (r1, r2) = split_double_word(extend_word(a_hi) * extend_word(b) + extend_word(carry));
+        UBig::from_words_up_to_4([r0, r1, r2, 0])
+    }
+
+    /// Multiply two `DoubleWord`s.
+    ///
+    /// The result has up to 4 words.
+    fn mul_double_word(a: DoubleWord, b: DoubleWord) -> UBig {
+        let (a_lo, a_hi) = split_double_word(a);
+        let (b_lo, b_hi) = split_double_word(b);
+        let (r0, carry) = split_double_word(extend_word(a_lo) * extend_word(b_lo));
+        let (lo_hi_0, lo_hi_1) = split_double_word(extend_word(a_lo) * extend_word(b_hi));
+        let (hi_lo_0, hi_lo_1) = split_double_word(extend_word(a_hi) * extend_word(b_lo));
+        // carry + lo_hi_0 + hi_lo_0 < 3 * 2^WORD_BITS, doesn't overflow.
+        let (r1, carry) =
+            split_double_word(extend_word(carry) + extend_word(lo_hi_0) + extend_word(hi_lo_0));
+        // The top half of the product is less than 2^(2*WORD_BITS), so this doesn't overflow.
+        let (r2, r3) = split_double_word(
+            extend_word(a_hi) * extend_word(b_hi)
+                + extend_word(carry)
+                + extend_word(lo_hi_1)
+                + extend_word(hi_lo_1),
+        );
+        UBig::from_words_up_to_4([r0, r1, r2, r3])
+    }
+
+    /// Create a `UBig` from at most 4 words, allocating only when the value doesn't fit in a
+    /// `DoubleWord`.
+    #[inline]
+    fn from_words_up_to_4(words: [Word; 4]) -> UBig {
+        match words {
+            [w0, w1, 0, 0] => UBig::from_double_word(double_word(w0, w1)),
+            [w0, w1, w2, 0] => {
+                let mut buffer = Buffer::allocate(3);
+                buffer.extend(&[w0, w1, w2]);
+                buffer.into()
+            }
+            _ => {
+                let mut buffer = Buffer::allocate(4);
+                buffer.extend(&words);
+                buffer.into()
+            }
+        }
+    }
+
+    /// Multiply a large number by a `DoubleWord`.
+    fn mul_large_double_word(lhs: &[Word], rhs: DoubleWord) -> UBig {
+        let (lo, hi) = split_double_word(rhs);
+        UBig::mul_large(lhs, &[lo, hi])
     }
 
     /// Multiply a large number by a `Word`.
@@ -321,9 +470,18 @@ impl UBig {
     }
 
     /// Multiply two large numbers.
+    ///
+    /// Multiplication by a power of 2 is done by shifting.
     fn mul_large(lhs: &[Word], rhs: &[Word]) -> UBig {
         debug_assert!(lhs.len() >= 2 && rhs.len() >= 2);
 
+        if UBig::is_power_of_two_large(rhs) {
+            return UBig::shl_ref_large(lhs, UBig::trailing_zeros_large(rhs));
+        }
+        if UBig::is_power_of_two_large(lhs) {
+            return UBig::shl_ref_large(rhs, UBig::trailing_zeros_large(lhs));
+        }
+
         // This may be 1 too large.
         const_assert!(Buffer::MAX_CAPACITY - UBig::MAX_LEN >= 1);
         let res_len = lhs.len() + rhs.len();
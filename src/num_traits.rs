@@ -10,7 +10,7 @@ impl num_traits::Zero for UBig {
 
     #[inline]
     fn is_zero(&self) -> bool {
-        *self == Self::from(0u8)
+        Self::is_zero(self)
     }
 }
 
@@ -22,7 +22,7 @@ impl num_traits::Zero for IBig {
 
     #[inline]
     fn is_zero(&self) -> bool {
-        *self == Self::from(0u8)
+        Self::is_zero(self)
     }
 }
 
@@ -31,6 +31,11 @@ impl num_traits::One for UBig {
     fn one() -> Self {
         Self::from(1u8)
     }
+
+    #[inline]
+    fn is_one(&self) -> bool {
+        Self::is_one(self)
+    }
 }
 
 impl num_traits::One for IBig {
@@ -38,6 +43,11 @@ impl num_traits::One for IBig {
     fn one() -> Self {
         Self::from(1u8)
     }
+
+    #[inline]
+    fn is_one(&self) -> bool {
+        Self::is_one(self)
+    }
 }
 
 impl num_traits::Pow<usize> for UBig {
@@ -78,6 +88,20 @@ impl num_traits::Pow<usize> for &IBig {
     }
 }
 
+impl num_traits::CheckedAdd for UBig {
+    #[inline]
+    fn checked_add(&self, v: &Self) -> Option<Self> {
+        UBig::checked_add(self, v)
+    }
+}
+
+impl num_traits::CheckedMul for UBig {
+    #[inline]
+    fn checked_mul(&self, v: &Self) -> Option<Self> {
+        UBig::checked_mul(self, v)
+    }
+}
+
 impl num_traits::Unsigned for UBig {}
 
 impl num_traits::Signed for IBig {
@@ -0,0 +1,680 @@
+//! Number theory.
+
+use crate::{
+    ibig::IBig,
+    modular::{modulo::Modulo, modulo_ring::ModuloRing},
+    ubig::UBig,
+};
+use alloc::{vec, vec::Vec};
+use core::mem;
+
+/// All primes below 1024.
+const SMALL_PRIMES: [u16; 172] = [
+    2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61, 67, 71, 73, 79, 83, 89, 97,
+    101, 103, 107, 109, 113, 127, 131, 137, 139, 149, 151, 157, 163, 167, 173, 179, 181, 191, 193,
+    197, 199, 211, 223, 227, 229, 233, 239, 241, 251, 257, 263, 269, 271, 277, 281, 283, 293, 307,
+    311, 313, 317, 331, 337, 347, 349, 353, 359, 367, 373, 379, 383, 389, 397, 401, 409, 419, 421,
+    431, 433, 439, 443, 449, 457, 461, 463, 467, 479, 487, 491, 499, 503, 509, 521, 523, 541, 547,
+    557, 563, 569, 571, 577, 587, 593, 599, 601, 607, 613, 617, 619, 631, 641, 643, 647, 653, 659,
+    661, 673, 677, 683, 691, 701, 709, 719, 727, 733, 739, 743, 751, 757, 761, 769, 773, 787, 797,
+    809, 811, 821, 823, 827, 829, 839, 853, 857, 859, 863, 877, 881, 883, 887, 907, 911, 919, 929,
+    937, 941, 947, 953, 967, 971, 977, 983, 991, 997, 1009, 1013, 1019, 1021,
+];
+
+/// Number of trial divisors checked in one pass over the words of the number.
+const TRIAL_DIVISION_BATCH: usize = 64;
+
+/// Trial division limit before running a primality test.
+const PRIMALITY_TRIAL_DIVISION_LIMIT: u64 = 1024;
+
+impl UBig {
+    /// Smallest prime factor less than `limit`, found by trial division.
+    ///
+    /// Returns `None` if `self` has no prime factor less than `limit`. This doesn't mean that
+    /// `self` is prime, only that it has no small factors.
+    ///
+    /// If `self` itself is a prime less than `limit`, returns `self`. `ubig!(0)` is divisible by
+    /// 2, and `ubig!(1)` has no prime factors.
+    ///
+    /// This is a cheap pre-filter before a more expensive primality test.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// assert_eq!(ubig!(91).smallest_factor_below(100), Some(7));
+    /// assert_eq!(ubig!(91).smallest_factor_below(7), None);
+    /// assert_eq!(ubig!(97).smallest_factor_below(100), Some(97));
+    /// assert_eq!(ubig!(97).smallest_factor_below(50), None);
+    /// ```
+    pub fn smallest_factor_below(&self, limit: u64) -> Option<u64> {
+        // Divisors beyond the table are the numbers 6k-1 and 6k+1. Some of them are composite,
+        // but their prime factors have already been checked.
+        let largest_small_prime = u64::from(*SMALL_PRIMES.last().unwrap());
+        let beyond_table = (largest_small_prime / 6 + 1..)
+            .flat_map(|k| [6 * k - 1, 6 * k + 1])
+            .take_while(|d| *d > largest_small_prime);
+        let mut divisors = SMALL_PRIMES
+            .iter()
+            .map(|p| u64::from(*p))
+            .chain(beyond_table)
+            .take_while(|d| *d < limit)
+            .peekable();
+
+        // If `self` fits in a u128, trial division can stop at its square root.
+        let self_u128 = u128::try_from(self).ok();
+        let mut batch = Vec::with_capacity(TRIAL_DIVISION_BATCH);
+        while divisors.peek().is_some() {
+            batch.clear();
+            batch.extend(divisors.by_ref().take(TRIAL_DIVISION_BATCH));
+            for (d, rem) in batch.iter().zip(self.rem_words(&batch)) {
+                if rem == 0 {
+                    return Some(*d);
+                }
+                if let Some(x) = self_u128 {
+                    if u128::from(*d) * u128::from(*d) > x {
+                        // No factors up to the square root, so `self` is 1 or a prime.
+                        return if x > 1 && x < u128::from(limit) {
+                            Some(x as u64)
+                        } else {
+                            None
+                        };
+                    }
+                }
+            }
+        }
+        None
+    }
+
+    /// Baillie-PSW primality test.
+    ///
+    /// Combines a strong probable prime test to base 2 with a strong Lucas probable prime test,
+    /// after trial division by small primes. The test is deterministic. It is known to be correct
+    /// for all numbers below 2^64, and no composite number passing it is known.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// assert!(ubig!(97).is_prime_bpsw());
+    /// assert!(!ubig!(91).is_prime_bpsw());
+    /// // A Mersenne prime.
+    /// assert!((ubig!(2).pow(127) - ubig!(1)).is_prime_bpsw());
+    /// ```
+    pub fn is_prime_bpsw(&self) -> bool {
+        if let Some(factor) = self.smallest_factor_below(PRIMALITY_TRIAL_DIVISION_LIMIT) {
+            return *self == factor;
+        }
+        if *self < UBig::from_word(2) {
+            return false;
+        }
+        is_bpsw_probable_prime(self)
+    }
+
+    /// The smallest prime greater than `self`.
+    ///
+    /// Odd candidates are checked in order, starting from the first odd number greater than
+    /// `self`. A sieve by small primes rejects most of them cheaply, and the rest are tested with
+    /// [UBig::is_prime_bpsw].
+    ///
+    /// On average about `ln(self) / 2` candidates are examined, and about a sixth of those need
+    /// the full test, which is dominated by modular exponentiation. For a 1024-bit number that is
+    /// about 60 exponentiations.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// assert_eq!(ubig!(0).next_prime(), ubig!(2));
+    /// assert_eq!(ubig!(2).next_prime(), ubig!(3));
+    /// assert_eq!(ubig!(90).next_prime(), ubig!(97));
+    /// assert_eq!(ubig!(97).next_prime(), ubig!(101));
+    /// ```
+    pub fn next_prime(&self) -> UBig {
+        if *self < UBig::from_word(2) {
+            return UBig::from_word(2);
+        }
+        let start = if self.bit(0) {
+            self + UBig::from_word(2)
+        } else {
+            self + UBig::from_word(1)
+        };
+        sieve_search(start, true)
+    }
+
+    /// The largest prime less than `self`, or `None` if `self <= 2`.
+    ///
+    /// Works like [UBig::next_prime], searching downwards.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// assert_eq!(ubig!(2).previous_prime(), None);
+    /// assert_eq!(ubig!(3).previous_prime(), Some(ubig!(2)));
+    /// assert_eq!(ubig!(100).previous_prime(), Some(ubig!(97)));
+    /// assert_eq!(ubig!(97).previous_prime(), Some(ubig!(89)));
+    /// ```
+    pub fn previous_prime(&self) -> Option<UBig> {
+        if *self <= UBig::from_word(3) {
+            return if *self == UBig::from_word(3) {
+                Some(UBig::from_word(2))
+            } else {
+                None
+            };
+        }
+        let start = if self.bit(0) {
+            self - UBig::from_word(2)
+        } else {
+            self - UBig::from_word(1)
+        };
+        Some(sieve_search(start, false))
+    }
+
+    /// Whether `self ≡ other (mod modulus)`.
+    ///
+    /// Modulo 0, numbers are only congruent when they are equal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// assert!(ubig!(17).is_congruent(&ubig!(2), &ubig!(5)));
+    /// assert!(ubig!(2).is_congruent(&ubig!(17), &ubig!(5)));
+    /// assert!(!ubig!(17).is_congruent(&ubig!(3), &ubig!(5)));
+    /// ```
+    pub fn is_congruent(&self, other: &UBig, modulus: &UBig) -> bool {
+        let diff = if self >= other {
+            self - other
+        } else {
+            other - self
+        };
+        diff.is_multiple_of(modulus)
+    }
+
+    /// Divide out all factors of `factor`.
+    ///
+    /// Returns `(cofactor, multiplicity)` such that `self == cofactor * factor^multiplicity` and
+    /// `cofactor` is not divisible by `factor`. For 0, it returns `(0, 0)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// assert_eq!(ubig!(1350).remove_factor(&ubig!(3)), (ubig!(50), 3));
+    /// assert_eq!(ubig!(1350).remove_factor(&ubig!(7)), (ubig!(1350), 0));
+    /// assert_eq!(ubig!(640).remove_factor(&ubig!(4)), (ubig!(10), 3));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `factor` is less than 2.
+    pub fn remove_factor(&self, factor: &UBig) -> (UBig, usize) {
+        if *factor < UBig::from_word(2) {
+            panic!("remove_factor with factor < 2");
+        }
+        if self.is_zero() {
+            return (UBig::from_word(0), 0);
+        }
+        if factor.is_power_of_two() {
+            let factor_bits = factor.trailing_zeros().unwrap();
+            let multiplicity = self.trailing_zeros().unwrap() / factor_bits;
+            return (self >> (multiplicity * factor_bits), multiplicity);
+        }
+
+        let mut cofactor = self.clone();
+        let mut multiplicity = 0;
+        loop {
+            let (q, r) = cofactor.div_rem(factor);
+            if !r.is_zero() {
+                return (cofactor, multiplicity);
+            }
+            cofactor = q;
+            multiplicity += 1;
+        }
+    }
+
+    /// Prime factorization.
+    ///
+    /// Returns the distinct prime factors in increasing order together with their
+    /// multiplicities. `ubig!(1)` has no prime factors.
+    ///
+    /// Small factors are found by trial division, the rest by Pollard's rho algorithm (Brent's
+    /// variant). The expected running time grows with the square root of the second largest
+    /// prime factor, so numbers whose two largest prime factors both have more than about 30
+    /// digits are not practical to factor.
+    ///
+    /// The primality of the factors is established by [UBig::is_prime_bpsw].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// assert_eq!(ubig!(360).factor(), [(ubig!(2), 3), (ubig!(3), 2), (ubig!(5), 1)]);
+    /// assert_eq!(
+    ///     ubig!(18446744073709551617).factor(),
+    ///     [(ubig!(274177), 1), (ubig!(67280421310721), 1)]
+    /// );
+    /// assert!(ubig!(1).factor().is_empty());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is 0.
+    pub fn factor(&self) -> Vec<(UBig, usize)> {
+        if self.is_zero() {
+            panic!("factor(0)");
+        }
+
+        let mut factors = Vec::new();
+        let mut n = self.clone();
+        while let Some(p) = n.smallest_factor_below(PRIMALITY_TRIAL_DIVISION_LIMIT) {
+            let p = UBig::from(p);
+            let (cofactor, multiplicity) = n.remove_factor(&p);
+            factors.push((p, multiplicity));
+            n = cofactor;
+        }
+
+        let mut large_factors = large_prime_factors(n);
+        large_factors.sort();
+        for p in large_factors {
+            match factors.last_mut() {
+                Some((last, multiplicity)) if *last == p => *multiplicity += 1,
+                _ => factors.push((p, 1)),
+            }
+        }
+        factors
+    }
+
+    /// All divisors, in no particular order.
+    ///
+    /// The number is first factored with [UBig::factor], then the divisors are generated one at
+    /// a time. The number of divisors can be enormous even for moderately sized numbers; use
+    /// [UBig::num_divisors] to check it first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// let mut divisors: Vec<_> = ubig!(12).divisors().collect();
+    /// divisors.sort();
+    /// assert_eq!(divisors, [ubig!(1), ubig!(2), ubig!(3), ubig!(4), ubig!(6), ubig!(12)]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is 0.
+    pub fn divisors(&self) -> impl Iterator<Item = UBig> {
+        let factors = self.factor();
+        Divisors {
+            exponents: vec![0; factors.len()],
+            factors,
+            next: Some(UBig::from_word(1)),
+        }
+    }
+
+    /// The number of divisors.
+    ///
+    /// If `self = p_1^e_1 * ... * p_k^e_k` this is `(e_1 + 1) * ... * (e_k + 1)`. The cost is
+    /// that of [UBig::factor].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// assert_eq!(ubig!(12).num_divisors(), ubig!(6));
+    /// assert_eq!(ubig!(1).num_divisors(), ubig!(1));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is 0.
+    pub fn num_divisors(&self) -> UBig {
+        self.factor()
+            .iter()
+            .map(|(_, e)| UBig::from(*e + 1))
+            .fold(UBig::from_word(1), |acc, x| acc * x)
+    }
+
+    /// Euler's totient function: the number of integers in `1..=self` coprime to `self`.
+    ///
+    /// Computed from the prime factorization as `n * (1 - 1/p_1) * ... * (1 - 1/p_k)`, so the
+    /// cost is that of [UBig::factor].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// assert_eq!(ubig!(1).euler_phi(), ubig!(1));
+    /// assert_eq!(ubig!(36).euler_phi(), ubig!(12));
+    /// assert_eq!(ubig!(97).euler_phi(), ubig!(96));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is 0.
+    pub fn euler_phi(&self) -> UBig {
+        let mut result = self.clone();
+        for (p, _) in self.factor() {
+            result /= &p;
+            result *= p - UBig::from_word(1);
+        }
+        result
+    }
+}
+
+impl IBig {
+    /// Whether `self ≡ other (mod modulus)`.
+    ///
+    /// The sign of `modulus` doesn't matter. Modulo 0, numbers are only congruent when they are
+    /// equal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ibig;
+    /// assert!(ibig!(-3).is_congruent(&ibig!(2), &ibig!(5)));
+    /// assert!(ibig!(-3).is_congruent(&ibig!(-8), &ibig!(-5)));
+    /// assert!(!ibig!(-3).is_congruent(&ibig!(3), &ibig!(5)));
+    /// ```
+    pub fn is_congruent(&self, other: &IBig, modulus: &IBig) -> bool {
+        (self - other)
+            .magnitude()
+            .is_multiple_of(modulus.magnitude())
+    }
+}
+
+/// Search for a prime among odd numbers starting at odd `start >= 3`, upwards or downwards.
+///
+/// Downward search requires a prime between 3 and `start`, which always exists.
+pub(crate) fn sieve_search(start: UBig, upwards: bool) -> UBig {
+    debug_assert!(start.bit(0) && start >= UBig::from_word(3));
+    let sieve_primes: Vec<u64> = SMALL_PRIMES[1..].iter().map(|p| u64::from(*p)).collect();
+    let largest_small_prime = UBig::from(*SMALL_PRIMES.last().unwrap());
+
+    let mut candidate = start;
+    let mut residues = candidate.rem_words(&sieve_primes);
+    loop {
+        if candidate <= largest_small_prime {
+            if candidate.is_prime_bpsw() {
+                return candidate;
+            }
+        } else if residues.iter().all(|r| *r != 0) && is_bpsw_probable_prime(&candidate) {
+            return candidate;
+        }
+        // Move by 2 and update the residues.
+        if upwards {
+            candidate += UBig::from_word(2);
+            for (r, p) in residues.iter_mut().zip(&sieve_primes) {
+                *r = (*r + 2) % p;
+            }
+        } else {
+            candidate -= UBig::from_word(2);
+            for (r, p) in residues.iter_mut().zip(&sieve_primes) {
+                *r = (*r + p - 2) % p;
+            }
+        }
+    }
+}
+
+/// Baillie-PSW test for odd `n` without factors below [PRIMALITY_TRIAL_DIVISION_LIMIT].
+fn is_bpsw_probable_prime(n: &UBig) -> bool {
+    let ring = ModuloRing::new(n);
+    is_strong_probable_prime_base_2(n, &ring)
+        && !is_perfect_square(n)
+        && is_strong_lucas_probable_prime(n, &ring)
+}
+
+/// Strong probable prime test to base 2 for odd `n > 2`.
+fn is_strong_probable_prime_base_2(n: &UBig, ring: &ModuloRing) -> bool {
+    let (d, s) = (n - UBig::from_word(1)).remove_factor_two();
+    let one = ring.from(1);
+    let minus_one = -&one;
+
+    let mut x = ring.from(2).pow(&d);
+    if x == one || x == minus_one {
+        return true;
+    }
+    for _ in 1..s {
+        x = &x * &x;
+        if x == minus_one {
+            return true;
+        }
+    }
+    false
+}
+
+/// Iterator over the divisors of a number, given its factorization.
+///
+/// The exponents are enumerated like an odometer, updating the current divisor with one
+/// multiplication or one exact division per step.
+struct Divisors {
+    factors: Vec<(UBig, usize)>,
+    /// Exponents of the factors in `next`.
+    exponents: Vec<usize>,
+    next: Option<UBig>,
+}
+
+impl Iterator for Divisors {
+    type Item = UBig;
+
+    fn next(&mut self) -> Option<UBig> {
+        let current = self.next.take()?;
+        let mut next = current.clone();
+        for ((p, max_exponent), exponent) in self.factors.iter().zip(&mut self.exponents) {
+            if *exponent < *max_exponent {
+                *exponent += 1;
+                next *= p;
+                self.next = Some(next);
+                break;
+            }
+            next /= p.pow(*exponent);
+            *exponent = 0;
+        }
+        Some(current)
+    }
+}
+
+/// Prime factors of `n` without factors below [PRIMALITY_TRIAL_DIVISION_LIMIT], with repetitions,
+/// in no particular order.
+fn large_prime_factors(n: UBig) -> Vec<UBig> {
+    let mut factors = Vec::new();
+    let mut composites = vec![n];
+    while let Some(n) = composites.pop() {
+        if n == UBig::from_word(1) {
+            continue;
+        }
+        if is_bpsw_probable_prime(&n) {
+            factors.push(n);
+            continue;
+        }
+        let root = sqrt_floor(&n);
+        if &root * &root == n {
+            composites.push(root.clone());
+            composites.push(root);
+        } else {
+            let d = pollard_rho_brent(&n);
+            composites.push(&n / &d);
+            composites.push(d);
+        }
+    }
+    factors
+}
+
+/// A non-trivial factor of odd composite `n` that is not a perfect square, by Pollard's rho
+/// algorithm with Brent's cycle detection.
+fn pollard_rho_brent(n: &UBig) -> UBig {
+    /// Number of steps between gcd computations.
+    const GCD_BATCH: usize = 128;
+
+    let ring = ModuloRing::new(n);
+    let one = UBig::from_word(1);
+    // Try the maps x -> x^2 + c until one gives a proper factor.
+    for c in 1u32.. {
+        let mut y = ring.from(2);
+        let mut ys = y.clone();
+        let mut q = ring.from(1);
+        let mut g;
+        let mut x;
+        let mut r = 1;
+        loop {
+            x = y.clone();
+            for _ in 0..r {
+                y = &y * &y + c;
+            }
+            let mut k = 0;
+            g = one.clone();
+            while k < r && g == one {
+                ys = y.clone();
+                for _ in 0..GCD_BATCH.min(r - k) {
+                    y = &y * &y + c;
+                    q *= &x - &y;
+                }
+                g = q.residue().gcd(n);
+                k += GCD_BATCH;
+            }
+            r *= 2;
+            if g != one {
+                break;
+            }
+        }
+        if g == *n {
+            // The whole batch was multiplied in at once; step through it one by one.
+            loop {
+                ys = &ys * &ys + c;
+                g = (&x - &ys).residue().gcd(n);
+                if g != one {
+                    break;
+                }
+            }
+        }
+        if g != *n {
+            return g;
+        }
+    }
+    unreachable!()
+}
+
+/// Whether `n` is a perfect square.
+fn is_perfect_square(n: &UBig) -> bool {
+    let root = sqrt_floor(n);
+    &root * &root == *n
+}
+
+/// `floor(sqrt(n))` by Newton's method.
+fn sqrt_floor(n: &UBig) -> UBig {
+    if n.is_zero() {
+        return UBig::from_word(0);
+    }
+    // Start above the square root, the iteration decreases until it reaches the floor.
+    let mut x = UBig::pow2((n.bit_len() + 1) / 2);
+    loop {
+        let y = (&x + n / &x) >> 1;
+        if y >= x {
+            return x;
+        }
+        x = y;
+    }
+}
+
+/// Jacobi symbol `(a / n)` for odd `n`.
+pub(crate) fn jacobi(a: &UBig, n: &UBig) -> i8 {
+    debug_assert!(n.bit(0));
+    let mut a = a % n;
+    let mut n = n.clone();
+    let mut result = 1;
+    while !a.is_zero() {
+        let zeros = a.trailing_zeros().unwrap();
+        a >>= zeros;
+        // (2 / n) = -1 iff n ≡ 3 or 5 (mod 8).
+        if zeros % 2 == 1 && n.bit(1) != n.bit(2) {
+            result = -result;
+        }
+        // Quadratic reciprocity: flip the sign iff both are 3 (mod 4).
+        mem::swap(&mut a, &mut n);
+        if a.bit(1) && n.bit(1) {
+            result = -result;
+        }
+        a %= &n;
+    }
+    if n == UBig::from_word(1) {
+        result
+    } else {
+        0
+    }
+}
+
+/// Strong Lucas probable prime test for odd `n` that is not a perfect square.
+///
+/// Uses Selfridge's parameters: `D` is the first of 5, -7, 9, -11, ... with Jacobi symbol
+/// `(D / n) = -1`, `P = 1`, `Q = (1 - D) / 4`.
+fn is_strong_lucas_probable_prime(n: &UBig, ring: &ModuloRing) -> bool {
+    let mut d: i64 = 5;
+    loop {
+        let d_abs = UBig::from(d.unsigned_abs()) % n;
+        let d_mod_n = if d > 0 || d_abs.is_zero() {
+            d_abs
+        } else {
+            n - d_abs
+        };
+        match jacobi(&d_mod_n, n) {
+            -1 => break,
+            // `|D|` is a factor of `n`.
+            0 => return *n == d.unsigned_abs(),
+            _ => d = if d > 0 { -d - 2 } else { -d + 2 },
+        }
+    }
+    let q = (1 - d) / 4;
+
+    // n + 1 = k * 2^s
+    let (k, s) = (n + UBig::from_word(1)).remove_factor_two();
+    let (u, mut v, mut q_k) = lucas_sequence(&k, n, ring, d, q);
+    let zero = ring.from(0);
+    if u == zero || v == zero {
+        return true;
+    }
+    for _ in 1..s {
+        // V_2k = V_k^2 - 2 Q^k
+        v = &v * &v - &q_k - &q_k;
+        if v == zero {
+            return true;
+        }
+        q_k = &q_k * &q_k;
+    }
+    false
+}
+
+/// Lucas sequences with `P = 1` modulo odd `n`: `(U_k, V_k, Q^k)`.
+fn lucas_sequence<'a>(
+    k: &UBig,
+    n: &UBig,
+    ring: &'a ModuloRing,
+    d: i64,
+    q: i64,
+) -> (Modulo<'a>, Modulo<'a>, Modulo<'a>) {
+    debug_assert!(!k.is_zero());
+    let half = ring.from((n + UBig::from_word(1)) >> 1);
+    let d = ring.from(d);
+    let q = ring.from(q);
+
+    // Start at k = 1 and process the remaining bits from the top.
+    let mut u = ring.from(1);
+    let mut v = ring.from(1);
+    let mut q_k = q.clone();
+    for i in (0..k.bit_len() - 1).rev() {
+        // U_2k = U_k V_k
+        // V_2k = V_k^2 - 2 Q^k
+        u = &u * &v;
+        v = &v * &v - &q_k - &q_k;
+        q_k = &q_k * &q_k;
+        if k.bit(i) {
+            // U_(k+1) = (U_k + V_k) / 2
+            // V_(k+1) = (D U_k + V_k) / 2
+            let u_next = (&u + &v) * &half;
+            v = (&d * &u + &v) * &half;
+            u = u_next;
+            q_k = &q_k * &q;
+        }
+    }
+    (u, v, q_k)
+}
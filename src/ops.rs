@@ -58,6 +58,9 @@ pub trait UnsignedAbs {
 
 /// Compute quotient and remainder at the same time.
 ///
+/// For references, [UBig::div_rem](crate::UBig::div_rem) and
+/// [IBig::div_rem](crate::IBig::div_rem) do the same without importing this trait.
+///
 /// # Example
 /// ```
 /// # use ibig::{ops::DivRem, ubig};
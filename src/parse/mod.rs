@@ -1,8 +1,12 @@
 //! Parsing numbers.
 
 use crate::{
+    arch::word::Word,
+    buffer::Buffer,
     error::ParseError,
     ibig::IBig,
+    mul,
+    primitive::WORD_BITS,
     radix::{self, Digit},
     sign::Sign::*,
     ubig::UBig,
@@ -50,6 +54,112 @@ impl UBig {
         UBig::from_str_radix_no_sign(src, radix)
     }
 
+    /// Convert a string in a given base to [UBig], ignoring leading and trailing ASCII whitespace.
+    ///
+    /// The whitespace characters trimmed are: space (U+0020), horizontal tab (U+0009),
+    /// line feed (U+000A), form feed (U+000C) and carriage return (U+000D).
+    /// Whitespace between the sign and the digits is not allowed.
+    ///
+    /// Otherwise the same as [UBig::from_str_radix].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is not between 2 and 36 inclusive.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ibig::{error::ParseError, ubig, UBig};
+    /// assert_eq!(UBig::from_str_radix_trimmed(" +7ab\r\n", 32)?, ubig!(7499));
+    /// assert!(UBig::from_str_radix_trimmed("+ 7ab", 32).is_err());
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    pub fn from_str_radix_trimmed(src: &str, radix: u32) -> Result<UBig, ParseError> {
+        UBig::from_str_radix(trim_ascii_whitespace(src), radix)
+    }
+
+    /// Convert a string of digits in a given base, least significant digit first, to [UBig].
+    ///
+    /// The first character is the units digit, so `"321"` in base 10 is 123. No sign is allowed.
+    /// Digits 10-35 are represented by `a-z` or `A-Z`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is not between 2 and 36 inclusive.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ibig::{error::ParseError, ubig, UBig};
+    /// assert_eq!(UBig::from_str_radix_le("54321", 10)?, ubig!(12345));
+    /// assert_eq!(UBig::from_str_radix_le("ba7", 32)?, ubig!(7499));
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    pub fn from_str_radix_le(src: &str, radix: u32) -> Result<UBig, ParseError> {
+        radix::check_radix_valid(radix);
+        if src.is_empty() {
+            return Err(ParseError::NoDigits);
+        }
+
+        let src = src.trim_end_matches('0');
+
+        if radix.is_power_of_two() {
+            power_two::parse_le(src, radix)
+        } else {
+            non_power_two::parse_le(src, radix)
+        }
+    }
+
+    /// Value of a single digit character in a given base.
+    ///
+    /// Returns `None` if `c` is not a digit in base `radix`. Digits 10-35 are represented by
+    /// `a-z` or `A-Z`. This only handles one digit: use [UBig::from_str_radix] to parse a number.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is not between 2 and 36 inclusive.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ibig::{ubig, UBig};
+    /// assert_eq!(UBig::from_digit('7', 10), Some(ubig!(7)));
+    /// assert_eq!(UBig::from_digit('Z', 36), Some(ubig!(35)));
+    /// assert_eq!(UBig::from_digit('a', 10), None);
+    /// ```
+    pub fn from_digit(c: char, radix: u32) -> Option<UBig> {
+        radix::check_radix_valid(radix);
+        let byte = u8::try_from(c).ok()?;
+        radix::digit_from_utf8_byte(byte, radix).map(UBig::from)
+    }
+
+    /// Convert a sequence of digits in a given base to [UBig], consuming them one at a time.
+    ///
+    /// The most significant digit comes first. No sign is allowed.
+    /// Digits 10-35 are represented by `a-z` or `A-Z`.
+    ///
+    /// The digits are accumulated into the result word by word as they arrive, so the input
+    /// never needs to be stored as a whole. This takes time quadratic in the number of digits;
+    /// for very long inputs that are already in memory, [UBig::from_str_radix] is faster.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is not between 2 and 36 inclusive.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ibig::{error::ParseError, ubig, UBig};
+    /// assert_eq!(UBig::from_digit_iter("7ab".chars(), 32)?, ubig!(7499));
+    /// let digits = core::iter::repeat('9').take(30);
+    /// assert_eq!(UBig::from_digit_iter(digits, 10)?, ubig!(10).pow(30) - ubig!(1));
+    /// assert_eq!(UBig::from_digit_iter("".chars(), 10), Err(ParseError::NoDigits));
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    pub fn from_digit_iter<I>(digits: I, radix: u32) -> Result<UBig, ParseError>
+    where
+        I: IntoIterator<Item = char>,
+    {
+        radix::check_radix_valid(radix);
+        parse_digit_iter(digits.into_iter(), radix)
+    }
+
     /// Convert a string with an optional radix prefix to [UBig].
     ///
     /// `src` may contain an optional `+` after the radix prefix.
@@ -134,6 +244,88 @@ impl IBig {
         Ok(IBig::from_sign_magnitude(sign, mag))
     }
 
+    /// Convert a string in a given base to [IBig], also accepting the Unicode minus sign.
+    ///
+    /// Same as [IBig::from_str_radix], except that `src` may also start with U+2212 MINUS SIGN
+    /// (`−`) instead of `-`. This helps with text copied from typeset mathematics.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is not between 2 and 36 inclusive.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ibig::{error::ParseError, ibig, IBig};
+    /// assert_eq!(IBig::from_str_radix_unicode("\u{2212}7ab", 32)?, ibig!(-7499));
+    /// assert_eq!(IBig::from_str_radix_unicode("-7ab", 32)?, ibig!(-7499));
+    /// assert!(IBig::from_str_radix("\u{2212}7ab", 32).is_err());
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    pub fn from_str_radix_unicode(src: &str, radix: u32) -> Result<IBig, ParseError> {
+        match src.strip_prefix('\u{2212}') {
+            Some(s) => {
+                radix::check_radix_valid(radix);
+                let mag = UBig::from_str_radix_no_sign(s, radix)?;
+                Ok(IBig::from_sign_magnitude(Negative, mag))
+            }
+            None => IBig::from_str_radix(src, radix),
+        }
+    }
+
+    /// Convert a string in a given base to [IBig], ignoring leading and trailing ASCII whitespace.
+    ///
+    /// The whitespace characters trimmed are: space (U+0020), horizontal tab (U+0009),
+    /// line feed (U+000A), form feed (U+000C) and carriage return (U+000D).
+    /// Whitespace between the sign and the digits is not allowed.
+    ///
+    /// Otherwise the same as [IBig::from_str_radix].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is not between 2 and 36 inclusive.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ibig::{error::ParseError, ibig, IBig};
+    /// assert_eq!(IBig::from_str_radix_trimmed("\t-7ab ", 32)?, ibig!(-7499));
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    pub fn from_str_radix_trimmed(src: &str, radix: u32) -> Result<IBig, ParseError> {
+        IBig::from_str_radix(trim_ascii_whitespace(src), radix)
+    }
+
+    /// Convert a sequence of characters in a given base to [IBig], consuming them one at a time.
+    ///
+    /// The sequence may start with a `+` or `-` sign, followed by the digits, most significant
+    /// first. Otherwise the same as [UBig::from_digit_iter].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is not between 2 and 36 inclusive.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ibig::{error::ParseError, ibig, IBig};
+    /// assert_eq!(IBig::from_digit_iter("-7ab".chars(), 32)?, ibig!(-7499));
+    /// assert_eq!(IBig::from_digit_iter("+7ab".chars(), 32)?, ibig!(7499));
+    /// assert_eq!(IBig::from_digit_iter("-".chars(), 32), Err(ParseError::NoDigits));
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    pub fn from_digit_iter<I>(chars: I, radix: u32) -> Result<IBig, ParseError>
+    where
+        I: IntoIterator<Item = char>,
+    {
+        radix::check_radix_valid(radix);
+        let mut chars = chars.into_iter().peekable();
+        let sign = match chars.peek() {
+            Some('-') => Negative,
+            _ => Positive,
+        };
+        chars.next_if(|c| *c == '-' || *c == '+');
+        let mag = parse_digit_iter(chars, radix)?;
+        Ok(IBig::from_sign_magnitude(sign, mag))
+    }
+
     /// Convert a string with an optional radix prefix to [IBig].
     ///
     /// `src` may contain an '+' or `-` prefix after the radix prefix.
@@ -163,3 +355,60 @@ impl IBig {
         Ok(IBig::from_sign_magnitude(sign, mag))
     }
 }
+
+/// Parse digits from an iterator, most significant first, to [UBig].
+///
+/// Horner's method on groups of digits that fit in a `Word`, as in `non_power_two::parse_chunk`.
+fn parse_digit_iter<I>(digits: I, radix: Digit) -> Result<UBig, ParseError>
+where
+    I: Iterator<Item = char>,
+{
+    debug_assert!(radix::is_radix_valid(radix));
+    // radix^group_len must fit in a Word.
+    let group_len = if radix.is_power_of_two() {
+        ((WORD_BITS - 1) / radix.trailing_zeros()) as usize
+    } else {
+        radix::radix_info(radix).digits_per_word
+    };
+
+    let mut buffer = Buffer::allocate(1);
+    let mut add_group = |group: Word, scale: Word| {
+        let carry = mul::mul_word_in_place_with_carry(&mut buffer, scale, group);
+        if carry != 0 {
+            buffer.push_may_reallocate(carry);
+        }
+    };
+
+    let mut any_digits = false;
+    let mut group: Word = 0;
+    let mut scale: Word = 1;
+    let mut len = 0;
+    for c in digits {
+        let digit = u8::try_from(c)
+            .ok()
+            .and_then(|byte| radix::digit_from_utf8_byte(byte, radix))
+            .ok_or(ParseError::InvalidDigit)?;
+        group = group * (radix as Word) + (digit as Word);
+        scale *= radix as Word;
+        len += 1;
+        if len == group_len {
+            add_group(group, scale);
+            group = 0;
+            scale = 1;
+            len = 0;
+        }
+        any_digits = true;
+    }
+    if !any_digits {
+        return Err(ParseError::NoDigits);
+    }
+    if len != 0 {
+        add_group(group, scale);
+    }
+    Ok(buffer.into())
+}
+
+/// Remove leading and trailing ASCII whitespace.
+fn trim_ascii_whitespace(src: &str) -> &str {
+    src.trim_matches(|c: char| c.is_ascii_whitespace())
+}
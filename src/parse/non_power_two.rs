@@ -4,62 +4,169 @@ use crate::{
     arch::word::Word,
     buffer::Buffer,
     error::ParseError,
-    mul,
+    math, mul,
     radix::{self, Digit},
     ubig::UBig,
 };
 use alloc::vec;
+use DigitOrder::*;
 
 /// Parse in chunks of CHUNK_LEN * digits_per_word.
 const CHUNK_LEN: usize = 256;
 
+/// Order of digits in the string.
+#[derive(Clone, Copy)]
+enum DigitOrder {
+    /// The first digit is the most significant.
+    BigEndian,
+    /// The first digit is the units digit.
+    LittleEndian,
+}
+
 /// Parse an unsigned string to [UBig].
 pub(crate) fn parse(src: &str, radix: Digit) -> Result<UBig, ParseError> {
+    parse_in_order(src.as_bytes(), radix, BigEndian)
+}
+
+/// Parse an unsigned string with the least significant digit first to [UBig].
+pub(crate) fn parse_le(src: &str, radix: Digit) -> Result<UBig, ParseError> {
+    parse_in_order(src.as_bytes(), radix, LittleEndian)
+}
+
+/// Parse an unsigned string to [UBig].
+fn parse_in_order(bytes: &[u8], radix: Digit, order: DigitOrder) -> Result<UBig, ParseError> {
     debug_assert!(radix::is_radix_valid(radix) && !radix.is_power_of_two());
     let radix_info = radix::radix_info(radix);
-    let bytes = src.as_bytes();
 
     if bytes.len() <= radix_info.digits_per_word {
-        let word = parse_word(bytes, radix)?;
+        let word = parse_word(bytes, radix, order)?;
         Ok(UBig::from_word(word))
     } else if bytes.len() <= CHUNK_LEN * radix_info.digits_per_word {
-        parse_chunk(bytes, radix)
+        parse_chunk(bytes, radix, order)
     } else {
-        parse_large(bytes, radix)
+        parse_large(bytes, radix, order)
     }
 }
 
 /// Parse an unsigned string to `Word`.
 ///
 /// The length of the string must be at most `digits_per_word`.
-fn parse_word(src: &[u8], radix: Digit) -> Result<Word, ParseError> {
+fn parse_word(src: &[u8], radix: Digit, order: DigitOrder) -> Result<Word, ParseError> {
     debug_assert!(radix::is_radix_valid(radix) && !radix.is_power_of_two());
     debug_assert!(src.len() <= radix::radix_info(radix).digits_per_word);
 
+    if radix == 10 && src.len() >= 8 {
+        return parse_word_decimal(src, order);
+    }
+
     let mut word: Word = 0;
-    for byte in src.iter() {
+    let mut add_digit = |byte: &u8| -> Result<(), ParseError> {
         let digit = radix::digit_from_utf8_byte(*byte, radix).ok_or(ParseError::InvalidDigit)?;
         word = word * (radix as Word) + (digit as Word);
+        Ok(())
+    };
+    // Horner's method runs from the most significant digit.
+    match order {
+        BigEndian => src.iter().try_for_each(&mut add_digit)?,
+        LittleEndian => src.iter().rev().try_for_each(&mut add_digit)?,
     }
     Ok(word)
 }
 
+/// Parse an unsigned decimal string to `Word`, 8 digits at a time.
+///
+/// The length of the string must be at most `digits_per_word`.
+// Out of line, so that parse_word stays small for the common short case.
+#[inline(never)]
+fn parse_word_decimal(src: &[u8], order: DigitOrder) -> Result<Word, ParseError> {
+    debug_assert!(src.len() <= radix::radix_info(10).digits_per_word);
+
+    let add_digit = |word: Word, byte: u8| -> Result<Word, ParseError> {
+        let digit = radix::digit_from_utf8_byte(byte, 10).ok_or(ParseError::InvalidDigit)?;
+        Ok(word * 10 + (digit as Word))
+    };
+    // Only reachable if a Word has at least 8 decimal digits, so the casts don't truncate.
+    let add_group = |word: Word, group: u64| -> Result<Word, ParseError> {
+        let value = parse_8_decimal_digits(group).ok_or(ParseError::InvalidDigit)?;
+        Ok(word * (100_000_000u32 as Word) + (value as Word))
+    };
+    // Horner's method runs from the most significant digit. The whole groups of 8 are on the
+    // most significant side, so they come first, followed by the remaining digits.
+    let mut word: Word = 0;
+    match order {
+        BigEndian => {
+            let mut groups = src.chunks_exact(8);
+            for group in &mut groups {
+                word = add_group(word, u64::from_le_bytes(group.try_into().unwrap()))?;
+            }
+            for byte in groups.remainder() {
+                word = add_digit(word, *byte)?;
+            }
+        }
+        LittleEndian => {
+            let mut groups = src.rchunks_exact(8);
+            for group in &mut groups {
+                word = add_group(word, u64::from_be_bytes(group.try_into().unwrap()))?;
+            }
+            for byte in groups.remainder().iter().rev() {
+                word = add_digit(word, *byte)?;
+            }
+        }
+    }
+    Ok(word)
+}
+
+/// Convert 8 ASCII decimal digits, the most significant in the lowest byte, to their value.
+///
+/// Returns `None` if any of the bytes is not a decimal digit.
+#[inline]
+fn parse_8_decimal_digits(group: u64) -> Option<u32> {
+    const ONES: u64 = u64::from_le_bytes([1; 8]);
+    // Every byte must be 0x30..=0x39: its high nibble must be 3, and still be 3 after adding 6.
+    // A carry out of a byte can only happen if that byte is invalid.
+    if (group & (0xf0 * ONES)) | ((group.wrapping_add(0x06 * ONES) & (0xf0 * ONES)) >> 4)
+        != 0x33 * ONES
+    {
+        return None;
+    }
+    // Combine adjacent digits into 2, 4 and finally 8 digit numbers.
+    let mut x = group - 0x30 * ONES;
+    x = (x * 10 + (x >> 8)) & 0x00ff_00ff_00ff_00ff;
+    x = (x * 100 + (x >> 16)) & 0x0000_ffff_0000_ffff;
+    x = (x * 10000 + (x >> 32)) & 0x0000_0000_ffff_ffff;
+    Some(x as u32)
+}
+
 /// Parse an unsigned string to [UBig].
 ///
 /// The length of input is limited to `CHUNK_LEN * digits_per_word`.
-fn parse_chunk(bytes: &[u8], radix: Digit) -> Result<UBig, ParseError> {
+fn parse_chunk(bytes: &[u8], radix: Digit, order: DigitOrder) -> Result<UBig, ParseError> {
     debug_assert!(radix::is_radix_valid(radix) && !radix.is_power_of_two());
     let radix_info = radix::radix_info(radix);
     debug_assert!(bytes.len() <= CHUNK_LEN * radix_info.digits_per_word);
 
-    let groups = bytes.rchunks(radix_info.digits_per_word);
-    let mut buffer = Buffer::allocate(groups.len());
-    for group in groups.rev() {
-        let next = parse_word(group, radix)?;
+    let mut buffer = Buffer::allocate(math::ceil_div_usize(
+        bytes.len(),
+        radix_info.digits_per_word,
+    ));
+    let mut add_group = |group: &[u8]| -> Result<(), ParseError> {
+        let next = parse_word(group, radix, order)?;
         let carry = mul::mul_word_in_place_with_carry(&mut buffer, radix_info.range_per_word, next);
         if carry != 0 {
             buffer.push(carry);
         }
+        Ok(())
+    };
+    // Horner's method runs from the most significant group.
+    match order {
+        BigEndian => bytes
+            .rchunks(radix_info.digits_per_word)
+            .rev()
+            .try_for_each(&mut add_group)?,
+        LittleEndian => bytes
+            .chunks(radix_info.digits_per_word)
+            .rev()
+            .try_for_each(&mut add_group)?,
     }
     Ok(buffer.into())
 }
@@ -67,7 +174,7 @@ fn parse_chunk(bytes: &[u8], radix: Digit) -> Result<UBig, ParseError> {
 /// Parse an unsigned string to [UBig].
 ///
 /// This result will usually not fit in CHUNK_LEN words.
-fn parse_large(bytes: &[u8], radix: Digit) -> Result<UBig, ParseError> {
+fn parse_large(bytes: &[u8], radix: Digit, order: DigitOrder) -> Result<UBig, ParseError> {
     debug_assert!(radix::is_radix_valid(radix) && !radix.is_power_of_two());
     let radix_info = radix::radix_info(radix);
     let chunk_bytes = CHUNK_LEN * radix_info.digits_per_word;
@@ -84,7 +191,7 @@ fn parse_large(bytes: &[u8], radix: Digit) -> Result<UBig, ParseError> {
         radix_powers.push(new);
     }
 
-    parse_large_divide_conquer(bytes, radix, chunk_bytes, &radix_powers)
+    parse_large_divide_conquer(bytes, radix, order, chunk_bytes, &radix_powers)
 }
 
 /// Convert an unsigned string to [UBig].
@@ -93,23 +200,30 @@ fn parse_large(bytes: &[u8], radix: Digit) -> Result<UBig, ParseError> {
 fn parse_large_divide_conquer(
     bytes: &[u8],
     radix: Digit,
+    order: DigitOrder,
     chunk_bytes: usize,
     radix_powers: &[UBig],
 ) -> Result<UBig, ParseError> {
     debug_assert!(bytes.len() <= chunk_bytes << radix_powers.len());
 
     match radix_powers.split_last() {
-        None => parse_chunk(bytes, radix),
+        None => parse_chunk(bytes, radix, order),
         Some((radix_power, radix_powers)) => {
             let bytes_lo_len = chunk_bytes << radix_powers.len();
             if bytes.len() <= bytes_lo_len {
-                parse_large_divide_conquer(bytes, radix, chunk_bytes, radix_powers)
+                parse_large_divide_conquer(bytes, radix, order, chunk_bytes, radix_powers)
             } else {
-                let (bytes_hi, bytes_lo) = bytes.split_at(bytes.len() - bytes_lo_len);
+                let (bytes_hi, bytes_lo) = match order {
+                    BigEndian => bytes.split_at(bytes.len() - bytes_lo_len),
+                    LittleEndian => {
+                        let (lo, hi) = bytes.split_at(bytes_lo_len);
+                        (hi, lo)
+                    }
+                };
                 let res_hi =
-                    parse_large_divide_conquer(bytes_hi, radix, chunk_bytes, radix_powers)?;
+                    parse_large_divide_conquer(bytes_hi, radix, order, chunk_bytes, radix_powers)?;
                 let res_lo =
-                    parse_large_divide_conquer(bytes_lo, radix, chunk_bytes, radix_powers)?;
+                    parse_large_divide_conquer(bytes_lo, radix, order, chunk_bytes, radix_powers)?;
                 Ok(res_hi * radix_power + res_lo)
             }
         }
@@ -11,28 +11,44 @@ use crate::{
 
 /// Parse an unsigned string to [UBig].
 pub(crate) fn parse(src: &str, radix: Digit) -> Result<UBig, ParseError> {
+    parse_digits_le(src.as_bytes().iter().rev(), radix)
+}
+
+/// Parse an unsigned string with the least significant digit first to [UBig].
+pub(crate) fn parse_le(src: &str, radix: Digit) -> Result<UBig, ParseError> {
+    parse_digits_le(src.as_bytes().iter(), radix)
+}
+
+/// Parse digit bytes, least significant first, to [UBig].
+fn parse_digits_le<'a, I>(digits: I, radix: Digit) -> Result<UBig, ParseError>
+where
+    I: ExactSizeIterator<Item = &'a u8>,
+{
     debug_assert!(radix::is_radix_valid(radix) && radix.is_power_of_two());
     let radix_info = radix::radix_info(radix);
 
-    if src.len() <= radix_info.digits_per_word {
-        let word = parse_word(src, radix)?;
+    if digits.len() <= radix_info.digits_per_word {
+        let word = parse_word(digits, radix)?;
         Ok(UBig::from_word(word))
     } else {
-        parse_large(src, radix)
+        parse_large(digits, radix)
     }
 }
 
-/// Parse an unsigned string to `Word`.
+/// Parse digit bytes, least significant first, to `Word`.
 ///
-/// The length of the string must be at most digits_per_word(radix).
-fn parse_word(src: &str, radix: Digit) -> Result<Word, ParseError> {
+/// The number of digits must be at most digits_per_word(radix).
+fn parse_word<'a, I>(digits: I, radix: Digit) -> Result<Word, ParseError>
+where
+    I: ExactSizeIterator<Item = &'a u8>,
+{
     debug_assert!(radix::is_radix_valid(radix) && radix.is_power_of_two());
-    debug_assert!(src.len() <= radix::radix_info(radix).digits_per_word);
+    debug_assert!(digits.len() <= radix::radix_info(radix).digits_per_word);
 
     let log_radix = radix.trailing_zeros();
     let mut word = 0;
     let mut bits = 0;
-    for byte in src.as_bytes().iter().rev() {
+    for byte in digits {
         let digit = radix::digit_from_utf8_byte(*byte, radix).ok_or(ParseError::InvalidDigit)?;
         word |= (digit as Word) << bits;
         bits += log_radix;
@@ -40,22 +56,25 @@ fn parse_word(src: &str, radix: Digit) -> Result<Word, ParseError> {
     Ok(word)
 }
 
-/// Parse an unsigned string to [UBig].
+/// Parse digit bytes, least significant first, to [UBig].
 ///
 /// The result will usually not fit in a single word.
-fn parse_large(src: &str, radix: Digit) -> Result<UBig, ParseError> {
+fn parse_large<'a, I>(digits: I, radix: Digit) -> Result<UBig, ParseError>
+where
+    I: ExactSizeIterator<Item = &'a u8>,
+{
     debug_assert!(radix::is_radix_valid(radix) && radix.is_power_of_two());
 
     let log_radix = radix.trailing_zeros();
     #[allow(clippy::redundant_closure)]
-    let num_bits = src
+    let num_bits = digits
         .len()
         .checked_mul(log_radix as usize)
         .unwrap_or_else(|| UBig::panic_number_too_large());
     let mut buffer = Buffer::allocate((num_bits - 1) / WORD_BITS_USIZE + 1);
     let mut bits = 0;
     let mut word = 0;
-    for byte in src.as_bytes().iter().rev() {
+    for byte in digits {
         let digit = radix::digit_from_utf8_byte(*byte, radix).ok_or(ParseError::InvalidDigit)?;
         word |= (digit as Word) << bits;
         let new_bits = bits + log_radix;
@@ -1,57 +1,258 @@
 //! Exponentiation.
 
 use crate::{
+    arch::word::DoubleWord,
+    buffer::Buffer,
     ibig::IBig,
+    primitive::WORD_BITS_USIZE,
     sign::Sign::*,
     ubig::{Repr::*, UBig},
 };
 
+/// Receives a notification for every multiplication in [UBig::pow_counting].
+///
+/// `()` ignores them, so that [UBig::pow] compiles without a counter.
+trait MulCounter {
+    fn count(&mut self);
+}
+
+impl MulCounter for () {
+    #[inline]
+    fn count(&mut self) {}
+}
+
+impl MulCounter for usize {
+    #[inline]
+    fn count(&mut self) {
+        *self += 1;
+    }
+}
+
 impl UBig {
     /// Raises self to the power of `exp`.
     ///
+    /// By convention `0.pow(0)` is 1. Bases 0, 1 and 2 are computed directly, without any
+    /// multiplications.
+    ///
     /// # Example
     ///
     /// ```
     /// # use ibig::ubig;
     /// assert_eq!(ubig!(3).pow(3), ubig!(27));
+    /// assert_eq!(ubig!(0).pow(0), ubig!(1));
     /// ```
     #[inline]
     pub fn pow(&self, exp: usize) -> UBig {
+        self.pow_counting(exp, &mut ())
+    }
+
+    /// Raises self to the power of `exp`, also returning the number of multiplications performed.
+    ///
+    /// Squarings count as multiplications. Special bases such as 0, 1 and 2 need none. This is
+    /// meant for observing the behavior of [UBig::pow], which gives the same result.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// assert_eq!(ubig!(3).pow_instrumented(3), (ubig!(27), 2));
+    /// assert_eq!(ubig!(3).pow_instrumented(16), (ubig!(43046721), 4));
+    /// ```
+    pub fn pow_instrumented(&self, exp: usize) -> (UBig, usize) {
+        let mut mul_count = 0;
+        let res = self.pow_counting(exp, &mut mul_count);
+        (res, mul_count)
+    }
+
+    /// Checked exponentiation.
+    ///
+    /// Returns `None` if the result would exceed [UBig::MAX_BIT_LEN] bits, where
+    /// [pow](UBig::pow) would panic.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// assert_eq!(ubig!(3).checked_pow(3), Some(ubig!(27)));
+    /// assert_eq!(ubig!(3).checked_pow(usize::MAX), None);
+    /// ```
+    pub fn checked_pow(&self, exp: usize) -> Option<UBig> {
+        // The result has between (bits - 1) * exp + 1 and bits * exp bits.
+        let bits = self.bit_len();
+        if bits <= 1 || exp == 0 {
+            return Some(self.pow(exp));
+        }
+        match bits.checked_mul(exp) {
+            Some(max_bits) if max_bits <= UBig::MAX_BIT_LEN => return Some(self.pow(exp)),
+            _ => {}
+        }
+        match (bits - 1).checked_mul(exp) {
+            Some(min_bits) if min_bits < UBig::MAX_BIT_LEN => {}
+            _ => return None,
+        }
+        // Borderline: check every step. The intermediate results never exceed the final one.
+        let mut p = usize::BITS - 1 - exp.leading_zeros();
+        let mut res = self.clone();
+        while p != 0 {
+            p -= 1;
+            res = res.checked_mul(&res)?;
+            if exp & (1 << p) != 0 {
+                res = res.checked_mul(self)?;
+            }
+        }
+        Some(res)
+    }
+
+    /// `self * base^exp`.
+    ///
+    /// The power is computed once and multiplied into `self` at the end, which is cheaper than
+    /// multiplying `self` by `base` repeatedly. When `base` is a power of two, this is a shift.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// assert_eq!(ubig!(7).mul_pow(&ubig!(10), 3), ubig!(7000));
+    /// assert_eq!(ubig!(7).mul_pow(&ubig!(4), 3), ubig!(448));
+    /// ```
+    pub fn mul_pow(&self, base: &UBig, exp: usize) -> UBig {
+        if exp == 0 {
+            return self.clone();
+        }
+        if self.is_zero() {
+            return UBig::from_word(0);
+        }
+        if base.is_power_of_two() {
+            #[allow(clippy::redundant_closure)]
+            let bits = (base.bit_len() - 1)
+                .checked_mul(exp)
+                .unwrap_or_else(|| UBig::panic_number_too_large());
+            return self << bits;
+        }
+        self * base.pow(exp)
+    }
+
+    /// Predicted bit length of `self.pow(exp)`, without computing the power.
+    ///
+    /// Returns `None` if the prediction overflows `usize`. The prediction is `bit_len * exp`,
+    /// which is an upper bound: the actual bit length is smaller by less than `exp`. It is exact
+    /// when `self` is 0, 1, or a power of two. This is cheap and can be used to decide whether a
+    /// `pow` is feasible before attempting it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// assert_eq!(ubig!(4).checked_pow_bits(&ubig!(10)), Some(21));
+    /// assert_eq!(ubig!(4).pow(10).bit_len(), 21);
+    /// assert_eq!(ubig!(5).checked_pow_bits(&ubig!(10)), Some(30));
+    /// assert_eq!(ubig!(5).pow(10).bit_len(), 24);
+    /// assert_eq!(ubig!(5).checked_pow_bits(&(ubig!(1) << 100)), None);
+    /// ```
+    pub fn checked_pow_bits(&self, exp: &UBig) -> Option<usize> {
+        if *exp == UBig::from_word(0) {
+            return Some(1);
+        }
+        let bits = self.bit_len();
+        if bits <= 1 {
+            return Some(bits);
+        }
+        let exp = usize::try_from(exp).ok()?;
+        if self.is_power_of_two() {
+            (bits - 1).checked_mul(exp)?.checked_add(1)
+        } else {
+            bits.checked_mul(exp)
+        }
+    }
+
+    /// Evaluates a polynomial at `x` using Horner's method.
+    ///
+    /// `coeffs[i]` is the coefficient of `x^i`, so the constant term comes first. An empty
+    /// polynomial evaluates to 0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ibig::{ubig, UBig};
+    /// // 1 + 2x + 3x^2 at x = 10
+    /// assert_eq!(UBig::eval_poly(&[ubig!(1), ubig!(2), ubig!(3)], &ubig!(10)), ubig!(321));
+    /// ```
+    pub fn eval_poly(coeffs: &[UBig], x: &UBig) -> UBig {
+        let mut coeffs = coeffs.iter().rev();
+        let mut acc = match coeffs.next() {
+            None => return UBig::from_word(0),
+            Some(c) => c.clone(),
+        };
+        for c in coeffs {
+            acc *= x;
+            acc += c;
+        }
+        acc
+    }
+
+    /// Square-and-multiply, reporting each multiplication to `mul_count`.
+    fn pow_counting<C: MulCounter>(&self, exp: usize, mul_count: &mut C) -> UBig {
         match exp {
             0 => return UBig::from_word(1),
             1 => return self.clone(),
-            2 => return self * self,
             _ => {}
         }
         match self.repr() {
             Small(0) => return UBig::from_word(0),
             Small(1) => return UBig::from_word(1),
-            Small(2) => {
-                let mut x = UBig::from_word(0);
-                x.set_bit(exp);
-                return x;
-            }
+            Small(2) => return UBig::pow2(exp),
             _ => {}
         }
         let mut p = usize::BITS - 2 - exp.leading_zeros();
         let mut res = self * self;
+        mul_count.count();
         loop {
             if exp & (1 << p) != 0 {
                 res *= self;
+                mul_count.count();
             }
             if p == 0 {
                 break;
             }
             p -= 1;
             res = &res * &res;
+            mul_count.count();
         }
         res
     }
+
+    /// 2 raised to the power of `exp`.
+    ///
+    /// Equivalent to `ubig!(1) << exp`, but builds the result directly.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ibig::{ubig, UBig};
+    /// assert_eq!(UBig::pow2(10), ubig!(1024));
+    /// assert_eq!(UBig::pow2(100), ubig!(1) << 100);
+    /// ```
+    #[inline]
+    pub fn pow2(exp: usize) -> UBig {
+        if exp < WORD_BITS_USIZE {
+            UBig::from_word(1 << exp)
+        } else if exp < 2 * WORD_BITS_USIZE {
+            UBig::from_double_word((1 as DoubleWord) << exp)
+        } else {
+            let idx = exp / WORD_BITS_USIZE;
+            let mut buffer = Buffer::allocate(idx + 1);
+            buffer.push_zeros(idx);
+            buffer.push(1 << (exp % WORD_BITS_USIZE));
+            buffer.into()
+        }
+    }
 }
 
 impl IBig {
     /// Raises self to the power of `exp`.
     ///
+    /// By convention `0.pow(0)` is 1.
+    ///
     /// # Example
     ///
     /// ```
@@ -67,4 +268,42 @@ impl IBig {
         };
         IBig::from_sign_magnitude(sign, self.magnitude().pow(exp))
     }
+
+    /// Evaluates a polynomial at `x` using Horner's method.
+    ///
+    /// `coeffs[i]` is the coefficient of `x^i`, so the constant term comes first. An empty
+    /// polynomial evaluates to 0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ibig::{ibig, IBig};
+    /// // 1 - 2x + 3x^2 at x = -10
+    /// assert_eq!(IBig::eval_poly(&[ibig!(1), ibig!(-2), ibig!(3)], &ibig!(-10)), ibig!(321));
+    /// ```
+    pub fn eval_poly(coeffs: &[IBig], x: &IBig) -> IBig {
+        let mut coeffs = coeffs.iter().rev();
+        let mut acc = match coeffs.next() {
+            None => return IBig::from(0u8),
+            Some(c) => c.clone(),
+        };
+        for c in coeffs {
+            acc *= x;
+            acc += c;
+        }
+        acc
+    }
+
+    /// 2 raised to the power of `exp`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ibig::{ibig, IBig};
+    /// assert_eq!(IBig::pow2(10), ibig!(1024));
+    /// ```
+    #[inline]
+    pub fn pow2(exp: usize) -> IBig {
+        IBig::from(UBig::pow2(exp))
+    }
 }
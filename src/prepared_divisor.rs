@@ -0,0 +1,157 @@
+//! Division by a prepared divisor.
+
+use crate::{
+    arch::word::Word,
+    div,
+    fast_divide::FastDivideNormalized,
+    ops::DivRem,
+    primitive::{double_word, split_double_word},
+    ubig::{Repr::*, UBig},
+};
+use core::ops::Rem;
+
+/// A divisor prepared for fast repeated division.
+///
+/// Dividing by a `u64` has to compute a reciprocal of the divisor first. A `PreparedDivisor`
+/// computes it once in [PreparedDivisor::new], so that dividing many numbers by the same divisor
+/// only needs multiplications. The savings are largest for remainders and for numbers a few
+/// words long; for long quotients the division loop itself dominates.
+///
+/// # Examples
+///
+/// ```
+/// # use ibig::{ubig, PreparedDivisor};
+/// let d = PreparedDivisor::new(10);
+/// assert_eq!(ubig!(1234).div_rem(&d), (ubig!(123), 4));
+/// assert_eq!(&ubig!(1234) % &d, 4);
+/// ```
+#[derive(Clone)]
+pub struct PreparedDivisor(PreparedDivisorRepr);
+
+#[derive(Clone)]
+enum PreparedDivisorRepr {
+    /// A divisor that fits in a `Word`, with the reciprocal of the divisor shifted so that its
+    /// top bit is 1.
+    Word {
+        divisor: Word,
+        fast_div: FastDivideNormalized,
+    },
+    /// A divisor that doesn't fit in a `Word`. Only possible for words narrower than 64 bits.
+    Large(UBig),
+}
+
+impl PreparedDivisor {
+    /// Prepare a divisor.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `divisor` is 0.
+    #[allow(clippy::useless_conversion)]
+    pub fn new(divisor: u64) -> PreparedDivisor {
+        assert!(divisor != 0, "divide by 0");
+        let repr = match Word::try_from(divisor) {
+            Ok(divisor) => PreparedDivisorRepr::Word {
+                divisor,
+                fast_div: FastDivideNormalized::new(divisor << divisor.leading_zeros()),
+            },
+            Err(_) => PreparedDivisorRepr::Large(UBig::from(divisor)),
+        };
+        PreparedDivisor(repr)
+    }
+
+    /// The divisor.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::PreparedDivisor;
+    /// assert_eq!(PreparedDivisor::new(10).divisor(), 10);
+    /// ```
+    #[allow(clippy::useless_conversion)]
+    pub fn divisor(&self) -> u64 {
+        match &self.0 {
+            PreparedDivisorRepr::Word { divisor, .. } => (*divisor).into(),
+            PreparedDivisorRepr::Large(divisor) => divisor.try_into().unwrap(),
+        }
+    }
+}
+
+impl DivRem<&PreparedDivisor> for UBig {
+    type OutputDiv = UBig;
+    type OutputRem = u64;
+
+    #[inline]
+    #[allow(clippy::useless_conversion)]
+    fn div_rem(self, rhs: &PreparedDivisor) -> (UBig, u64) {
+        match (self.into_repr(), &rhs.0) {
+            (Large(mut buffer), PreparedDivisorRepr::Word { divisor, fast_div }) => {
+                let rem = div::fast_div_by_word_in_place(&mut buffer, *divisor, *fast_div);
+                (buffer.into(), rem.into())
+            }
+            (Small(word), _) => DivRem::div_rem(&UBig::from_word(word), rhs),
+            (Double(dword), _) => DivRem::div_rem(&UBig::from_double_word(dword), rhs),
+            (Large(buffer), PreparedDivisorRepr::Large(_)) => {
+                DivRem::div_rem(&UBig::from(buffer), rhs)
+            }
+        }
+    }
+}
+
+impl DivRem<&PreparedDivisor> for &UBig {
+    type OutputDiv = UBig;
+    type OutputRem = u64;
+
+    #[inline]
+    #[allow(clippy::useless_conversion)]
+    fn div_rem(self, rhs: &PreparedDivisor) -> (UBig, u64) {
+        match (self.repr(), &rhs.0) {
+            // A single hardware division is faster than the reciprocal.
+            (Small(word), PreparedDivisorRepr::Word { divisor, .. }) => {
+                (UBig::from_word(word / divisor), (word % divisor).into())
+            }
+            (Double(dword), PreparedDivisorRepr::Word { divisor, fast_div }) => {
+                let (lo, hi) = split_double_word(*dword);
+                let mut words = [lo, hi];
+                let rem = div::fast_div_by_word_in_place(&mut words, *divisor, *fast_div);
+                (
+                    UBig::from_double_word(double_word(words[0], words[1])),
+                    rem.into(),
+                )
+            }
+            (Large(buffer), PreparedDivisorRepr::Word { divisor, fast_div }) => {
+                let mut buffer = buffer.clone();
+                let rem = div::fast_div_by_word_in_place(&mut buffer, *divisor, *fast_div);
+                (buffer.into(), rem.into())
+            }
+            (_, PreparedDivisorRepr::Large(divisor)) => {
+                let (q, r) = DivRem::div_rem(self, divisor);
+                (q, r.try_into().unwrap())
+            }
+        }
+    }
+}
+
+impl Rem<&PreparedDivisor> for &UBig {
+    type Output = u64;
+
+    #[inline]
+    #[allow(clippy::useless_conversion)]
+    fn rem(self, rhs: &PreparedDivisor) -> u64 {
+        match (self.repr(), &rhs.0) {
+            (Small(word), PreparedDivisorRepr::Word { divisor, .. }) => (word % divisor).into(),
+            (_, PreparedDivisorRepr::Word { divisor, fast_div }) => {
+                div::fast_rem_by_word(&self.as_words(), *divisor, *fast_div).into()
+            }
+            (_, PreparedDivisorRepr::Large(divisor)) => (self % divisor).try_into().unwrap(),
+        }
+    }
+}
+
+impl Rem<&PreparedDivisor> for UBig {
+    type Output = u64;
+
+    #[inline]
+    fn rem(self, rhs: &PreparedDivisor) -> u64 {
+        &self % rhs
+    }
+}
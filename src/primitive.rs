@@ -36,6 +36,8 @@ where
     Self: From<u8>,
     Self: TryFrom<Word>,
     Self: TryInto<Word>,
+    Self: TryFrom<DoubleWord>,
+    Self: TryInto<DoubleWord>,
     Self: TryInto<usize>,
     Self: Eq,
     Self: Add<Output = Self>,
@@ -72,8 +72,14 @@ pub(crate) struct RadixInfo {
     /// Faster division by normalized range_per_word.
     /// Only for non-power-of-2 radixes.
     pub(crate) fast_div_range_per_word: FastDivideNormalized,
+
+    /// log2(radix) rounded up, in fixed point with `LOG2_RADIX_FRAC_BITS` fractional bits.
+    pub(crate) log2_radix_ceil: u64,
 }
 
+/// Number of fractional bits of `RadixInfo::log2_radix_ceil`.
+pub(crate) const LOG2_RADIX_FRAC_BITS: u32 = 32;
+
 /// RadixInfo for a given radix.
 #[inline]
 pub(crate) fn radix_info(radix: Digit) -> &'static RadixInfo {
@@ -90,6 +96,7 @@ impl RadixInfo {
                 range_per_word: 0,
                 fast_div_radix,
                 fast_div_range_per_word: FastDivideNormalized::dummy(),
+                log2_radix_ceil: (radix.trailing_zeros() as u64) << LOG2_RADIX_FRAC_BITS,
             }
         } else {
             let mut digits_per_word = 0;
@@ -105,11 +112,35 @@ impl RadixInfo {
                 range_per_word,
                 fast_div_radix,
                 fast_div_range_per_word,
+                log2_radix_ceil: log2_ceil_fixed(radix),
             }
         }
     }
 }
 
+/// log2(x) rounded up, in fixed point with `LOG2_RADIX_FRAC_BITS` fractional bits.
+///
+/// Computes the fractional bits one at a time by repeated squaring of `x / 2^floor(log2(x))`,
+/// kept in fixed point with 62 fractional bits. Truncating the squares makes the result a lower
+/// bound off by less than 2 units, so adding 2 units makes it an upper bound.
+const fn log2_ceil_fixed(x: Digit) -> u64 {
+    let int_part = Digit::BITS - 1 - x.leading_zeros();
+    // y in [1, 2)
+    let mut y = (x as u128) << (62 - int_part);
+    let mut frac_part: u64 = 0;
+    let mut i = 0;
+    while i < LOG2_RADIX_FRAC_BITS {
+        y = (y * y) >> 62;
+        frac_part <<= 1;
+        if y >= 1 << 63 {
+            frac_part |= 1;
+            y >>= 1;
+        }
+        i += 1;
+    }
+    ((int_part as u64) << LOG2_RADIX_FRAC_BITS) + frac_part + 2
+}
+
 type RadixInfoTable = [RadixInfo; MAX_RADIX as usize + 1];
 
 static RADIX_INFO_TABLE: RadixInfoTable = generate_radix_info_table();
@@ -120,6 +151,7 @@ const fn generate_radix_info_table() -> RadixInfoTable {
         range_per_word: 0,
         fast_div_radix: FastDivideSmall::dummy(),
         fast_div_range_per_word: FastDivideNormalized::dummy(),
+        log2_radix_ceil: 0,
     }; MAX_RADIX as usize + 1];
 
     let mut radix = 2;
@@ -149,6 +181,9 @@ mod tests {
                     (radix as Word).pow(info.digits_per_word as u32)
                 );
             }
+            let log2_radix = (radix as f64).log2() * (1u64 << LOG2_RADIX_FRAC_BITS) as f64;
+            assert!(info.log2_radix_ceil as f64 >= log2_radix);
+            assert!(info.log2_radix_ceil as f64 <= log2_radix + 3.0);
         }
     }
 
@@ -4,7 +4,7 @@ use crate::{
     arch::word::Word,
     buffer::Buffer,
     ibig::IBig,
-    ops::UnsignedAbs,
+    number_theory,
     ubig::{Repr::*, UBig},
 };
 
@@ -32,6 +32,7 @@ impl UBig {
 
         match range.repr() {
             Small(word) => UBig::from_word(rng.gen_range(0..*word)),
+            Double(dword) => UBig::from_double_word(rng.gen_range(0..*dword)),
             Large(buffer) => UBig::uniform_large(buffer, rng),
         }
     }
@@ -48,6 +49,56 @@ impl UBig {
         }
         buffer.into()
     }
+
+    /// Random number with exactly `bits` bits, i.e. in range [2^(bits-1)..2^bits)
+    fn random_bits_exact<R>(bits: usize, rng: &mut R) -> UBig
+    where
+        R: Rng + ?Sized,
+    {
+        debug_assert!(bits >= 1);
+        let low = UBig::pow2(bits - 1);
+        UBig::uniform(&low, rng) + low
+    }
+
+    /// Random probable prime with exactly `bits` bits.
+    ///
+    /// Starts from a random odd number with the top bit set and searches upwards for a prime, the
+    /// same way as [UBig::next_prime]. If the search goes past `bits` bits, it starts over.
+    ///
+    /// The result is a probable prime according to [UBig::is_prime_bpsw]: no composite number
+    /// passing that test is known. Primes that follow large gaps are somewhat more likely to be
+    /// chosen.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ibig::UBig;
+    /// use rand::thread_rng;
+    /// let p = UBig::random_prime(256, &mut thread_rng());
+    /// assert_eq!(p.bit_len(), 256);
+    /// assert!(p.is_prime_bpsw());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bits < 2`.
+    pub fn random_prime<R>(bits: usize, rng: &mut R) -> UBig
+    where
+        R: Rng + ?Sized,
+    {
+        match bits {
+            0 | 1 => panic!("random_prime with fewer than 2 bits"),
+            2 => UBig::from_word(if rng.gen() { 2 } else { 3 }),
+            _ => loop {
+                let mut start = UBig::random_bits_exact(bits, rng);
+                start.set_bit(0);
+                let p = number_theory::sieve_search(start, true);
+                if p.bit_len() == bits {
+                    break p;
+                }
+            },
+        }
+    }
 }
 
 /// Try to fill `sample` with random number in range [0..words).
@@ -2,7 +2,7 @@ use crate::{arch::word::Word, buffer::Buffer, ibig::IBig, primitive::WORD_BITS_U
 use alloc::vec::Vec;
 use core::fmt::{self, Formatter};
 use serde::{
-    de::{Deserialize, Deserializer, SeqAccess, Visitor},
+    de::{Deserialize, Deserializer, Error, SeqAccess, Visitor},
     ser::{Serialize, SerializeSeq, Serializer},
 };
 use static_assertions::const_assert;
@@ -13,7 +13,8 @@ const WORDS_PER_U64: usize = 64 / WORD_BITS_USIZE;
 impl Serialize for UBig {
     #[allow(clippy::useless_conversion)]
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
-        let chunks = self.as_words().chunks(WORDS_PER_U64);
+        let words = self.as_words();
+        let chunks = words.chunks(WORDS_PER_U64);
         let mut seq = serializer.serialize_seq(Some(chunks.len()))?;
         for chunk in chunks {
             let mut word_u64: u64 = 0;
@@ -26,22 +27,87 @@ impl Serialize for UBig {
     }
 }
 
+/// Deserialization accepts any length.
+///
+/// The length of the input is only limited by [UBig::MAX_BIT_LEN], and memory is allocated for
+/// the length announced by the input. Use [BoundedUBig] for untrusted input.
 impl<'de> Deserialize<'de> for UBig {
     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
-        deserializer.deserialize_seq(UBigVisitor)
+        deserializer.deserialize_seq(UBigVisitor {
+            max_len_64: usize::MAX,
+        })
     }
 }
 
-struct UBigVisitor;
+/// A [UBig] that only deserializes from at most `MAX_WORDS` 64-bit words.
+///
+/// [UBig] is serialized as a sequence of 64-bit words. When deserializing from untrusted input,
+/// wrap it in `BoundedUBig` to reject longer sequences before any memory is allocated for them.
+/// Serialization is the same as for [UBig].
+///
+/// # Examples
+///
+/// ```
+/// # use ibig::{ubig, BoundedUBig, UBig};
+/// // At most 256 bits.
+/// type UBig256 = BoundedUBig<4>;
+/// let x = UBig256::from(ubig!(1000));
+/// assert_eq!(UBig::from(x), ubig!(1000));
+/// ```
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct BoundedUBig<const MAX_WORDS: usize>(pub UBig);
+
+impl<const MAX_WORDS: usize> From<UBig> for BoundedUBig<MAX_WORDS> {
+    #[inline]
+    fn from(x: UBig) -> Self {
+        BoundedUBig(x)
+    }
+}
+
+impl<const MAX_WORDS: usize> From<BoundedUBig<MAX_WORDS>> for UBig {
+    #[inline]
+    fn from(x: BoundedUBig<MAX_WORDS>) -> UBig {
+        x.0
+    }
+}
+
+impl<const MAX_WORDS: usize> Serialize for BoundedUBig<MAX_WORDS> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
+
+impl<'de, const MAX_WORDS: usize> Deserialize<'de> for BoundedUBig<MAX_WORDS> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let x = deserializer.deserialize_seq(UBigVisitor {
+            max_len_64: MAX_WORDS,
+        })?;
+        Ok(BoundedUBig(x))
+    }
+}
+
+struct UBigVisitor {
+    /// Maximum number of 64-bit words.
+    max_len_64: usize,
+}
 
 impl<'de> Visitor<'de> for UBigVisitor {
     type Value = UBig;
 
     fn expecting(&self, f: &mut Formatter) -> fmt::Result {
-        write!(f, "a sequence of 64-bit words")
+        if self.max_len_64 == usize::MAX {
+            write!(f, "a sequence of 64-bit words")
+        } else {
+            write!(f, "a sequence of at most {} 64-bit words", self.max_len_64)
+        }
     }
 
     fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<UBig, A::Error> {
+        if let Some(len_64) = seq.size_hint() {
+            if len_64 > self.max_len_64 {
+                return Err(A::Error::invalid_length(len_64, &self));
+            }
+        }
         match seq.size_hint() {
             Some(0) => {
                 assert!(seq.next_element::<u64>()?.is_none());
@@ -64,6 +130,9 @@ impl<'de> Visitor<'de> for UBigVisitor {
             None => {
                 let mut words_64 = Vec::new();
                 while let Some(word_64) = seq.next_element()? {
+                    if words_64.len() == self.max_len_64 {
+                        return Err(A::Error::invalid_length(words_64.len() + 1, &self));
+                    }
                     words_64.push(word_64);
                 }
                 let mut buffer = Buffer::allocate(len_64_to_max_len(words_64.len()));
@@ -1,7 +1,7 @@
 //! Bit shift operators.
 
 use crate::{
-    arch::word::Word,
+    arch::word::{DoubleWord, Word},
     buffer::Buffer,
     ibig::IBig,
     primitive::{double_word, extend_word, split_double_word, WORD_BITS_USIZE},
@@ -93,6 +93,7 @@ impl Shl<usize> for UBig {
         match self.into_repr() {
             Small(0) => UBig::from_word(0),
             Small(word) => UBig::shl_word(word, rhs),
+            Double(dword) => UBig::shl_double_word(dword, rhs),
             Large(buffer) => UBig::shl_large(buffer, rhs),
         }
     }
@@ -106,6 +107,7 @@ impl Shl<usize> for &UBig {
         match self.repr() {
             Small(0) => UBig::from_word(0),
             Small(word) => UBig::shl_word(*word, rhs),
+            Double(dword) => UBig::shl_double_word(*dword, rhs),
             Large(buffer) => UBig::shl_ref_large(buffer, rhs),
         }
     }
@@ -118,6 +120,7 @@ impl Shr<usize> for UBig {
     fn shr(self, rhs: usize) -> UBig {
         match self.into_repr() {
             Small(word) => UBig::shr_word(word, rhs),
+            Double(dword) => UBig::shr_double_word(dword, rhs),
             Large(buffer) => UBig::shr_large(buffer, rhs),
         }
     }
@@ -130,6 +133,7 @@ impl Shr<usize> for &UBig {
     fn shr(self, rhs: usize) -> UBig {
         match self.repr() {
             Small(word) => UBig::shr_word(*word, rhs),
+            Double(dword) => UBig::shr_double_word(*dword, rhs),
             Large(buffer) => UBig::shr_large_ref(buffer, rhs),
         }
     }
@@ -164,7 +168,7 @@ impl Shr<usize> for IBig {
         match sign {
             Positive => IBig::from(mag.shr(rhs)),
             Negative => {
-                let b = mag.are_low_bits_nonzero(rhs);
+                let b = mag.any_bit_set(0..rhs);
                 -IBig::from(mag.shr(rhs)) - IBig::from(b)
             }
         }
@@ -180,13 +184,91 @@ impl Shr<usize> for &IBig {
         match sign {
             Positive => IBig::from(mag.shr(rhs)),
             Negative => {
-                let b = mag.are_low_bits_nonzero(rhs);
+                let b = mag.any_bit_set(0..rhs);
                 -IBig::from(mag.shr(rhs)) - IBig::from(b)
             }
         }
     }
 }
 
+impl UBig {
+    /// Shift left within a fixed width: `(self << n) mod 2^width`.
+    ///
+    /// Unlike `<<`, bits shifted to positions `width` and above are lost, as in a `width`-bit
+    /// register. Bits of `self` at positions `width` and above are ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// assert_eq!(ubig!(0b1011).wrapping_shl(2, 5), ubig!(0b01100));
+    /// assert_eq!(ubig!(0b1011).wrapping_shl(5, 5), ubig!(0));
+    /// ```
+    pub fn wrapping_shl(&self, n: usize, width: usize) -> UBig {
+        if n >= width {
+            UBig::from_word(0)
+        } else {
+            self.truncate_bits(width - n) << n
+        }
+    }
+
+    /// Shift right within a fixed width: `(self mod 2^width) >> n`.
+    ///
+    /// Bits of `self` at positions `width` and above are ignored, as in a `width`-bit register.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// assert_eq!(ubig!(0b111011).wrapping_shr(2, 5), ubig!(0b110));
+    /// ```
+    pub fn wrapping_shr(&self, n: usize, width: usize) -> UBig {
+        self.truncate_bits(width) >> n
+    }
+
+    /// Shift right, also returning the bits shifted out: `(self >> n, self mod 2^n)`.
+    ///
+    /// This is division by `2^n` with remainder.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// assert_eq!(ubig!(0b110101).shr_extract(4), (ubig!(0b11), ubig!(0b0101)));
+    /// assert_eq!(ubig!(0b110101).shr_extract(0), (ubig!(0b110101), ubig!(0)));
+    /// ```
+    #[inline]
+    pub fn shr_extract(&self, n: usize) -> (UBig, UBig) {
+        (self >> n, self.truncate_bits(n))
+    }
+
+    /// Shift left, unless the result would be too large: `self << n`.
+    ///
+    /// Returns `None` if the result would have more than `max_bits` bits, or more than
+    /// [UBig::MAX_BIT_LEN] bits. The length is checked before anything is allocated, so this can
+    /// be used to reject untrusted shift amounts. `<<` itself panics only when the result exceeds
+    /// [UBig::MAX_BIT_LEN], and below that it allocates whatever the shift requires.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// assert_eq!(ubig!(0b101).checked_shl(5, 8), Some(ubig!(0b10100000)));
+    /// assert_eq!(ubig!(0b101).checked_shl(6, 8), None);
+    /// assert_eq!(ubig!(0).checked_shl(usize::MAX, 8), Some(ubig!(0)));
+    /// ```
+    pub fn checked_shl(&self, n: usize, max_bits: usize) -> Option<UBig> {
+        if self.is_zero() {
+            return Some(UBig::from_word(0));
+        }
+        let bit_len = self.bit_len().checked_add(n)?;
+        if bit_len > max_bits.min(UBig::MAX_BIT_LEN) {
+            return None;
+        }
+        Some(self << n)
+    }
+}
+
 impl UBig {
     /// Shift left one non-zero `Word` by `rhs` bits.
     #[inline]
@@ -194,7 +276,7 @@ impl UBig {
         debug_assert!(word != 0);
 
         if rhs <= WORD_BITS_USIZE {
-            UBig::from(extend_word(word) << rhs)
+            UBig::from_double_word(extend_word(word) << rhs)
         } else {
             UBig::shl_word_slow(word, rhs)
         }
@@ -212,23 +294,42 @@ impl UBig {
         buffer.into()
     }
 
+    /// Shift left one `DoubleWord` that doesn't fit in a `Word` by `rhs` bits.
+    #[inline]
+    fn shl_double_word(dword: DoubleWord, rhs: usize) -> UBig {
+        if rhs < dword.leading_zeros() as usize {
+            UBig::from_double_word(dword << rhs)
+        } else {
+            let (lo, hi) = split_double_word(dword);
+            UBig::shl_ref_large(&[lo, hi], rhs)
+        }
+    }
+
     /// Shift left `buffer` by `rhs` bits.
+    ///
+    /// Reuses `buffer` if it has enough capacity.
     fn shl_large(mut buffer: Buffer, rhs: usize) -> UBig {
         let shift_words = rhs / WORD_BITS_USIZE;
+        let shift_bits = (rhs % WORD_BITS_USIZE) as u32;
+        // A carry word is only needed if high bits are shifted out of the top word.
+        let has_carry = shift_bits > buffer.last().unwrap().leading_zeros();
 
-        if buffer.capacity() < buffer.len() + shift_words + 1 {
+        if buffer.capacity() < buffer.len() + shift_words + has_carry as usize {
             return UBig::shl_ref_large(&buffer, rhs);
         }
 
-        let shift_bits = (rhs % WORD_BITS_USIZE) as u32;
         let carry = shift::shl_in_place(&mut buffer, shift_bits);
-        buffer.push(carry);
-        buffer.push_zeros_front(shift_words);
+        if has_carry {
+            buffer.push(carry);
+        }
+        if shift_words != 0 {
+            buffer.push_zeros_front(shift_words);
+        }
         buffer.into()
     }
 
     /// Shift left large number of words by `rhs` bits.
-    fn shl_ref_large(words: &[Word], rhs: usize) -> UBig {
+    pub(crate) fn shl_ref_large(words: &[Word], rhs: usize) -> UBig {
         let shift_words = rhs / WORD_BITS_USIZE;
         let shift_bits = (rhs % WORD_BITS_USIZE) as u32;
 
@@ -251,8 +352,19 @@ impl UBig {
         UBig::from_word(word)
     }
 
+    /// Shift right one `DoubleWord` by `rhs` bits.
+    #[inline]
+    fn shr_double_word(dword: DoubleWord, rhs: usize) -> UBig {
+        let dword = if rhs < DoubleWord::BITS as usize {
+            dword >> rhs
+        } else {
+            0
+        };
+        UBig::from_double_word(dword)
+    }
+
     /// Shift right `buffer` by `rhs` bits.
-    fn shr_large(mut buffer: Buffer, rhs: usize) -> UBig {
+    pub(crate) fn shr_large(mut buffer: Buffer, rhs: usize) -> UBig {
         let shift_words = rhs / WORD_BITS_USIZE;
         if shift_words >= buffer.len() {
             return UBig::from_word(0);
@@ -273,7 +385,7 @@ impl UBig {
         match words {
             [] => UBig::from_word(0),
             &[w] => UBig::from_word(w >> shift_bits),
-            &[lo, hi] => UBig::from(double_word(lo, hi) >> shift_bits),
+            &[lo, hi] => UBig::from_double_word(double_word(lo, hi) >> shift_bits),
             _ => {
                 let mut buffer = Buffer::allocate(words.len());
                 buffer.extend(words);
@@ -7,10 +7,29 @@ use crate::{
 };
 use core::ops::Neg;
 
+/// The sign of an [IBig].
+///
+/// Zero is `Positive`.
+///
+/// Signs can be negated and multiplied. The product of two signs is `Negative` if exactly one of
+/// them is `Negative`, like an exclusive or.
+///
+/// # Examples
+///
+/// ```
+/// # use ibig::{ibig, Sign};
+/// assert_eq!(ibig!(-5).sign(), Sign::Negative);
+/// assert_eq!(ibig!(0).sign(), Sign::Positive);
+/// assert_eq!(Sign::Negative * Sign::Negative, Sign::Positive);
+/// assert_eq!(Sign::Negative * ibig!(5), ibig!(-5));
+/// assert_eq!(-Sign::Positive, Sign::Negative);
+/// ```
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
-pub(crate) enum Sign {
+pub enum Sign {
+    /// Zero or greater.
     Positive,
+    /// Less than zero.
     Negative,
 }
 
@@ -53,6 +72,42 @@ impl IBig {
             Negative => IBig::from(-1i8),
         }
     }
+
+    /// The absolute value as a [UBig], consuming `self`.
+    ///
+    /// The magnitude is moved out without copying. To borrow it instead, use
+    /// [IBig::magnitude].
+    ///
+    /// # Examples
+    /// ```
+    /// # use ibig::{ibig, ubig};
+    /// assert_eq!(ibig!(-5).unsigned_abs(), ubig!(5));
+    /// ```
+    #[inline]
+    pub fn unsigned_abs(self) -> UBig {
+        let (_, mag) = self.into_sign_magnitude();
+        mag
+    }
+
+    /// The absolute value as a [UBig], without consuming `self`.
+    ///
+    /// The ways to get the absolute value differ in their result type and ownership:
+    /// * [Abs::abs] returns an [IBig], staying in the signed type.
+    /// * [IBig::unsigned_abs] returns a [UBig], consuming `self` without copying.
+    /// * `abs_unsigned` returns a [UBig], copying the magnitude.
+    /// * [IBig::magnitude] borrows the magnitude as a `&UBig`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ibig::{ibig, ubig};
+    /// let a = ibig!(-5);
+    /// assert_eq!(a.abs_unsigned(), ubig!(5));
+    /// assert_eq!(a.abs_unsigned(), *a.magnitude());
+    /// ```
+    #[inline]
+    pub fn abs_unsigned(&self) -> UBig {
+        self.magnitude().clone()
+    }
 }
 
 impl Neg for IBig {
@@ -97,8 +152,7 @@ impl UnsignedAbs for IBig {
 
     #[inline]
     fn unsigned_abs(self) -> UBig {
-        let (_, mag) = self.into_sign_magnitude();
-        mag
+        IBig::unsigned_abs(self)
     }
 }
 
@@ -2,22 +2,27 @@
 
 use self::Repr::*;
 use crate::{
-    arch::{ntt, word::Word},
+    arch::{
+        ntt,
+        word::{DoubleWord, Word},
+    },
     buffer::Buffer,
     math,
-    primitive::WORD_BITS_USIZE,
+    primitive::{double_word, split_double_word, WORD_BITS_USIZE},
 };
-use core::slice;
+use core::{ops::Deref, slice};
 
 /// Internal representation of UBig.
 #[derive(Debug, Eq, Hash, PartialEq)]
 pub(crate) enum Repr {
     /// A number that fits in a single Word.
     Small(Word),
-    /// A number that does not fit in a single Word.
+    /// A number that fits in a DoubleWord, but not in a single Word.
+    Double(DoubleWord),
+    /// A number that does not fit in a DoubleWord.
     ///
     /// The buffer has:
-    /// * length at least 2
+    /// * length at least 3
     /// * no leading zero
     /// * compact capacity
     Large(Buffer),
@@ -49,12 +54,29 @@ impl UBig {
         UBig(Small(word))
     }
 
+    /// Construct from a double word.
+    #[inline]
+    pub(crate) fn from_double_word(dw: DoubleWord) -> UBig {
+        match Word::try_from(dw) {
+            Ok(word) => UBig(Small(word)),
+            Err(_) => UBig(Double(dw)),
+        }
+    }
+
     /// Get the representation of UBig.
     #[inline]
     pub(crate) fn repr(&self) -> &Repr {
         &self.0
     }
 
+    /// Get the mutable representation of UBig.
+    ///
+    /// The caller must keep the representation normalized.
+    #[inline]
+    pub(crate) fn repr_mut(&mut self) -> &mut Repr {
+        &mut self.0
+    }
+
     /// Convert into representation.
     #[inline]
     pub(crate) fn into_repr(self) -> Repr {
@@ -66,17 +88,45 @@ impl UBig {
     pub(crate) fn len(&self) -> usize {
         match self.repr() {
             Small(_) => 1,
+            Double(_) => 2,
             Large(buffer) => buffer.len(),
         }
     }
 
+    /// Capacity of the storage in machine words.
+    ///
+    /// Numbers that fit in one or two words are stored inline, and report a capacity of 1 or 2.
+    /// Larger numbers report the capacity of their heap buffer, which may exceed their length.
+    ///
+    /// How much capacity is reserved is an implementation detail and may change.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// assert_eq!(ubig!(5).capacity_words(), 1);
+    /// assert!((ubig!(1) << 1000).capacity_words() >= 16);
+    /// ```
+    #[inline]
+    pub fn capacity_words(&self) -> usize {
+        match self.repr() {
+            Small(_) => 1,
+            Double(_) => 2,
+            Large(buffer) => buffer.capacity(),
+        }
+    }
+
     /// Representation in Words.
     #[inline]
-    pub(crate) fn as_words(&self) -> &[Word] {
+    pub(crate) fn as_words(&self) -> Words<'_> {
         match self.repr() {
-            Small(0) => &[],
-            Small(word) => slice::from_ref(word),
-            Large(buffer) => buffer,
+            Small(0) => Words::Borrowed(&[]),
+            Small(word) => Words::Borrowed(slice::from_ref(word)),
+            Double(dw) => {
+                let (lo, hi) = split_double_word(*dw);
+                Words::Double([lo, hi])
+            }
+            Large(buffer) => Words::Borrowed(buffer),
         }
     }
 
@@ -123,6 +173,7 @@ impl Clone for UBig {
     fn clone(&self) -> UBig {
         match self.repr() {
             Small(x) => UBig(Small(*x)),
+            Double(x) => UBig(Double(*x)),
             Large(buffer) => UBig(Large(buffer.clone())),
         }
     }
@@ -146,15 +197,46 @@ impl From<Buffer> for UBig {
     /// there will be no reallocation here.
     fn from(mut buffer: Buffer) -> UBig {
         buffer.pop_leading_zeros();
+        if buffer.len() > 2 {
+            buffer.shrink();
+        }
+        UBig::from_buffer_keep_capacity(buffer)
+    }
+}
+
+impl UBig {
+    /// Like `From<Buffer>`, but a large result keeps the whole capacity of `buffer`.
+    ///
+    /// Used where the number is a destination that gets refilled, so that the spare capacity
+    /// is reused rather than reallocated.
+    pub(crate) fn from_buffer_keep_capacity(mut buffer: Buffer) -> UBig {
+        buffer.pop_leading_zeros();
 
         match buffer.len() {
             0 => UBig::from_word(0),
             1 => UBig::from_word(buffer[0]),
+            2 => UBig(Double(double_word(buffer[0], buffer[1]))),
             _ if buffer.len() > UBig::MAX_LEN => UBig::panic_number_too_large(),
-            _ => {
-                buffer.shrink();
-                UBig(Large(buffer))
-            }
+            _ => UBig(Large(buffer)),
+        }
+    }
+}
+
+/// Words of a [UBig], least significant first.
+#[derive(Clone, Copy)]
+pub(crate) enum Words<'a> {
+    Borrowed(&'a [Word]),
+    Double([Word; 2]),
+}
+
+impl Deref for Words<'_> {
+    type Target = [Word];
+
+    #[inline]
+    fn deref(&self) -> &[Word] {
+        match self {
+            Words::Borrowed(words) => words,
+            Words::Double(words) => words,
         }
     }
 }
@@ -167,6 +249,7 @@ mod tests {
     fn capacity(x: &UBig) -> usize {
         match x.repr() {
             Small(_) => 1,
+            Double(_) => 2,
             Large(large) => large.capacity(),
         }
     }
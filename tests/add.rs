@@ -2,7 +2,7 @@ use core::{
     fmt::Debug,
     ops::{Add, AddAssign, Sub, SubAssign},
 };
-use ibig::{ibig, ubig};
+use ibig::{ibig, ubig, IBig, UBig};
 
 /// Test a + b = c in various ways.
 fn test_add_sub<'a, T>(a: &'a T, b: &'a T, c: &'a T)
@@ -79,6 +79,16 @@ fn test_add_sub_ubig() {
             ubig!(2),
             ubig!(_0x100000000000000000000000000000001),
         ),
+        (
+            ubig!(0x10000000000000000),
+            ubig!(0xffffffffffffffff),
+            ubig!(0x1ffffffffffffffff),
+        ),
+        (
+            ubig!(0xffffffffffffffffffffffffffffffff),
+            ubig!(0xffffffffffffffffffffffffffffffff),
+            ubig!(_0x1fffffffffffffffffffffffffffffffe),
+        ),
         (
             ubig!(0x88888888888888888888888888888888),
             ubig!(0x88888888888888888888888888888888),
@@ -259,3 +269,131 @@ fn test_add_sub_ibig_primitive() {
     x -= &10;
     assert_eq!(x, ibig!(-6));
 }
+
+#[test]
+fn test_checked_add() {
+    assert_eq!(ubig!(2).checked_add(&ubig!(3)), Some(ubig!(5)));
+    assert_eq!(ubig!(0).checked_add(&ubig!(0)), Some(ubig!(0)));
+    let a = ubig!(_0xffffffffffffffffffffffffffffffffffffffff);
+    assert_eq!(a.checked_add(&ubig!(1)), Some(&a + ubig!(1)));
+
+    // Only feasible when the maximum size is small, e.g. with 16-bit words.
+    if UBig::MAX_BIT_LEN <= 1 << 20 {
+        let max = UBig::ones(UBig::MAX_BIT_LEN);
+        assert_eq!(max.checked_add(&ubig!(0)), Some(max.clone()));
+        assert_eq!(max.checked_add(&ubig!(1)), None);
+        let half = ubig!(1) << (UBig::MAX_BIT_LEN - 1);
+        assert_eq!(half.checked_add(&(&half - ubig!(1))), Some(max.clone()));
+        assert_eq!(half.checked_add(&half), None);
+    }
+}
+
+#[test]
+fn test_saturating_sub() {
+    assert_eq!(ubig!(5).saturating_sub(&ubig!(3)), ubig!(2));
+    assert_eq!(ubig!(5).saturating_sub(&ubig!(5)), ubig!(0));
+    assert_eq!(ubig!(3).saturating_sub(&ubig!(5)), ubig!(0));
+    assert_eq!(ubig!(0).saturating_sub(&ubig!(0)), ubig!(0));
+
+    let a = ubig!(_0x10000000000000000000000000000000000000000);
+    let b = ubig!(_0xffffffffffffffffffffffffffffffffffffffff);
+    assert_eq!(a.saturating_sub(&b), ubig!(1));
+    assert_eq!(a.saturating_sub(&a), ubig!(0));
+    assert_eq!(b.saturating_sub(&a), ubig!(0));
+    assert_eq!(ubig!(7).saturating_sub(&a), ubig!(0));
+    assert_eq!(a.saturating_sub(&ubig!(1)), b);
+}
+
+#[test]
+fn test_inc_dec() {
+    let mut values = vec![ubig!(0), ubig!(1), ubig!(0xff)];
+    // Carries and borrows across every word boundary up to 4 words.
+    for bits in [16, 32, 64, 128, 192, 256] {
+        values.push((ubig!(1) << bits) - ubig!(1));
+        values.push(ubig!(1) << bits);
+        values.push((ubig!(1) << bits) + ubig!(1));
+    }
+    for x in values {
+        let mut a = x.clone();
+        a.inc();
+        assert_eq!(a, &x + ubig!(1));
+        a.dec();
+        assert_eq!(a, x);
+        if x != ubig!(0) {
+            a.dec();
+            assert_eq!(a, &x - ubig!(1));
+            a.inc();
+            assert_eq!(a, x);
+        }
+    }
+}
+
+#[test]
+fn test_inc_reuses_buffer() {
+    let mut a = ubig!(1) << 1000;
+    let capacity = a.capacity_words();
+    for _ in 0..1000 {
+        a.inc();
+    }
+    assert_eq!(a, (ubig!(1) << 1000) + ubig!(1000));
+    assert_eq!(a.capacity_words(), capacity);
+}
+
+#[test]
+#[should_panic]
+fn test_dec_zero() {
+    let mut a = ubig!(0);
+    a.dec();
+}
+
+#[test]
+fn test_checked_next_prev() {
+    assert_eq!(ubig!(0).checked_next(&ubig!(0)), None);
+    assert_eq!(ubig!(0).checked_next(&ubig!(1)), None);
+    assert_eq!(ubig!(0).checked_next(&ubig!(2)), Some(ubig!(1)));
+    assert_eq!(ubig!(7).checked_next(&ubig!(3)), None);
+    assert_eq!(ubig!(0).checked_prev(&ubig!(0)), None);
+    assert_eq!(ubig!(1).checked_prev(&ubig!(0)), Some(ubig!(0)));
+    assert_eq!(ubig!(3).checked_prev(&ubig!(7)), None);
+
+    let word = ubig!(1) << 64;
+    assert_eq!(
+        (&word - ubig!(1)).checked_next(&(&word + ubig!(1))),
+        Some(word.clone())
+    );
+    assert_eq!((&word - ubig!(1)).checked_next(&word), None);
+    assert_eq!(word.checked_prev(&ubig!(0)), Some(&word - ubig!(1)));
+
+    // Count through a range crossing a word boundary, both ways.
+    let start = (ubig!(1) << 128) - ubig!(3);
+    let end = (ubig!(1) << 128) + ubig!(3);
+    let mut forward = Vec::new();
+    let mut i = Some(start.clone());
+    while let Some(x) = i {
+        i = x.checked_next(&end);
+        forward.push(x);
+    }
+    assert_eq!(forward.len(), 6);
+    assert_eq!(forward.last(), Some(&(&end - ubig!(1))));
+    let mut backward = Vec::new();
+    let mut i = end.checked_prev(&start);
+    while let Some(x) = i {
+        i = x.checked_prev(&start);
+        backward.push(x);
+    }
+    backward.reverse();
+    assert_eq!(forward, backward);
+
+    assert_eq!(ibig!(-1).checked_next(&ibig!(1)), Some(ibig!(0)));
+    assert_eq!(ibig!(-1).checked_next(&ibig!(0)), None);
+    assert_eq!(ibig!(-5).checked_next(&ibig!(-10)), None);
+    assert_eq!(ibig!(0).checked_prev(&ibig!(-1)), Some(ibig!(-1)));
+    assert_eq!(ibig!(-1).checked_prev(&ibig!(-1)), None);
+    assert_eq!(ibig!(1).checked_prev(&ibig!(5)), None);
+    let word = IBig::from(word);
+    assert_eq!(
+        (-&word).checked_prev(&(-&word - ibig!(1))),
+        Some(-&word - ibig!(1))
+    );
+    assert_eq!((-&word).checked_next(&ibig!(0)), Some(-&word + ibig!(1)));
+}
@@ -1,7 +1,7 @@
 use ibig::{
     ibig,
     ops::{AndNot, NextPowerOfTwo},
-    ubig, IBig,
+    ubig, IBig, UBig,
 };
 
 #[test]
@@ -37,6 +37,14 @@ fn test_set_bit() {
         a,
         ubig!(_0x400000000000000028000000000000000000000000000000a)
     );
+
+    let mut b = ubig!(1);
+    b.set_bit(64);
+    assert_eq!(b, ubig!(_0x10000000000000001));
+    b.set_bit(127);
+    assert_eq!(b, ubig!(_0x80000000000000010000000000000001));
+    b.set_bit(128);
+    assert_eq!(b, ubig!(_0x180000000000000010000000000000001));
 }
 
 #[test]
@@ -57,6 +65,111 @@ fn test_clear_bit() {
     assert_eq!(a, ubig!(0b1000));
     a.clear_bit(3);
     assert_eq!(a, ubig!(0));
+
+    let mut b = ubig!(_0x10000000000000005);
+    b.clear_bit(1000);
+    assert_eq!(b, ubig!(_0x10000000000000005));
+    b.clear_bit(64);
+    assert_eq!(b, ubig!(5));
+    b.clear_bit(1000);
+    assert_eq!(b, ubig!(5));
+}
+
+#[test]
+fn test_any_bit_set() {
+    let values = [
+        ubig!(0),
+        ubig!(1),
+        ubig!(0b10010),
+        ubig!(1) << 15,
+        ubig!(1) << 16,
+        ubig!(1) << 31,
+        ubig!(1) << 32,
+        ubig!(1) << 63,
+        ubig!(1) << 64,
+        (ubig!(1) << 127) + (ubig!(1) << 5),
+        ubig!(1) << 128,
+        (ubig!(1) << 200) | (ubig!(1) << 64),
+        UBig::ones(150) << 40,
+    ];
+    let points = [
+        0, 1, 4, 5, 15, 16, 17, 31, 32, 33, 40, 63, 64, 65, 127, 128, 129, 190, 200, 201, 256, 1000,
+    ];
+    for x in &values {
+        for &start in &points {
+            for &end in &points {
+                let expected = (start..end).any(|i| x.bit(i));
+                assert_eq!(
+                    x.any_bit_set(start..end),
+                    expected,
+                    "{:#x} {}..{}",
+                    x,
+                    start,
+                    end
+                );
+            }
+        }
+        assert_eq!(x.any_bit_set(0..usize::MAX), *x != ubig!(0));
+        assert!(!x.any_bit_set(usize::MAX..usize::MAX));
+    }
+}
+
+#[test]
+fn test_view_bits() {
+    let values = [
+        ubig!(0),
+        ubig!(1),
+        ubig!(0b10010),
+        UBig::ones(64),
+        (ubig!(1) << 127) + (ubig!(1) << 5),
+        ubig!(0xfedcba9876543210) << 100,
+    ];
+    for x in &values {
+        let bits = x.view_bits();
+        assert_eq!(bits.len(), x.bit_len());
+        assert_eq!(bits.is_empty(), *x == ubig!(0));
+        for i in 0..bits.len() + 70 {
+            assert_eq!(bits.get(i), x.bit(i));
+        }
+        assert_eq!(bits.iter().len(), bits.len());
+        assert!(bits.iter().eq((0..x.bit_len()).map(|i| x.bit(i))));
+    }
+}
+
+#[test]
+fn test_chunks() {
+    let values = [
+        ubig!(0),
+        ubig!(1),
+        ubig!(0x12345),
+        UBig::ones(64),
+        (ubig!(1) << 127) + (ubig!(1) << 5),
+        ubig!(0xfedcba9876543210) << 100,
+        UBig::ones(1000) / ubig!(7),
+    ];
+    for x in &values {
+        for bits in [
+            1, 3, 8, 15, 16, 17, 31, 32, 33, 63, 64, 65, 100, 128, 200, 2000,
+        ] {
+            let chunks: Vec<UBig> = x.chunks(bits).collect();
+            assert_eq!(chunks.len(), (x.bit_len() + bits - 1) / bits);
+            assert!(chunks.iter().all(|c| c.bit_len() <= bits));
+            if let Some(last) = chunks.last() {
+                assert_ne!(*last, ubig!(0));
+            }
+            let joined = chunks
+                .iter()
+                .enumerate()
+                .fold(ubig!(0), |acc, (i, c)| acc | (c << (i * bits)));
+            assert_eq!(joined, *x, "{:#x} {}", x, bits);
+        }
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_chunks_0() {
+    let _ = ubig!(5).chunks(0);
 }
 
 #[test]
@@ -74,6 +187,30 @@ fn test_trailing_zeros() {
     assert_eq!(ibig!(-0xf0000).trailing_zeros(), Some(16));
 }
 
+#[test]
+fn test_trailing_zeros_nonzero() {
+    assert_eq!(ubig!(1).trailing_zeros_nonzero(), 0);
+    assert_eq!(ubig!(0xf0000).trailing_zeros_nonzero(), 16);
+    assert_eq!((ubig!(1) << 100).trailing_zeros_nonzero(), 100);
+    assert_eq!((ubig!(3) << 200).trailing_zeros_nonzero(), 200);
+}
+
+#[test]
+#[cfg_attr(debug_assertions, should_panic)]
+fn test_trailing_zeros_nonzero_0() {
+    assert_eq!(ubig!(0).trailing_zeros_nonzero(), 0);
+}
+
+#[test]
+fn test_remove_factor_two() {
+    assert_eq!(ubig!(0).remove_factor_two(), (ubig!(0), 0));
+    assert_eq!(ubig!(1).remove_factor_two(), (ubig!(1), 0));
+    assert_eq!(ubig!(0xf0000).remove_factor_two(), (ubig!(0xf), 16));
+    let odd = ubig!(_0xfffffffffffffffffffff00000000000000000000000000000000000000000000003);
+    assert_eq!((&odd << 300).remove_factor_two(), (odd.clone(), 300));
+    assert_eq!((ubig!(1) << 500).remove_factor_two(), (ubig!(1), 500));
+}
+
 #[test]
 fn test_bit_len() {
     assert_eq!(ubig!(0).bit_len(), 0);
@@ -84,6 +221,54 @@ fn test_bit_len() {
     );
 }
 
+#[test]
+fn test_floor_ceil_log2() {
+    assert_eq!(ubig!(0).floor_log2(), None);
+    assert_eq!(ubig!(0).ceil_log2(), None);
+    assert_eq!(ubig!(1).floor_log2(), Some(0));
+    assert_eq!(ubig!(1).ceil_log2(), Some(0));
+    assert_eq!(ubig!(2).ceil_log2(), Some(1));
+    assert_eq!(ubig!(3).floor_log2(), Some(1));
+    assert_eq!(ubig!(3).ceil_log2(), Some(2));
+    for k in [5, 31, 32, 63, 64, 65, 127, 128, 129, 1000] {
+        let p = ubig!(1) << k;
+        assert_eq!(p.floor_log2(), Some(k));
+        assert_eq!(p.ceil_log2(), Some(k));
+        assert_eq!((&p - ubig!(1)).floor_log2(), Some(k - 1));
+        assert_eq!((&p - ubig!(1)).ceil_log2(), Some(k));
+        assert_eq!((&p + ubig!(1)).floor_log2(), Some(k));
+        assert_eq!((&p + ubig!(1)).ceil_log2(), Some(k + 1));
+    }
+}
+
+#[test]
+fn test_ibig_bit_len_signed_bits() {
+    for v in i16::MIN..=i16::MAX {
+        let x = IBig::from(v);
+        assert_eq!(
+            x.bit_len(),
+            (16 - v.unsigned_abs().leading_zeros()) as usize
+        );
+        assert_eq!(
+            x.signed_bits(),
+            (17 - (v ^ (v >> 15)).leading_zeros()) as usize
+        );
+    }
+    for v in [i8::MIN, -1, 0, 1, i8::MAX] {
+        let bits = IBig::from(v).signed_bits();
+        assert!(bits <= 8);
+        assert_eq!(IBig::from(i16::from(v)).signed_bits(), bits);
+    }
+
+    let p = ibig!(1) << 200;
+    assert_eq!(p.bit_len(), 201);
+    assert_eq!(p.signed_bits(), 202);
+    assert_eq!((-&p).bit_len(), 201);
+    assert_eq!((-&p).signed_bits(), 201);
+    assert_eq!((-&p - ibig!(1)).signed_bits(), 202);
+    assert_eq!((&p - ibig!(1)).signed_bits(), 201);
+}
+
 #[test]
 #[allow(clippy::bool_assert_comparison)]
 fn test_is_power_of_two() {
@@ -105,6 +290,65 @@ fn test_is_power_of_two() {
     );
 }
 
+#[test]
+fn test_reverse_bits() {
+    fn naive(x: &UBig, width: usize) -> UBig {
+        let mut result = ubig!(0);
+        for i in 0..width {
+            if x.bit(i) {
+                result.set_bit(width - 1 - i);
+            }
+        }
+        result
+    }
+
+    let numbers = [
+        ubig!(0),
+        ubig!(1),
+        ubig!(0b1101),
+        ubig!(0xffffffffffffffff),
+        ubig!(_0x8000000000000001),
+        ubig!(_0x123456789abcdef0fedcba9876543210),
+        ubig!(_0x5555555555555555555555555555555555555555555555555),
+        (ubig!(1) << 200) - ubig!(3),
+    ];
+    for x in &numbers {
+        for width in [
+            0, 1, 3, 31, 32, 33, 63, 64, 65, 100, 127, 128, 129, 190, 201, 257, 300,
+        ] {
+            assert_eq!(x.reverse_bits(width), naive(x, width), "{:x} {}", x, width);
+        }
+    }
+}
+
+#[test]
+fn test_ones() {
+    for n in (0..300).chain([1000, 1023, 1024, 1025]) {
+        let x = UBig::ones(n);
+        assert_eq!(x, (ubig!(1) << n) - ubig!(1));
+        assert_eq!(x.bit_len(), n);
+        assert!((&x + ubig!(1)).is_power_of_two());
+    }
+}
+
+#[test]
+fn test_not_in_width() {
+    let numbers = [
+        ubig!(0),
+        ubig!(1),
+        ubig!(0b1101),
+        ubig!(0xffffffffffffffff),
+        ubig!(_0x123456789abcdef0fedcba9876543210),
+        (ubig!(1) << 200) - ubig!(3),
+    ];
+    for x in &numbers {
+        for width in [0, 1, 3, 32, 33, 64, 65, 127, 128, 129, 200, 201, 300] {
+            let mask = (ubig!(1) << width) - ubig!(1);
+            assert_eq!(x.not_in_width(width), (x & &mask) ^ &mask);
+        }
+    }
+}
+
 #[test]
 fn test_next_power_of_two() {
     assert_eq!(ubig!(0).next_power_of_two(), ubig!(1));
@@ -1,5 +1,6 @@
 use core::cmp::Ordering;
-use ibig::{ibig, ubig};
+use ibig::{ibig, ubig, IBig};
+use std::collections::{BTreeSet, HashSet};
 
 #[test]
 fn test_cmp() {
@@ -31,3 +32,138 @@ fn test_cmp() {
     assert!(ibig!(-10) < ibig!(-5));
     assert!(ibig!(-5) > ibig!(-10));
 }
+
+#[test]
+fn test_cmp_across_lengths() {
+    let mut numbers = vec![ubig!(0), ubig!(1)];
+    for bits in [8, 16, 31, 32, 33, 63, 64, 65, 127, 128, 129, 200, 1000] {
+        let p = ubig!(1) << bits;
+        numbers.push(&p - ubig!(1));
+        numbers.push(p.clone());
+        numbers.push(p + ubig!(1));
+    }
+    numbers.sort();
+    numbers.dedup();
+    for pair in numbers.windows(2) {
+        assert!(pair[0] < pair[1]);
+        assert!(pair[0].bit_len() <= pair[1].bit_len());
+        assert_eq!(pair[0].cmp(&pair[1]), Ordering::Less);
+        assert_eq!(pair[1].cmp(&pair[0]), Ordering::Greater);
+    }
+
+    let mut set = BTreeSet::new();
+    for x in numbers.iter().rev() {
+        set.insert(x.clone());
+    }
+    assert!(set.iter().eq(numbers.iter()));
+}
+
+#[test]
+fn test_eq_primitive() {
+    assert!(ubig!(0) == 0u8);
+    assert!(ubig!(5) == 5u64);
+    assert!(ubig!(5) != 6u64);
+    assert!(ubig!(255) == 255u8);
+    assert!(ubig!(256) != 0u8);
+    assert!(ubig!(0xffffffffffffffff) == u64::MAX);
+    assert!(ubig!(0x10000000000000000) != 0u64);
+    assert!(ubig!(0x10000000000000000) == 1u128 << 64);
+    assert!(ubig!(_0xffffffffffffffffffffffffffffffff) == u128::MAX);
+    assert!(ubig!(_0x100000000000000000000000000000000) != 0u128);
+    assert!(ubig!(_0x100000000000000000000000000000000) != u128::MAX);
+    assert!(ubig!(1000) == 1000usize);
+    assert!(ubig!(1000) == 1000u16);
+    assert!(ubig!(1000) == 1000u32);
+}
+
+#[test]
+fn test_is_zero_is_one() {
+    assert!(ubig!(0).is_zero());
+    assert!(!ubig!(1).is_zero());
+    assert!(!ubig!(0x10000000000000000).is_zero());
+    assert!(!ubig!(_0x100000000000000000000000000000000).is_zero());
+    assert!(ubig!(1).is_one());
+    assert!(!ubig!(0).is_one());
+    assert!(!ubig!(_0x100000000000000000000000000000001).is_one());
+    assert!((ubig!(_0x100000000000000000000000000000001) >> 128).is_one());
+    assert!((ubig!(0x10000000000000000) - ubig!(0x10000000000000000)).is_zero());
+
+    assert!(ibig!(0).is_zero());
+    assert!((ibig!(5) - ibig!(5)).is_zero());
+    assert!(!ibig!(-1).is_zero());
+    assert!(ibig!(1).is_one());
+    assert!(!ibig!(-1).is_one());
+    assert!(!ibig!(0).is_one());
+}
+
+#[test]
+fn test_ibig_zero_sign_normalized() {
+    let big = ibig!(_0x123456789abcdef0123456789abcdef);
+    let zeros = [
+        ibig!(0),
+        ibig!(5) - ibig!(5),
+        ibig!(-5) + ibig!(5),
+        -ibig!(0),
+        &big - &big,
+        -(&big - &big),
+        -&big + &big,
+        ibig!(-5) * ibig!(0),
+        ibig!(-3) / ibig!(7),
+        ibig!(-14) % ibig!(7),
+        (ibig!(-1) >> 1000) + ibig!(1),
+        IBig::from(ubig!(0)),
+    ];
+    for zero in &zeros {
+        assert_eq!(*zero, ibig!(0));
+        assert_eq!(zero.cmp(&ibig!(0)), Ordering::Equal);
+        assert_eq!(zero.signum(), ibig!(0));
+        assert!(zero.is_zero());
+    }
+    let set: HashSet<IBig> = zeros.iter().cloned().collect();
+    assert_eq!(set.len(), 1);
+
+    let mut values: Vec<IBig> = zeros.to_vec();
+    values.extend([
+        ibig!(-1),
+        -&big,
+        ibig!(1),
+        big.clone(),
+        ibig!(-0x10000000000000000),
+    ]);
+    values.sort();
+    assert_eq!(values[0], -&big);
+    assert_eq!(values[1], ibig!(-0x10000000000000000));
+    assert_eq!(values[2], ibig!(-1));
+    assert!(values[3..3 + zeros.len()].iter().all(|x| *x == ibig!(0)));
+    assert_eq!(values[3 + zeros.len()], ibig!(1));
+    assert_eq!(values[4 + zeros.len()], big);
+}
+
+#[test]
+fn test_cmp_magnitude() {
+    let big = ibig!(_0x123456789abcdef0123456789abcdef);
+    let values = [
+        ibig!(0),
+        ibig!(1),
+        ibig!(-1),
+        ibig!(5),
+        ibig!(-5),
+        ibig!(_0x10000000000000000),
+        ibig!(-_0x10000000000000000),
+        big.clone(),
+        -&big,
+        &big + ibig!(1),
+        -&big - ibig!(1),
+    ];
+    for a in &values {
+        for b in &values {
+            assert_eq!(a.cmp_magnitude(b), a.magnitude().cmp(b.magnitude()));
+            assert_eq!(a.cmp_magnitude(b), (-a).cmp_magnitude(b));
+            assert_eq!(a.cmp_magnitude(b), a.cmp_magnitude(&-b));
+            assert_eq!(a.cmp_magnitude(b), b.cmp_magnitude(a).reverse());
+        }
+    }
+    assert_eq!(ibig!(-7).cmp_magnitude(&ibig!(3)), Ordering::Greater);
+    assert_eq!(ibig!(3).cmp_magnitude(&ibig!(-7)), Ordering::Less);
+    assert_eq!(ibig!(-7).cmp_magnitude(&ibig!(7)), Ordering::Equal);
+}
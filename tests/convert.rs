@@ -1,4 +1,4 @@
-use ibig::{error::OutOfBoundsError, ibig, ubig, IBig, UBig};
+use ibig::{error::OutOfBoundsError, ibig, ubig, IBig, LeBytes, Sign, UBig};
 use std::convert::TryFrom;
 
 #[test]
@@ -23,6 +23,116 @@ fn test_from_to_be_bytes() {
     assert_eq!(UBig::from_be_bytes(&bytes).to_be_bytes(), bytes);
 }
 
+#[test]
+fn test_le_bytes_iter() {
+    let numbers = [
+        ubig!(0),
+        ubig!(1),
+        ubig!(0xff),
+        ubig!(0x100),
+        ubig!(0x0102030405060708),
+        ubig!(0x0102030405060708090a0b0c0d0e0f),
+        (ubig!(1) << 1000) - ubig!(1),
+        ubig!(0x1234) << 500,
+    ];
+    for x in &numbers {
+        let iter = x.le_bytes_iter();
+        assert_eq!(iter.len(), x.to_le_bytes().len());
+        assert!(iter.eq(x.to_le_bytes().into_iter()));
+    }
+}
+
+#[test]
+fn test_to_bytes_padded() {
+    assert_eq!(ubig!(0).to_le_bytes_padded(0), Ok(vec![]));
+    assert_eq!(ubig!(0).to_be_bytes_padded(3), Ok(vec![0, 0, 0]));
+    assert_eq!(ubig!(1).to_le_bytes_padded(0), Err(OutOfBoundsError));
+    assert_eq!(ubig!(0x0102).to_le_bytes_padded(2), Ok(vec![2, 1]));
+    assert_eq!(ubig!(0x0102).to_be_bytes_padded(2), Ok(vec![1, 2]));
+    assert_eq!(ubig!(0x0102).to_be_bytes_padded(1), Err(OutOfBoundsError));
+
+    let x = UBig::from_be_bytes(&[0xff; 31]);
+    let padded = x.to_be_bytes_padded(32).unwrap();
+    assert_eq!(padded.len(), 32);
+    assert_eq!(padded[0], 0);
+    assert_eq!(UBig::from_be_bytes(&padded), x);
+    let padded = x.to_le_bytes_padded(32).unwrap();
+    assert_eq!(padded[31], 0);
+    assert_eq!(UBig::from_le_bytes(&padded), x);
+    assert_eq!(x.to_be_bytes_padded(30), Err(OutOfBoundsError));
+    assert_eq!(x.to_le_bytes_padded(30), Err(OutOfBoundsError));
+}
+
+#[test]
+fn test_ibig_from_sign_magnitude_bytes() {
+    assert_eq!(
+        IBig::from_sign_magnitude_bytes(Sign::Positive, &[1, 2, 3]),
+        ibig!(0x010203)
+    );
+    assert_eq!(
+        IBig::from_sign_magnitude_bytes(Sign::Negative, &[0, 1, 2, 3]),
+        ibig!(-0x010203)
+    );
+    assert_eq!(
+        IBig::from((Sign::Negative, &[0xff; 20][..])),
+        -IBig::from(UBig::from_be_bytes(&[0xff; 20]))
+    );
+
+    for bytes in [&[][..], &[0], &[0; 30]] {
+        let x = IBig::from_sign_magnitude_bytes(Sign::Negative, bytes);
+        assert_eq!(x, ibig!(0));
+        assert_eq!(x.sign(), Sign::Positive);
+    }
+}
+
+#[test]
+fn test_capacity_words() {
+    assert_eq!(ubig!(0).capacity_words(), 1);
+    assert_eq!(ubig!(0xff).capacity_words(), 1);
+    assert!(UBig::from(u128::MAX).capacity_words() >= 2);
+
+    let bytes = [0xff; 200];
+    let x = UBig::from_le_bytes(&bytes);
+    // Words have at most 64 bits.
+    assert!(x.capacity_words() * 64 >= x.bit_len());
+    // Shifting right reuses the buffer, possibly shrinking it.
+    let capacity = x.capacity_words();
+    let y = x >> 8;
+    assert!(y.capacity_words() <= capacity);
+}
+
+#[test]
+fn test_from_to_le_u64_chunks() {
+    let empty: [u64; 0] = [];
+    assert_eq!(UBig::from_le_u64_chunks(&[]).to_le_u64_chunks(), empty);
+    assert_eq!(UBig::from_le_u64_chunks(&[0; 10]).to_le_u64_chunks(), empty);
+    assert_eq!(UBig::from_le_u64_chunks(&[5, 0, 0]).to_le_u64_chunks(), [5]);
+    assert_eq!(
+        UBig::from_le_u64_chunks(&[0x0123456789abcdef]),
+        ubig!(0x0123456789abcdef)
+    );
+    // The result doesn't depend on the word size.
+    let x = UBig::from_le_u64_chunks(&[0x0123456789abcdef, 0, 0xfedcba9876543210, 1]);
+    assert_eq!(
+        x,
+        UBig::from_str_radix("1fedcba987654321000000000000000000123456789abcdef", 16).unwrap()
+    );
+    assert_eq!(
+        x.to_le_u64_chunks(),
+        [0x0123456789abcdef, 0, 0xfedcba9876543210, 1]
+    );
+    assert_eq!(
+        x.to_le_bytes(),
+        [
+            0xef, 0xcd, 0xab, 0x89, 0x67, 0x45, 0x23, 0x01, 0, 0, 0, 0, 0, 0, 0, 0, 0x10, 0x32,
+            0x54, 0x76, 0x98, 0xba, 0xdc, 0xfe, 1
+        ]
+    );
+    let y = (ubig!(1) << 1000) - ubig!(1);
+    assert_eq!(y.to_le_u64_chunks().len(), 16);
+    assert_eq!(UBig::from_le_u64_chunks(&y.to_le_u64_chunks()), y);
+}
+
 #[test]
 fn test_ubig_from_unsigned() {
     assert_eq!(UBig::from(0xf1u8), UBig::from_be_bytes(&[0xf1]));
@@ -331,3 +441,37 @@ fn test_to_f64() {
     assert!((ibig!(-0x1fffffffffffff7) << 967).to_f64() > -f64::INFINITY);
     assert!((ibig!(-0x1fffffffffffff8) << 967).to_f64() == -f64::INFINITY);
 }
+
+#[test]
+fn test_from_to_byte_arrays() {
+    assert_eq!(UBig::from([]), ubig!(0));
+    assert_eq!(UBig::from([0, 0, 1, 2, 3]), ubig!(0x010203));
+    assert_eq!(UBig::from(LeBytes([3, 2, 1, 0, 0])), ubig!(0x010203));
+
+    assert_eq!(<[u8; 0]>::try_from(ubig!(0)), Ok([]));
+    assert_eq!(<[u8; 5]>::try_from(ubig!(0x010203)), Ok([0, 0, 1, 2, 3]));
+    assert_eq!(<[u8; 3]>::try_from(&ubig!(0x010203)), Ok([1, 2, 3]));
+    assert_eq!(<[u8; 2]>::try_from(ubig!(0x010203)), Err(OutOfBoundsError));
+    assert_eq!(
+        LeBytes::<5>::try_from(ubig!(0x010203)),
+        Ok(LeBytes([3, 2, 1, 0, 0]))
+    );
+    assert_eq!(
+        LeBytes::<2>::try_from(&ubig!(0x010203)),
+        Err(OutOfBoundsError)
+    );
+
+    let mut bytes = [0u8; 32];
+    for (i, b) in bytes.iter_mut().enumerate() {
+        *b = i as u8 + 1;
+    }
+    let x = UBig::from(bytes);
+    assert_eq!(x, UBig::from_be_bytes(&bytes));
+    assert_eq!(<[u8; 32]>::try_from(&x), Ok(bytes));
+    assert_eq!(<[u8; 31]>::try_from(&x), Err(OutOfBoundsError));
+    let mut padded = [0u8; 40];
+    padded[8..].copy_from_slice(&bytes);
+    assert_eq!(<[u8; 40]>::try_from(&x), Ok(padded));
+    let LeBytes(le) = LeBytes::<32>::try_from(&x).unwrap();
+    assert_eq!(le.to_vec(), x.to_le_bytes());
+}
@@ -1,7 +1,7 @@
 use ibig::{
     ibig,
     ops::{DivEuclid, DivRem, DivRemEuclid, RemEuclid},
-    ubig, IBig,
+    ubig, IBig, PreparedDivisor, UBig,
 };
 
 #[test]
@@ -141,6 +141,220 @@ fn test_div_rem_ubig() {
     }
 }
 
+#[test]
+fn test_rem_assign_ubig_accumulator() {
+    let moduli = [
+        ubig!(_0x123456789abcdef0123456789abcdef0123456789),
+        ubig!(_0xf23456789abcdef0123456789abcdef0123456789),
+        (ubig!(1) << 300) - ubig!(1),
+        ubig!(1) << 200,
+    ];
+    for m in &moduli {
+        let mut acc = ubig!(0);
+        let mut expected = ubig!(0);
+        for digit in 0..50u64 {
+            acc = acc * ubig!(0x1234567890abcdef) + digit;
+            expected = (expected * ubig!(0x1234567890abcdef) + digit) % m.clone();
+            acc %= m;
+            assert_eq!(acc, expected);
+        }
+    }
+}
+
+#[test]
+fn test_div_rem_into() {
+    let values = [
+        ubig!(0),
+        ubig!(7),
+        ubig!(0x10000000000000001),
+        ubig!(_0x123456789abcdef0123456789abcdef0123456789),
+        ubig!(_0xf23456789abcdef0123456789abcdef0123456789abcdef0123456789),
+        (ubig!(1) << 300) - ubig!(1),
+        ubig!(1) << 200,
+    ];
+    // Reuse the same outputs throughout, so that they start out with different sizes.
+    let mut q = ubig!(0);
+    let mut r = ubig!(0);
+    for a in &values {
+        for b in &values[1..] {
+            a.div_rem_into(b, &mut q, &mut r);
+            assert_eq!((q.clone(), r.clone()), a.div_rem(b));
+        }
+    }
+}
+
+#[test]
+fn test_div_rem_into_reuses_memory() {
+    // Start with more capacity than any of the results need.
+    let mut q = ubig!(1) << 5000;
+    let mut r = ubig!(1) << 5000;
+    let capacities = (q.capacity_words(), r.capacity_words());
+    for (a, b) in [
+        (ubig!(3).pow(1000), ubig!(7).pow(100)),
+        (ubig!(3).pow(1000), ubig!(7).pow(300)),
+        (ubig!(7).pow(300), ubig!(3).pow(300)),
+    ] {
+        for _ in 0..3 {
+            a.div_rem_into(&b, &mut q, &mut r);
+            assert_eq!((&q, &r), (&(&a / &b), &(&a % &b)));
+            assert_eq!((q.capacity_words(), r.capacity_words()), capacities);
+        }
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_div_rem_into_0() {
+    let mut q = ubig!(0);
+    let mut r = ubig!(0);
+    ubig!(5).div_rem_into(&ubig!(0), &mut q, &mut r);
+}
+
+#[test]
+fn test_div_rem_ubig_power_of_two() {
+    let values = [
+        ubig!(_0x123456789abcdef0123456789abcdef0123456789),
+        (ubig!(1) << 300) - ubig!(1),
+        ubig!(1) << 200,
+        ubig!(1) << 1000,
+    ];
+    for a in &values {
+        for k in [128, 129, 191, 192, 500, 1000, 1001] {
+            let p = ubig!(1) << k;
+            let q = a >> k;
+            let r = a & (&p - ubig!(1));
+            assert_eq!(a / &p, q);
+            assert_eq!(a.clone() / p.clone(), q);
+            assert_eq!(a % &p, r);
+            assert_eq!(a.clone() % p.clone(), r);
+            assert_eq!(a.div_rem(&p), (q.clone(), r.clone()));
+            assert_eq!(a.clone().div_rem(p.clone()), (q.clone(), r.clone()));
+            // Compare with the general algorithm.
+            let p_plus_1 = &p + ubig!(1);
+            assert_eq!(&q * &p_plus_1 + &r - &q, a.clone());
+        }
+    }
+}
+
+#[test]
+#[allow(clippy::needless_borrow)]
+fn test_div_rem_ubig_double_word_boundary() {
+    let values = [
+        ubig!(1),
+        ubig!(2),
+        ubig!(3),
+        ubig!(0xffffffff),
+        ubig!(0xffffffffffffffff),
+        ubig!(0x10000000000000000),
+        ubig!(0x10000000000000001),
+        ubig!(0x80000000000000000000000000000000),
+        ubig!(0xfffffffffffffffffffffffffffffffe),
+        ubig!(0xffffffffffffffffffffffffffffffff),
+        ubig!(_0x100000000000000000000000000000000),
+        ubig!(_0x100000000000000000000000000000001),
+    ];
+
+    for b in &values {
+        for q in &values {
+            for r in [ubig!(0), b / ubig!(2), b - ubig!(1)] {
+                let a = q * b + &r;
+                assert_eq!(&a / b, *q);
+                assert_eq!(a.clone() / b.clone(), *q);
+                assert_eq!(&a % b, r);
+                assert_eq!(a.clone() % b.clone(), r);
+                assert_eq!((&a).div_rem(b), (q.clone(), r.clone()));
+                assert_eq!(a.div_rem(b.clone()), (q.clone(), r));
+            }
+        }
+    }
+}
+
+#[test]
+fn test_div_rem_ubig_by_double_word() {
+    // Divisors of two words for every word size.
+    let divisors = [
+        ubig!(0x10001),
+        ubig!(0x80000005),
+        ubig!(0xffffffff),
+        ubig!(0x100000001),
+        ubig!(0x8000000000000001),
+        ubig!(0x10000000000000000),
+        ubig!(0x1000000000000ffff),
+        ubig!(0x123456789abcdef0123456789),
+        ubig!(0x80000000000000000000000000000000),
+        ubig!(0xffffffffffffffffffffffffffffffff),
+    ];
+    let quotients = [
+        ubig!(1),
+        ubig!(0xffffffffffffffff),
+        ubig!(0x10000000000000000),
+        UBig::ones(300),
+        ubig!(3).pow(200),
+        ubig!(1) << 500,
+    ];
+    for b in &divisors {
+        for q in &quotients {
+            for r in [ubig!(0), ubig!(1), b / ubig!(3), b - ubig!(1)] {
+                let a = q * b + &r;
+                assert_eq!(&a / b, *q);
+                assert_eq!(&a % b, r);
+                assert_eq!(DivRem::div_rem(&a, b), (q.clone(), r.clone()));
+                assert_eq!(a.div_rem(b.clone()), (q.clone(), r));
+            }
+        }
+    }
+
+    let a = ubig!(7).pow(100);
+    let d = 0x123456789abcdef0123456789u128;
+    assert_eq!(&a / d * d + &a % d, a);
+    assert_eq!(DivRem::div_rem(&a, d), (&a / d, &a % d));
+}
+
+#[test]
+fn test_rem_word() {
+    let values = [
+        ubig!(0),
+        ubig!(1),
+        ubig!(0xffffffffffffffff),
+        ubig!(0xffffffffffffffffffffffffffffffff),
+        ubig!(_0x987987123984798abbcc213789723948792138479837492837498cc),
+        ubig!(1) << 1000,
+    ];
+    let moduli = [
+        1,
+        2,
+        3,
+        10,
+        0x1000,
+        0xffffffff,
+        0x100000000,
+        0xf234567812345678,
+        u64::MAX,
+    ];
+    for a in &values {
+        let rems = a.rem_words(&moduli);
+        assert_eq!(rems.len(), moduli.len());
+        for (m, r) in moduli.iter().zip(&rems) {
+            let expected = a % m;
+            assert_eq!(a.rem_word(*m), expected);
+            assert_eq!(*r, expected);
+        }
+    }
+    assert_eq!(ubig!(5).rem_words(&[]), []);
+}
+
+#[test]
+#[should_panic]
+fn test_rem_word_0() {
+    let _ = ubig!(5).rem_word(0);
+}
+
+#[test]
+#[should_panic]
+fn test_rem_words_0() {
+    let _ = ubig!(5).rem_words(&[3, 0]);
+}
+
 #[test]
 #[should_panic]
 fn test_divide_by_0_ubig() {
@@ -148,6 +362,7 @@ fn test_divide_by_0_ubig() {
 }
 
 #[test]
+#[allow(clippy::needless_borrow)]
 fn test_div_rem_ibig() {
     for a in -20i8..=20i8 {
         for b in -20i8..=20i8 {
@@ -227,6 +442,125 @@ fn test_div_rem_euclid_ibig() {
     }
 }
 
+#[test]
+fn test_div_rem_inherent() {
+    let a = ubig!(_0x123456789abcdef0123456789abcdef0123456789);
+    let b = ubig!(_0x1000000000000000f);
+    let qr = (&a / &b, &a % &b);
+    assert_eq!(UBig::div_rem(&a, &b), qr);
+    assert_eq!(UBig::div_rem(&a, b.clone()), qr);
+    assert_eq!(UBig::div_rem(&ubig!(23), 10u8), (ubig!(2), 3u8));
+
+    let a = -IBig::from(a);
+    let b = IBig::from(b);
+    let qr = (&a / &b, &a % &b);
+    assert_eq!(IBig::div_rem(&a, &b), qr);
+    assert_eq!(IBig::div_rem(&a, b.clone()), qr);
+    assert_eq!(IBig::div_rem(&ibig!(-23), 10i8), (ibig!(-2), -3i8));
+    assert_eq!(IBig::div_rem(&ibig!(-23), 10u8), (ibig!(-2), ibig!(-3)));
+}
+
+#[test]
+fn test_prepared_divisor() {
+    let numbers = [
+        ubig!(0),
+        ubig!(1),
+        ubig!(9),
+        ubig!(0xffff),
+        ubig!(0xffffffff),
+        ubig!(0xffffffffffffffff),
+        ubig!(_0x123456789abcdef0123456789abcdef),
+        ubig!(_0xfedcba9876543210fedcba9876543210fedcba9876543210),
+        ubig!(1) << 1000,
+    ];
+    let divisors = [
+        1,
+        2,
+        3,
+        10,
+        0x8000,
+        0xffff,
+        0x10001,
+        0xffffffff,
+        0x100000001,
+        1 << 63,
+        u64::MAX,
+    ];
+    for d in divisors {
+        let prepared = PreparedDivisor::new(d);
+        assert_eq!(prepared.divisor(), d);
+        for x in &numbers {
+            let q = x / d;
+            let r = x % d;
+            assert_eq!(x.div_rem(&prepared), (q.clone(), r));
+            assert_eq!(DivRem::div_rem(x.clone(), &prepared), (q, r));
+            assert_eq!(x % &prepared, r);
+            assert_eq!(x.clone() % &prepared, r);
+        }
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_prepared_divisor_0() {
+    let _ = PreparedDivisor::new(0);
+}
+
+#[test]
+fn test_is_multiple_of() {
+    let values = [
+        ubig!(0),
+        ubig!(1),
+        ubig!(2),
+        ubig!(3),
+        ubig!(12),
+        ubig!(0x10000),
+        ubig!(0xffffffff),
+        ubig!(1) << 64,
+        ubig!(3) << 64,
+        ubig!(_0xfedcba9876543210fedcba9876543210),
+        ubig!(1) << 200,
+        (ubig!(1) << 200) * ubig!(0xffffffff),
+        ubig!(_0xfedcba9876543210fedcba9876543210) * ubig!(_0x123456789abcdef0123456789),
+    ];
+    for x in &values {
+        for d in &values {
+            let expected = if *d == ubig!(0) {
+                *x == ubig!(0)
+            } else {
+                x % d == ubig!(0)
+            };
+            assert_eq!(x.is_multiple_of(d), expected, "{} {}", x, d);
+        }
+    }
+}
+
+#[test]
+fn test_rem_euclid_ubig() {
+    for a in -20i8..=20i8 {
+        for b in 1u8..=20u8 {
+            let r = a.rem_euclid(b as i8) as u8;
+            assert_eq!(IBig::from(a).rem_euclid_ubig(&UBig::from(b)), UBig::from(r));
+        }
+    }
+
+    let m = ubig!(_0x1000000000000000000000001);
+    assert_eq!(ibig!(-1).rem_euclid_ubig(&m), &m - ubig!(1));
+    assert_eq!(IBig::from(m.clone()).rem_euclid_ubig(&m), ubig!(0));
+    assert_eq!((-IBig::from(m.clone())).rem_euclid_ubig(&m), ubig!(0));
+    assert_eq!(
+        (-IBig::from(&m * ubig!(5) + ubig!(3))).rem_euclid_ubig(&m),
+        &m - ubig!(3)
+    );
+    assert_eq!(ibig!(-3).rem_euclid_ubig(&m), &m - ubig!(3));
+}
+
+#[test]
+#[should_panic]
+fn test_rem_euclid_ubig_0() {
+    let _ = ibig!(-5).rem_euclid_ubig(&ubig!(0));
+}
+
 #[test]
 #[should_panic]
 fn test_divide_by_0_ibig() {
@@ -234,7 +568,7 @@ fn test_divide_by_0_ibig() {
 }
 
 #[test]
-#[allow(clippy::op_ref)]
+#[allow(clippy::op_ref, clippy::needless_borrow)]
 fn test_div_rem_ubig_unsigned() {
     assert_eq!(ubig!(23) / 10u8, ubig!(2));
     assert_eq!(ubig!(23) / &10u8, ubig!(2));
@@ -283,7 +617,7 @@ fn test_div_rem_euclid_ubig_unsigned() {
 }
 
 #[test]
-#[allow(clippy::op_ref)]
+#[allow(clippy::op_ref, clippy::needless_borrow)]
 fn test_div_rem_ubig_signed() {
     assert_eq!(ubig!(23) / 10, ubig!(2));
     assert_eq!(ubig!(23) / &10, ubig!(2));
@@ -364,7 +698,7 @@ fn test_div_rem_euclid_ubig_signed_overflow() {
 }
 
 #[test]
-#[allow(clippy::op_ref)]
+#[allow(clippy::op_ref, clippy::needless_borrow)]
 fn test_div_rem_ibig_unsigned() {
     assert_eq!(ibig!(-23) / 10u8, ibig!(-2));
     assert_eq!(ibig!(-23) / &10u8, ibig!(-2));
@@ -413,7 +747,7 @@ fn test_div_rem_euclid_ibig_unsigned() {
 }
 
 #[test]
-#[allow(clippy::op_ref)]
+#[allow(clippy::op_ref, clippy::needless_borrow)]
 fn test_div_rem_ibig_signed() {
     assert_eq!(ibig!(-23) / (-10), ibig!(2));
     assert_eq!(ibig!(-23) / &(-10), ibig!(2));
@@ -1,8 +1,4 @@
-use ibig::{
-    ibig,
-    ops::{Abs, UnsignedAbs},
-    ubig, IBig, UBig,
-};
+use ibig::{ibig, ops::Abs, ubig, IBig, UBig};
 
 #[test]
 fn test_gcd_ubig() {
@@ -109,6 +105,146 @@ fn test_extended_gcd_ubig_0_0() {
     let _ = ubig!(0).extended_gcd(&ubig!(0));
 }
 
+#[test]
+fn test_extended_gcd_into() {
+    let big = ubig!(_0x5a4653ca673768565b41f775d6947d55cf3813d1);
+    let mut g = ubig!(1) << 1000;
+    let mut x = -IBig::from(ubig!(1) << 1000);
+    let mut y = IBig::from(ubig!(1) << 1000);
+    let test_cases = [
+        (ubig!(0), ubig!(123)),
+        (ubig!(123), ubig!(0)),
+        (ubig!(12), ubig!(18)),
+        (ubig!(18), ubig!(12)),
+        (big.clone(), ubig!(1) << 160),
+        (ubig!(1) << 160, big.clone()),
+        (&big * ubig!(6), &big * ubig!(10)),
+        (ubig!(17), &big << 100),
+    ];
+    for (a, b) in &test_cases {
+        a.extended_gcd_into(b, &mut g, &mut x, &mut y);
+        let (expected_g, expected_x, expected_y) = a.extended_gcd(b);
+        assert_eq!((&g, &x, &y), (&expected_g, &expected_x, &expected_y));
+        assert_eq!(&x * IBig::from(a) + &y * IBig::from(b), IBig::from(&g));
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_extended_gcd_into_0_0() {
+    let mut g = ubig!(0);
+    let mut x = ibig!(0);
+    let mut y = ibig!(0);
+    ubig!(0).extended_gcd_into(&ubig!(0), &mut g, &mut x, &mut y);
+}
+
+#[test]
+fn test_gcd_lcm() {
+    let test_cases = [
+        (ubig!(0), ubig!(0), ubig!(0), ubig!(0)),
+        (ubig!(0), ubig!(5), ubig!(5), ubig!(0)),
+        (ubig!(7), ubig!(0), ubig!(7), ubig!(0)),
+        (ubig!(1), ubig!(1), ubig!(1), ubig!(1)),
+        (ubig!(12), ubig!(18), ubig!(6), ubig!(36)),
+        (ubig!(17), ubig!(19), ubig!(1), ubig!(323)),
+        (
+            ubig!(_0x100000000000000000000000000000000),
+            ubig!(_0x300000000000000000000000000000000),
+            ubig!(_0x100000000000000000000000000000000),
+            ubig!(_0x300000000000000000000000000000000),
+        ),
+    ];
+    for (a, b, gcd, lcm) in &test_cases {
+        assert_eq!(a.gcd_lcm(b), (gcd.clone(), lcm.clone()));
+        assert_eq!(b.gcd_lcm(a), (gcd.clone(), lcm.clone()));
+        assert_eq!(a.lcm(b), *lcm);
+    }
+
+    let a = ubig!(3).pow(100) * ubig!(2).pow(50);
+    let b = ubig!(3).pow(70) * ubig!(5).pow(60);
+    let (gcd, lcm) = a.gcd_lcm(&b);
+    assert_eq!(gcd, a.gcd(&b));
+    assert_eq!(&gcd * &lcm, &a * &b);
+}
+
+#[test]
+fn test_gcd_lcm_all() {
+    assert_eq!(UBig::gcd_all([]), ubig!(0));
+    assert_eq!(UBig::gcd_all([ubig!(0), ubig!(0)]), ubig!(0));
+    assert_eq!(UBig::gcd_all([ubig!(0), ubig!(12), ubig!(0)]), ubig!(12));
+    assert_eq!(UBig::gcd_all([ubig!(12), ubig!(18), ubig!(27)]), ubig!(3));
+    let big = ubig!(3).pow(100);
+    assert_eq!(
+        UBig::gcd_all([&big * ubig!(10), &big * ubig!(14), &big * ubig!(35)]),
+        big
+    );
+
+    assert_eq!(UBig::lcm_all([]), ubig!(1));
+    assert_eq!(UBig::lcm_all([ubig!(4), ubig!(6), ubig!(10)]), ubig!(60));
+    assert_eq!(UBig::lcm_all([ubig!(4), ubig!(0), ubig!(10)]), ubig!(0));
+    assert_eq!(
+        UBig::lcm_all([ubig!(2).pow(50), ubig!(3).pow(40), ubig!(6).pow(45)]),
+        ubig!(2).pow(50) * ubig!(3).pow(45)
+    );
+
+    // Iteration stops early, so the panicking tail is never reached.
+    let coprime = [ubig!(6), ubig!(35)]
+        .into_iter()
+        .chain(std::iter::repeat_with(|| panic!("consumed past gcd 1")));
+    assert_eq!(UBig::gcd_all(coprime), ubig!(1));
+    let with_zero = [ubig!(6), ubig!(0)]
+        .into_iter()
+        .chain(std::iter::repeat_with(|| panic!("consumed past lcm 0")));
+    assert_eq!(UBig::lcm_all(with_zero), ubig!(0));
+}
+
+#[test]
+fn test_mod_inverse() {
+    assert_eq!(ubig!(7).mod_inverse(&ubig!(10)), Some(ubig!(3)));
+    assert_eq!(ubig!(17).mod_inverse(&ubig!(10)), Some(ubig!(3)));
+    assert_eq!(ubig!(3).mod_inverse(&ubig!(10)), Some(ubig!(7)));
+    assert_eq!(ubig!(2).mod_inverse(&ubig!(10)), None);
+    assert_eq!(ubig!(0).mod_inverse(&ubig!(10)), None);
+    assert_eq!(ubig!(0).mod_inverse(&ubig!(1)), Some(ubig!(0)));
+    assert_eq!(ubig!(5).mod_inverse(&ubig!(1)), Some(ubig!(0)));
+    assert_eq!(
+        ubig!(_0x123456789123456789123456789123456789).mod_inverse(&ubig!(_0x987654321)),
+        None
+    );
+
+    let p = ubig!(_0x3ffffffffffffffffffffffffffffffffffffd); // largest prime under 2^150
+    for a in [
+        ubig!(1),
+        ubig!(2),
+        ubig!(_0x123456789123456789123456789),
+        &p - ubig!(1),
+    ] {
+        let inv = a.mod_inverse(&p).unwrap();
+        assert!(inv < p);
+        assert_eq!(&a * &inv % &p, ubig!(1));
+    }
+
+    for m in 1u8..=20 {
+        for a in 0u8..=40 {
+            let m = UBig::from(m);
+            let a = UBig::from(a);
+            match a.mod_inverse(&m) {
+                Some(inv) => {
+                    assert!(inv < m);
+                    assert_eq!(&a * &inv % &m, ubig!(1) % &m);
+                }
+                None => assert_ne!(a.gcd(&m), ubig!(1)),
+            }
+        }
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_mod_inverse_modulus_0() {
+    let _ = ubig!(3).mod_inverse(&ubig!(0));
+}
+
 #[test]
 fn test_gcd_ibig() {
     assert_eq!(ibig!(12).gcd(&ibig!(18)), ibig!(6));
@@ -1,4 +1,4 @@
-use ibig::ubig;
+use ibig::{ibig, ubig, UBig};
 use std::{
     collections::hash_map::DefaultHasher,
     hash::{Hash, Hasher},
@@ -24,3 +24,56 @@ fn test_hash() {
     let h3 = hash(&(ubig!(2) << 1000));
     assert!(h3 != h);
 }
+
+/// Records everything written to it.
+#[derive(Default)]
+struct RecordingHasher {
+    writes: Vec<Vec<u8>>,
+}
+
+impl Hasher for RecordingHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        self.writes.push(bytes.to_vec());
+    }
+
+    fn finish(&self) -> u64 {
+        0
+    }
+}
+
+fn stable_hash_writes(x: &UBig) -> Vec<Vec<u8>> {
+    let mut hasher = RecordingHasher::default();
+    x.stable_hash(&mut hasher);
+    hasher.writes
+}
+
+#[test]
+fn test_stable_hash() {
+    assert_eq!(stable_hash_writes(&ubig!(0)), [vec![0; 8], vec![]]);
+    assert_eq!(
+        stable_hash_writes(&ubig!(0x1234)),
+        [vec![2, 0, 0, 0, 0, 0, 0, 0], vec![0x34, 0x12]]
+    );
+    let x = ubig!(_0x0102030405060708090a0b0c0d0e0f1011);
+    assert_eq!(
+        stable_hash_writes(&x),
+        [vec![17, 0, 0, 0, 0, 0, 0, 0], x.to_le_bytes()]
+    );
+
+    // The internal capacity doesn't matter.
+    let y = (&x << 1000) >> 1000;
+    assert_eq!(stable_hash_writes(&y), stable_hash_writes(&x));
+
+    let mut hasher = RecordingHasher::default();
+    ibig!(-0x1234).stable_hash(&mut hasher);
+    assert_eq!(
+        hasher.writes,
+        [vec![1], vec![2, 0, 0, 0, 0, 0, 0, 0], vec![0x34, 0x12]]
+    );
+    let mut hasher = RecordingHasher::default();
+    ibig!(0x1234).stable_hash(&mut hasher);
+    assert_eq!(
+        hasher.writes,
+        [vec![0], vec![2, 0, 0, 0, 0, 0, 0, 0], vec![0x34, 0x12]]
+    );
+}
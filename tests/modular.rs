@@ -1,4 +1,4 @@
-use ibig::{ibig, ubig, ModuloRing};
+use ibig::{ibig, ubig, CrtBasis, IBig, Modulo, ModuloRing, UBig};
 
 #[test]
 fn test_modulus() {
@@ -9,6 +9,15 @@ fn test_modulus() {
     assert_eq!(ring.modulus(), ubig!(10).pow(100));
 }
 
+#[test]
+fn test_ring_from_ubig() {
+    for n in [ubig!(100), ubig!(1) << 100, ubig!(10).pow(100)] {
+        let ring: ModuloRing = n.clone().into();
+        assert_eq!(ring.modulus(), n);
+        assert_eq!(ring.from(&n + ubig!(7)).residue(), ubig!(7));
+    }
+}
+
 #[test]
 fn test_clone() {
     let ring1 = ModuloRing::new(&ubig!(100));
@@ -101,6 +110,39 @@ fn test_convert() {
     );
 }
 
+#[test]
+fn test_from_ibig() {
+    let ring = ModuloRing::new(&ubig!(100));
+    assert_eq!(ring.from_ibig(&ibig!(0)).residue(), ubig!(0));
+    assert_eq!(ring.from_ibig(&ibig!(-1)).residue(), ubig!(99));
+    assert_eq!(ring.from_ibig(&ibig!(-100)).residue(), ubig!(0));
+    assert_eq!(ring.from_ibig(&ibig!(-101)).residue(), ubig!(99));
+    assert_eq!(
+        ring.from_ibig(&ibig!(_18297381723918723981723981723906)),
+        ring.from(6)
+    );
+    assert_eq!(
+        ring.from_ibig(&ibig!(-_18297381723918723981723981723994))
+            .residue(),
+        ubig!(6)
+    );
+
+    let n = ubig!(_1000000000000000000000000000000000000000000000000000000000000);
+    let ring = ModuloRing::new(&n);
+    assert_eq!(ring.from_ibig(&ibig!(-1)).residue(), &n - ubig!(1));
+    assert_eq!(ring.from_ibig(&-IBig::from(n.clone())).residue(), ubig!(0));
+    assert_eq!(
+        ring.from_ibig(&IBig::from(&n * ubig!(7) + ubig!(5)))
+            .residue(),
+        ubig!(5)
+    );
+    assert_eq!(
+        ring.from_ibig(&-IBig::from(&n * ubig!(7) + ubig!(5)))
+            .residue(),
+        &n - ubig!(5)
+    );
+}
+
 #[test]
 fn test_negate() {
     let ring = ModuloRing::new(&ubig!(100));
@@ -128,6 +170,31 @@ fn test_different_rings() {
     assert_ne!(ring1, ring2);
 }
 
+#[test]
+#[should_panic(expected = "same modulus 100")]
+fn test_add_different_ring_instances() {
+    let ring1 = ModuloRing::new(&ubig!(100));
+    let ring2 = ModuloRing::new(&ubig!(100));
+    let _ = ring1.from(5) + ring2.from(5);
+}
+
+#[test]
+#[should_panic(expected = "modulus 100 vs 200")]
+fn test_mul_different_moduli() {
+    let ring1 = ModuloRing::new(&ubig!(100));
+    let ring2 = ModuloRing::new(&ubig!(200));
+    let _ = ring1.from(5) * ring2.from(5);
+}
+
+#[test]
+fn test_rebind_ring() {
+    let ring1 = ModuloRing::new(&ubig!(_1000000000000000000000000000000));
+    let ring2 = ModuloRing::new(&ubig!(_1000000000000000000000000000000));
+    let x = ring1.from(-5);
+    let y = ring2.from(x.residue());
+    assert_eq!(y + 5u8, ring2.from(0));
+}
+
 #[test]
 #[should_panic]
 fn test_cmp_different_rings() {
@@ -193,6 +260,40 @@ fn test_add_sub() {
     }
 }
 
+#[test]
+fn test_add_sub_primitive() {
+    let ring1 = ModuloRing::new(&ubig!(100));
+    let ring2 = ModuloRing::new(&ubig!(_1000000000000000000000000000000));
+
+    assert_eq!(ring1.from(99) + 1u8, ring1.from(0));
+    assert_eq!(&ring1.from(99) + 1234u64, ring1.from(33));
+    assert_eq!(ring1.from(5) - 7i32, ring1.from(98));
+    assert_eq!(&ring1.from(5) - -7i64, ring1.from(12));
+    assert_eq!(ring1.from(5) + u128::MAX, ring1.from(u128::MAX) + 5u8);
+    assert_eq!(
+        ring1.from(5) - i128::MIN,
+        ring1.from(5) - ring1.from(i128::MIN)
+    );
+    let mut x = ring1.from(50);
+    x += 70usize;
+    assert_eq!(x, ring1.from(20));
+    x -= 30isize;
+    assert_eq!(x, ring1.from(90));
+
+    let a = ring2.from(ubig!(999999999999999999999999999999));
+    assert_eq!(&a + 1u16, ring2.from(0));
+    assert_eq!(a.clone() - u64::MAX, a.clone() - ring2.from(u64::MAX));
+    assert_eq!(
+        a.clone() + -1i8,
+        ring2.from(ubig!(999999999999999999999999999998))
+    );
+    let mut x = a;
+    x -= 999999999999999999999999999999u128;
+    assert_eq!(x, ring2.from(0));
+    x += -3i16;
+    assert_eq!(x, ring2.from(-3));
+}
+
 #[test]
 fn test_mul() {
     let ring1 = ModuloRing::new(&ubig!(100));
@@ -232,6 +333,69 @@ fn test_mul() {
     }
 }
 
+#[test]
+fn test_mul_primitive() {
+    let ring1 = ModuloRing::new(&ubig!(100));
+    let ring2 = ModuloRing::new(&ubig!(_1000000000000000000000000000000));
+
+    for ring in [&ring1, &ring2] {
+        let a = ring.from(ubig!(123456789123456789123456789));
+        assert_eq!(&a * 2u8, &a * ring.from(2));
+        assert_eq!(a.clone() * 65535u16, &a * ring.from(65535));
+        assert_eq!(&a * 1000003u32, &a * ring.from(ubig!(1000003)));
+        assert_eq!(&a * u64::MAX, &a * ring.from(UBig::from(u64::MAX)));
+        assert_eq!(&a * u128::MAX, &a * ring.from(UBig::from(u128::MAX)));
+        assert_eq!(&a * 7usize, &a * ring.from(7));
+        assert_eq!(&a * -3i8, -(&a * ring.from(3)));
+        assert_eq!(&a * i16::MIN, &a * ring.from(i16::MIN));
+        assert_eq!(&a * -1i32, -&a);
+        assert_eq!(&a * i64::MIN, &a * ring.from(i64::MIN));
+        assert_eq!(&a * i128::MAX, &a * ring.from(i128::MAX));
+        let mut x = a.clone();
+        x *= -5isize;
+        assert_eq!(x, &a * ring.from(-5));
+        x *= 100u64;
+        assert_eq!(x, &a * ring.from(-500));
+    }
+    assert_eq!(ring1.from(7) * 15u8, ring1.from(5));
+}
+
+#[test]
+fn test_sum_product() {
+    let ring1 = ModuloRing::new(&ubig!(100));
+    let ring2 = ModuloRing::new(&ubig!(_1000000000000000000000000000000));
+
+    for ring in [&ring1, &ring2] {
+        let values: Vec<_> = (1..=30).map(|x| ring.from(x)).collect();
+        let sum: Modulo = values.iter().sum();
+        assert_eq!(sum, ring.from(465));
+        let sum: Modulo = values.clone().into_iter().sum();
+        assert_eq!(sum, ring.from(465));
+
+        let factorial = (1..=30u32).fold(ubig!(1), |acc, x| acc * UBig::from(x));
+        let product: Modulo = values.iter().product();
+        assert_eq!(product, ring.from(&factorial));
+        let product: Modulo = values.into_iter().product();
+        assert_eq!(product, ring.from(&factorial));
+
+        let single: Modulo = [ring.from(7)].iter().product();
+        assert_eq!(single, ring.from(7));
+    }
+}
+
+#[test]
+#[should_panic(expected = "empty iterator")]
+fn test_sum_empty() {
+    let _: Modulo = Vec::<Modulo>::new().into_iter().sum();
+}
+
+#[test]
+#[should_panic(expected = "empty iterator")]
+fn test_product_empty() {
+    let empty: [Modulo; 0] = [];
+    let _: Modulo = empty.iter().product();
+}
+
 #[test]
 fn test_inverse() {
     let ring = ModuloRing::new(&ubig!(1));
@@ -261,6 +425,41 @@ fn test_inverse() {
     );
 }
 
+#[test]
+fn test_inverse_power_of_two() {
+    for k in 0..=10 {
+        let m = 1u32 << k;
+        let ring = ModuloRing::new(&UBig::from(m));
+        for a in 0..m {
+            let expected = (0..m).find(|x| a * x % m == 1 % m);
+            assert_eq!(ring.from(a).inverse(), expected.map(|x| ring.from(x)));
+        }
+    }
+
+    for k in [63, 64, 65, 127, 128, 129, 200, 1000] {
+        let m = ubig!(1) << k;
+        let ring = ModuloRing::new(&m);
+        assert_eq!(ring.from(1).inverse(), Some(ring.from(1)));
+        assert_eq!(
+            ring.from(&m - ubig!(1)).inverse(),
+            Some(ring.from(&m - ubig!(1)))
+        );
+        assert_eq!(ring.from(&m - ubig!(2)).inverse(), None);
+        assert_eq!(ring.from(0).inverse(), None);
+        for a in [
+            ubig!(3),
+            ubig!(0xffffffffffffffff),
+            ubig!(_0x123456789123456789123456789123456789),
+            ubig!(_0x5a4653ca673768565b41f775d6947d55cf3813d1), // 3^100
+        ] {
+            let x = ring.from(&a);
+            assert_eq!(x.inverse().unwrap() * &x, ring.from(1));
+            let y = ring.from(a << 1);
+            assert_eq!(y.inverse(), None);
+        }
+    }
+}
+
 #[test]
 fn test_div() {
     let ring = ModuloRing::new(&ubig!(1));
@@ -285,6 +484,32 @@ fn test_div() {
     assert_eq!(a, res);
 }
 
+#[test]
+fn test_checked_div() {
+    let ring = ModuloRing::new(&ubig!(10));
+    assert_eq!(ring.from(2).checked_div(&ring.from(3)), Some(ring.from(4)));
+    assert_eq!(ring.from(0).checked_div(&ring.from(7)), Some(ring.from(0)));
+    assert_eq!(ring.from(2).checked_div(&ring.from(4)), None);
+    assert_eq!(ring.from(1).checked_div(&ring.from(0)), None);
+
+    let ring = ModuloRing::new(&ubig!(1000000000000000000000000000057)); // prime
+    let a = ring.from(123456789);
+    let b = ring.from(987654321);
+    assert_eq!(a.checked_div(&b).unwrap() * &b, a);
+    assert_eq!(a.checked_div(&ring.from(0)), None);
+
+    let ring = ModuloRing::new(&ubig!(1000000000000000000000000000000)); // not prime
+    assert_eq!(ring.from(7).checked_div(&ring.from(10)), None);
+}
+
+#[test]
+#[should_panic]
+fn test_checked_div_different_rings() {
+    let ring1 = ModuloRing::new(&ubig!(100));
+    let ring2 = ModuloRing::new(&ubig!(200));
+    let _ = ring1.from(1).checked_div(&ring2.from(2));
+}
+
 #[test]
 #[should_panic]
 fn test_add_different_rings() {
@@ -334,6 +559,15 @@ fn test_div_by_noninvertible() {
     let _ = x / y;
 }
 
+#[test]
+#[should_panic]
+fn test_div_by_noninvertible_large() {
+    let ring = ModuloRing::new(&ubig!(1000000000000000000000000000000));
+    let x = ring.from(10);
+    let y = ring.from(2);
+    let _ = &x / &y;
+}
+
 #[test]
 fn test_pow() {
     let ring = ModuloRing::new(&ubig!(100));
@@ -364,6 +598,240 @@ fn test_pow() {
     assert_eq!(ring.from(13).pow(&(prime - ubig!(1))), ring.from(1));
 }
 
+#[test]
+fn test_ubig_pow_mod() {
+    let moduli = [
+        ubig!(1),
+        ubig!(2),
+        ubig!(6),
+        ubig!(64),
+        ubig!(1000),
+        ubig!(1) << 64,
+        ubig!(1) << 65,
+        ubig!(1) << 200,
+        (ubig!(1) << 100) * ubig!(3),
+        ubig!(10).pow(30),
+        ubig!(10).pow(30) + ubig!(7),
+    ];
+    let bases = [
+        ubig!(0),
+        ubig!(1),
+        ubig!(2),
+        ubig!(3),
+        ubig!(6),
+        ubig!(12345678901234567),
+        (ubig!(1) << 150) + ubig!(1),
+        ubig!(1) << 150,
+    ];
+    for m in &moduli {
+        let ring = ModuloRing::new(m);
+        for base in &bases {
+            for exp in [
+                ubig!(0),
+                ubig!(1),
+                ubig!(2),
+                ubig!(5),
+                ubig!(64),
+                ubig!(12837918273),
+                ubig!(1) << 300,
+            ] {
+                let res = base.pow_mod(&exp, m);
+                assert_eq!(res, ring.from(base).pow(&exp).residue());
+                assert!(res < *m);
+                if exp < ubig!(100) {
+                    assert_eq!(res, base.pow(usize::try_from(&exp).unwrap()) % m);
+                }
+            }
+        }
+    }
+    assert_eq!(ubig!(3).pow_mod(&ubig!(5), &ubig!(64)), ubig!(51));
+    assert_eq!(ubig!(2).pow_mod(&ubig!(5), &ubig!(32)), ubig!(0));
+    assert_eq!(ubig!(2).pow_mod(&ubig!(4), &ubig!(32)), ubig!(16));
+}
+
+#[test]
+#[should_panic]
+fn test_ubig_pow_mod_0() {
+    let _ = ubig!(3).pow_mod(&ubig!(3), &ubig!(0));
+}
+
+#[test]
+fn test_ubig_add_sub_mul_mod() {
+    let moduli = [
+        ubig!(1),
+        ubig!(10),
+        ubig!(1) << 64,
+        ubig!(10).pow(30) + ubig!(7),
+        (ubig!(1) << 200) - ubig!(1),
+    ];
+    let values = [
+        ubig!(0),
+        ubig!(3),
+        ubig!(9),
+        ubig!(12345678901234567),
+        ubig!(1) << 150,
+        (ubig!(1) << 300) + ubig!(5),
+    ];
+    for m in &moduli {
+        let ring = ModuloRing::new(m);
+        for a in &values {
+            for b in &values {
+                assert_eq!(a.add_mod(b, m), (ring.from(a) + ring.from(b)).residue());
+                assert_eq!(a.sub_mod(b, m), (ring.from(a) - ring.from(b)).residue());
+                assert_eq!(a.mul_mod(b, m), (ring.from(a) * ring.from(b)).residue());
+                assert!(a.sub_mod(b, m) < *m);
+            }
+        }
+    }
+    assert_eq!(ubig!(3).sub_mod(&ubig!(9), &ubig!(10)), ubig!(4));
+    assert_eq!(ubig!(13).sub_mod(&ubig!(9), &ubig!(10)), ubig!(4));
+    assert_eq!(ubig!(9).sub_mod(&ubig!(19), &ubig!(10)), ubig!(0));
+}
+
+#[test]
+#[should_panic]
+fn test_ubig_sub_mod_0() {
+    let _ = ubig!(3).sub_mod(&ubig!(5), &ubig!(0));
+}
+
+#[test]
+fn test_ubig_pow_mod_bytes() {
+    let moduli = [
+        ubig!(1),
+        ubig!(10),
+        ubig!(64),
+        ubig!(1) << 64,
+        ubig!(10).pow(30) + ubig!(7),
+        (ubig!(1) << 521) - ubig!(1),
+    ];
+    let exponents: [&[u8]; 8] = [
+        &[],
+        &[0],
+        &[0, 0, 1],
+        &[0x80],
+        &[0x0f, 0xf0],
+        &[0xff; 9],
+        &[0x12, 0x34, 0x56, 0x78, 0x9a, 0xbc, 0xde, 0xf0, 0x01],
+        &[0xa5; 64],
+    ];
+    for m in &moduli {
+        for base in [ubig!(0), ubig!(2), ubig!(3), (ubig!(1) << 600) + ubig!(3)] {
+            for exp in exponents {
+                assert_eq!(
+                    base.pow_mod_bytes(exp, m),
+                    base.pow_mod(&UBig::from_be_bytes(exp), m)
+                );
+            }
+        }
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_ubig_pow_mod_bytes_0() {
+    let _ = ubig!(3).pow_mod_bytes(&[1], &ubig!(0));
+}
+
+#[test]
+fn test_ibig_pow_mod() {
+    let moduli = [
+        ubig!(1),
+        ubig!(10),
+        ubig!(1) << 64,
+        ubig!(10).pow(30) + ubig!(7),
+    ];
+    for m in &moduli {
+        for base in [
+            ibig!(0),
+            ibig!(3),
+            ibig!(-3),
+            ibig!(-1) << 100,
+            ibig!(-12345678901234567),
+        ] {
+            for exp in [ubig!(0), ubig!(1), ubig!(2), ubig!(12837918273)] {
+                let residue = base.rem_euclid_ubig(m);
+                let expected = ModuloRing::new(m).from(&residue).pow(&exp).residue();
+                let res = base.pow_mod(&exp, m);
+                assert_eq!(res, expected);
+                assert_eq!(res, IBig::from(residue).pow_mod(&exp, m));
+                assert!(res < *m);
+            }
+        }
+    }
+    assert_eq!(ibig!(-3).pow_mod(&ubig!(3), &ubig!(100)), ubig!(73));
+}
+
+#[test]
+#[should_panic]
+fn test_ibig_pow_mod_0() {
+    let _ = ibig!(-3).pow_mod(&ubig!(3), &ubig!(0));
+}
+
+#[test]
+fn test_pow_table() {
+    let moduli = [
+        ubig!(1),
+        ubig!(2),
+        ubig!(100),
+        ubig!(1) << 64,
+        ubig!(10).pow(30) + ubig!(7),
+        ubig!(2).pow(607) - ubig!(1),
+    ];
+    for m in &moduli {
+        let ring = ModuloRing::new(m);
+        for base in [ubig!(0), ubig!(1), ubig!(3), ubig!(12345678901234567)] {
+            let base = ring.from(base);
+            let table = base.precompute_powers();
+            assert_eq!(table.base(), &base);
+            for exp in [
+                ubig!(0),
+                ubig!(1),
+                ubig!(2),
+                ubig!(12837918273),
+                m.clone(),
+                m - ubig!(1),
+                (ubig!(1) << 1000) + ubig!(5),
+                ubig!(3).pow(700),
+            ] {
+                assert_eq!(table.pow(&exp), base.pow(&exp));
+            }
+        }
+    }
+}
+
+#[test]
+fn test_order() {
+    for m in [2u32, 3, 7, 13, 97, 100, 256, 1001] {
+        let ring = ModuloRing::new(&UBig::from(m));
+        for a in 0..m {
+            let x = ring.from(a);
+            let one = ring.from(1);
+            let naive = if UBig::from(a).gcd(&UBig::from(m)) != ubig!(1) {
+                None
+            } else {
+                let mut y = x.clone();
+                let mut k = 1u32;
+                while y != one {
+                    y *= &x;
+                    k += 1;
+                }
+                Some(UBig::from(k))
+            };
+            assert_eq!(x.order(), naive);
+        }
+    }
+
+    let ring = ModuloRing::new(&ubig!(1));
+    assert_eq!(ring.from(0).order(), Some(ubig!(1)));
+
+    // 2 is a primitive root modulo the prime 2^64 - 59.
+    let p = ubig!(18446744073709551557);
+    let ring = ModuloRing::new(&p);
+    assert_eq!(ring.from(2).order(), Some(&p - ubig!(1)));
+    assert_eq!(ring.from(4).order(), Some((&p - ubig!(1)) / ubig!(2)));
+    assert_eq!(ring.from(-1).order(), Some(ubig!(2)));
+}
+
 #[test]
 fn test_pow_signed() {
     let ring = ModuloRing::new(&ubig!(100));
@@ -417,3 +885,105 @@ fn test_format() {
         "C9F2C9CD04674EDEA3FFFFFFF (mod C9F2C9CD04674EDEA40000000)"
     );
 }
+
+#[test]
+fn test_crt() {
+    assert_eq!(UBig::crt(&[]), Some(ubig!(0)));
+    assert_eq!(UBig::crt(&[(ubig!(5), ubig!(7))]), Some(ubig!(5)));
+    assert_eq!(UBig::crt(&[(ubig!(12), ubig!(7))]), Some(ubig!(5)));
+    assert_eq!(
+        UBig::crt(&[
+            (ubig!(2), ubig!(3)),
+            (ubig!(3), ubig!(5)),
+            (ubig!(2), ubig!(7))
+        ]),
+        Some(ubig!(23))
+    );
+    assert_eq!(
+        UBig::crt(&[(ubig!(0), ubig!(1)), (ubig!(3), ubig!(4))]),
+        Some(ubig!(3))
+    );
+
+    let x = ubig!(_123456789012345678901234567890123456789012345678901234567890);
+    let moduli = [
+        ubig!(1000000007),
+        ubig!(998244353),
+        ubig!(_340282366920938463463374607431768211297),
+        ubig!(_18446744073709551557),
+    ];
+    let congruences: Vec<(UBig, UBig)> = moduli.iter().map(|m| (&x % m, m.clone())).collect();
+    assert_eq!(UBig::crt(&congruences), Some(x));
+
+    assert_eq!(
+        UBig::crt(&[(ubig!(1), ubig!(4)), (ubig!(2), ubig!(6))]),
+        None
+    );
+    assert_eq!(
+        UBig::crt(&[(ubig!(1), ubig!(4)), (ubig!(1), ubig!(6))]),
+        None
+    );
+    assert_eq!(
+        UBig::crt(&[
+            (ubig!(1), ubig!(3)),
+            (ubig!(1), ubig!(1) << 200),
+            (ubig!(0), ubig!(1) << 100)
+        ]),
+        None
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_crt_modulus_0() {
+    let _ = UBig::crt(&[(ubig!(1), ubig!(3)), (ubig!(1), ubig!(0))]);
+}
+
+#[test]
+fn test_crt_basis() {
+    let basis = CrtBasis::new(&[]).unwrap();
+    assert_eq!(basis.product(), &ubig!(1));
+    assert_eq!(basis.combine(&[]), ubig!(0));
+
+    let moduli = [
+        ubig!(1000000007),
+        ubig!(998244353),
+        ubig!(_340282366920938463463374607431768211297),
+        ubig!(1),
+        ubig!(_18446744073709551557),
+    ];
+    let basis = CrtBasis::new(&moduli).unwrap();
+    let product = moduli.iter().fold(ubig!(1), |acc, m| acc * m);
+    assert_eq!(basis.product(), &product);
+
+    let values = [
+        ubig!(0),
+        ubig!(1),
+        ubig!(1000000006),
+        ubig!(_123456789012345678901234567890123456789012345678901234567890),
+        &product - ubig!(1),
+    ];
+    for x in &values {
+        let residues: Vec<UBig> = moduli.iter().map(|m| x % m).collect();
+        assert_eq!(basis.combine(&residues), *x);
+        let congruences: Vec<(UBig, UBig)> = residues
+            .iter()
+            .cloned()
+            .zip(moduli.iter().cloned())
+            .collect();
+        assert_eq!(UBig::crt(&congruences), Some(x.clone()));
+    }
+
+    // Residues don't have to be reduced.
+    let residues: Vec<UBig> = moduli.iter().map(|m| m + ubig!(5)).collect();
+    assert_eq!(basis.combine(&residues), ubig!(5));
+
+    assert!(CrtBasis::new(&[ubig!(6), ubig!(35), ubig!(22)]).is_none());
+    assert!(CrtBasis::new(&[ubig!(1) << 100, ubig!(1) << 50]).is_none());
+}
+
+#[test]
+#[should_panic]
+fn test_crt_basis_wrong_len() {
+    let basis = CrtBasis::new(&[ubig!(3), ubig!(5)]).unwrap();
+    let _ = basis.combine(&[ubig!(1)]);
+}
@@ -2,7 +2,7 @@ use core::{
     fmt::Debug,
     ops::{Mul, MulAssign},
 };
-use ibig::{ibig, ubig};
+use ibig::{ibig, ubig, UBig};
 
 fn test_mul<'a, T>(a: &'a T, b: &'a T, c: &'a T)
 where
@@ -65,6 +65,66 @@ fn test_mul_ubig() {
     }
 }
 
+#[test]
+fn test_mul_ubig_power_of_two() {
+    let values = [
+        ubig!(_0x123456789abcdef0123456789abcdef0123456789),
+        (ubig!(1) << 300) - ubig!(1),
+        ubig!(1) << 200,
+    ];
+    for a in &values {
+        for k in [128, 129, 191, 192, 500, 1000] {
+            let p = ubig!(1) << k;
+            let c = a << k;
+            test_mul(a, &p, &c);
+            test_mul(&p, a, &c);
+            // Compare with the general algorithm.
+            assert_eq!(a * (&p + ubig!(1)) - a, c);
+        }
+    }
+}
+
+#[test]
+fn test_mul_ubig_double_word_boundary() {
+    let values = [
+        ubig!(0),
+        ubig!(1),
+        ubig!(2),
+        ubig!(0xffffffff),
+        ubig!(0x100000000),
+        ubig!(0xffffffffffffffff),
+        ubig!(0x10000000000000000),
+        ubig!(0x10000000000000001),
+        ubig!(0x1ffffffffffffffff),
+        ubig!(0x80000000000000000000000000000000),
+        ubig!(0xfffffffffffffffffffffffffffffffe),
+        ubig!(0xffffffffffffffffffffffffffffffff),
+        ubig!(_0x100000000000000000000000000000000),
+        ubig!(_0x100000000000000000000000000000001),
+    ];
+
+    for a in &values {
+        for b in &values {
+            // Shifting a moves the multiplication to the general multi-word algorithm.
+            let c = ((a << 256) * b) >> 256;
+            test_mul(a, b, &c);
+        }
+    }
+
+    assert_eq!(
+        ubig!(0xffffffffffffffffffffffffffffffff) * ubig!(0xffffffffffffffffffffffffffffffff),
+        ubig!(_0xfffffffffffffffffffffffffffffffe00000000000000000000000000000001)
+    );
+    assert_eq!(
+        ubig!(0xffffffffffffffffffffffffffffffff) * ubig!(0xffffffffffffffff),
+        ubig!(_0xfffffffffffffffeffffffffffffffff0000000000000001)
+    );
+    assert_eq!(
+        ubig!(0xffffffffffffffff) * ubig!(0xffffffffffffffff),
+        ubig!(0xfffffffffffffffe0000000000000001)
+    );
+}
+
 #[test]
 fn test_mul_ibig() {
     let test_cases = [
@@ -147,3 +207,51 @@ fn test_mul_ibig_primitive() {
     x *= &-2;
     assert_eq!(x, ibig!(12));
 }
+
+#[test]
+fn test_checked_mul() {
+    assert_eq!(ubig!(2).checked_mul(&ubig!(3)), Some(ubig!(6)));
+    assert_eq!(ubig!(0).checked_mul(&ubig!(3)), Some(ubig!(0)));
+    let a = ubig!(_0xffffffffffffffffffffffffffffffffffffffff);
+    assert_eq!(a.checked_mul(&a), Some(&a * &a));
+
+    // Only feasible when the maximum size is small, e.g. with 16-bit words.
+    if UBig::MAX_BIT_LEN <= 1 << 20 {
+        let m = UBig::MAX_BIT_LEN;
+        assert_eq!(m % 2, 0);
+        let h = m / 2;
+        let max = UBig::ones(m);
+        assert_eq!(max.checked_mul(&ubig!(1)), Some(max.clone()));
+        assert_eq!(max.checked_mul(&ubig!(2)), None);
+
+        // Borderline: the bit lengths add up to m + 1.
+        let p = ubig!(1) << h;
+        let odd = &p - ubig!(1);
+        let even = &p - ubig!(2);
+        assert_eq!((&p + ubig!(1)).checked_mul(&odd), Some(max.clone()));
+        assert_eq!((&p + ubig!(2)).checked_mul(&odd), None);
+        assert_eq!(
+            (&p + ubig!(1)).checked_mul(&even),
+            Some(&max - &p - ubig!(1))
+        );
+        assert_eq!((ubig!(3) << (h - 1)).checked_mul(&even), None);
+    }
+}
+
+#[test]
+fn test_product_of() {
+    assert_eq!(UBig::product_of(Vec::new()), ubig!(1));
+    assert_eq!(UBig::product_of([ubig!(7)]), ubig!(7));
+    assert_eq!(UBig::product_of([ubig!(7), ubig!(0), ubig!(5)]), ubig!(0));
+
+    for n in 0u32..70 {
+        let expected = (1..=n).fold(ubig!(1), |acc, i| acc * UBig::from(i));
+        assert_eq!(UBig::product_of((1..=n).map(UBig::from)), expected);
+    }
+
+    let factors: Vec<UBig> = (0..100)
+        .map(|i| (ubig!(1) << (i * 7)) + UBig::from(i as u32))
+        .collect();
+    let expected = factors.iter().fold(ubig!(1), |acc, x| acc * x);
+    assert_eq!(UBig::product_of(factors), expected);
+}
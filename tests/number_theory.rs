@@ -0,0 +1,296 @@
+use ibig::{ibig, ubig, IBig, UBig};
+
+#[test]
+fn test_smallest_factor_below() {
+    fn smallest_factor_naive(n: u64) -> Option<u64> {
+        match n {
+            0 => Some(2),
+            1 => None,
+            _ => (2..=n).find(|d| n % d == 0),
+        }
+    }
+
+    for n in 0u64..2000 {
+        for limit in [0, 2, 3, 10, 100, 1024, 1100, 3000] {
+            let expected = smallest_factor_naive(n).filter(|d| *d < limit);
+            assert_eq!(UBig::from(n).smallest_factor_below(limit), expected);
+        }
+    }
+
+    // Factors beyond the table of small primes.
+    assert_eq!(ubig!(1065023).smallest_factor_below(2000), Some(1031)); // 1031 * 1033
+    assert_eq!(ubig!(1065023).smallest_factor_below(1031), None);
+
+    let mersenne_127 = (ubig!(1) << 127) - ubig!(1);
+    assert_eq!(mersenne_127.smallest_factor_below(10000), None);
+    assert_eq!(
+        (&mersenne_127 * ubig!(1009)).smallest_factor_below(10000),
+        Some(1009)
+    );
+    assert_eq!(
+        (&mersenne_127 * &mersenne_127 * ubig!(4099)).smallest_factor_below(10000),
+        Some(4099)
+    );
+}
+
+#[test]
+fn test_is_prime_bpsw() {
+    const N: usize = 20000;
+    let mut is_prime = [true; N];
+    is_prime[0] = false;
+    is_prime[1] = false;
+    for i in 2..N {
+        if is_prime[i] {
+            for j in (i * i..N).step_by(i) {
+                is_prime[j] = false;
+            }
+        }
+    }
+    for (n, expected) in is_prime.iter().enumerate() {
+        assert_eq!(UBig::from(n).is_prime_bpsw(), *expected, "{}", n);
+    }
+
+    // Strong pseudoprimes to base 2, Carmichael numbers, Lucas pseudoprimes,
+    // and squares of Wieferich primes.
+    let composites = [
+        ubig!(2047),
+        ubig!(3277),
+        ubig!(4033),
+        ubig!(4681),
+        ubig!(8321),
+        ubig!(561),
+        ubig!(1105),
+        ubig!(41041),
+        ubig!(5459),
+        ubig!(5777),
+        ubig!(10877),
+        ubig!(1194649),
+        ubig!(12327121),
+        ubig!(3215031751),
+        ubig!(3825123056546413051),
+        ubig!(318665857834031151167461),
+        ubig!(3317044064679887385961981),
+        ubig!(147573952589676412927), // 2^67 - 1
+        ubig!(2).pow(127) + ubig!(1),
+    ];
+    for n in &composites {
+        assert!(!n.is_prime_bpsw(), "{}", n);
+    }
+
+    let primes = [
+        ubig!(1000003),
+        ubig!(4294967291),
+        ubig!(18446744073709551557),
+        ubig!(2).pow(61) - ubig!(1),
+        ubig!(2).pow(89) - ubig!(1),
+        ubig!(2).pow(127) - ubig!(1),
+        ubig!(2).pow(521) - ubig!(1),
+        ubig!(2).pow(607) - ubig!(1),
+    ];
+    for p in &primes {
+        assert!(p.is_prime_bpsw(), "{}", p);
+    }
+
+    // A product of two large primes.
+    let n = (ubig!(2).pow(89) - ubig!(1)) * (ubig!(2).pow(127) - ubig!(1));
+    assert!(!n.is_prime_bpsw());
+    assert!(!(ubig!(2).pow(521) - ubig!(1)).pow(2).is_prime_bpsw());
+}
+
+#[test]
+fn test_next_previous_prime() {
+    let primes: Vec<u64> = (2u64..5000)
+        .filter(|n| (2..*n).all(|d| n % d != 0))
+        .collect();
+    for n in 0u64..4900 {
+        let next = primes.iter().find(|p| **p > n).unwrap();
+        assert_eq!(UBig::from(n).next_prime(), UBig::from(*next));
+        let previous = primes.iter().rev().find(|p| **p < n);
+        assert_eq!(
+            UBig::from(n).previous_prime(),
+            previous.map(|p| UBig::from(*p))
+        );
+    }
+
+    let mersenne_127 = ubig!(2).pow(127) - ubig!(1);
+    assert_eq!((&mersenne_127 - ubig!(1)).next_prime(), mersenne_127);
+    assert_eq!(
+        (&mersenne_127 + ubig!(1)).previous_prime(),
+        Some(mersenne_127)
+    );
+    assert_eq!(ubig!(2).pow(64).next_prime(), ubig!(2).pow(64) + ubig!(13));
+    assert_eq!(
+        ubig!(2).pow(64).previous_prime(),
+        Some(ubig!(2).pow(64) - ubig!(59))
+    );
+    assert_eq!(
+        ubig!(2).pow(128).next_prime(),
+        ubig!(2).pow(128) + ubig!(51)
+    );
+    assert_eq!(
+        ubig!(2).pow(128).previous_prime(),
+        Some(ubig!(2).pow(128) - ubig!(159))
+    );
+}
+
+#[test]
+fn test_is_congruent() {
+    for a in -20i64..20 {
+        for b in -20i64..20 {
+            for m in -7i64..7 {
+                let expected = if m == 0 { a == b } else { (a - b) % m == 0 };
+                let (x, y, z) = (IBig::from(a), IBig::from(b), IBig::from(m));
+                assert_eq!(x.is_congruent(&y, &z), expected);
+                if a >= 0 && b >= 0 && m >= 0 {
+                    let (x, y, z) = (
+                        UBig::from(a as u64),
+                        UBig::from(b as u64),
+                        UBig::from(m as u64),
+                    );
+                    assert_eq!(x.is_congruent(&y, &z), expected);
+                }
+            }
+        }
+    }
+
+    // Across the word boundary.
+    let m = ubig!(0x10000000000000000);
+    let a = ubig!(_0x300000000000000000000000000000005);
+    assert!(a.is_congruent(&ubig!(5), &m));
+    assert!(ubig!(5).is_congruent(&a, &m));
+    assert!(!a.is_congruent(&ubig!(6), &m));
+    assert!(ubig!(0xffffffffffffffff).is_congruent(&ubig!(_0x1ffffffffffffffff), &m));
+    assert!(IBig::from(a).is_congruent(&ibig!(-0xfffffffffffffffb), &IBig::from(m)));
+}
+
+#[test]
+fn test_remove_factor() {
+    assert_eq!(ubig!(0).remove_factor(&ubig!(3)), (ubig!(0), 0));
+    assert_eq!(ubig!(1).remove_factor(&ubig!(3)), (ubig!(1), 0));
+    assert_eq!(ubig!(81).remove_factor(&ubig!(3)), (ubig!(1), 4));
+    assert_eq!(ubig!(1350).remove_factor(&ubig!(5)), (ubig!(54), 2));
+    assert_eq!(ubig!(96).remove_factor(&ubig!(8)), (ubig!(12), 1));
+    assert_eq!(ubig!(96).remove_factor(&ubig!(2)), (ubig!(3), 5));
+
+    let p = ubig!(1000000007);
+    let cofactor = ubig!(123456789) * ubig!(2);
+    let x = p.pow(37) * &cofactor;
+    assert_eq!(x.remove_factor(&p), (cofactor.clone(), 37));
+    assert_eq!(x.remove_factor(&p.pow(5)), (&cofactor * p.pow(2), 7));
+
+    let x = ubig!(5) << 1003;
+    assert_eq!(x.remove_factor(&ubig!(16)), (ubig!(5) << 3, 250));
+    assert_eq!(x.remove_factor(&ubig!(1024)), (ubig!(5) << 3, 100));
+}
+
+#[test]
+#[should_panic]
+fn test_remove_factor_one() {
+    let _ = ubig!(10).remove_factor(&ubig!(1));
+}
+
+#[test]
+fn test_factor() {
+    assert!(ubig!(1).factor().is_empty());
+    assert_eq!(ubig!(2).factor(), [(ubig!(2), 1)]);
+    assert_eq!(ubig!(1021).factor(), [(ubig!(1021), 1)]);
+    assert_eq!(ubig!(1031).factor(), [(ubig!(1031), 1)]);
+    assert_eq!((ubig!(1) << 100).factor(), [(ubig!(2), 100)]);
+
+    let mersenne = ubig!(2).pow(127) - ubig!(1);
+    assert_eq!(mersenne.factor(), [(mersenne.clone(), 1)]);
+
+    // Known primes: two 32-bit ones, two 30-bit ones, and a few small ones.
+    let p1 = ubig!(4294967291);
+    let p2 = ubig!(4294967311);
+    let p3 = ubig!(1000000007);
+    let p4 = ubig!(998244353);
+    let expected = [
+        (ubig!(3), 2),
+        (ubig!(1009), 1),
+        (ubig!(1033), 3),
+        (p4.clone(), 1),
+        (p3.clone(), 2),
+        (p1.clone(), 1),
+        (p2.clone(), 2),
+    ];
+    let n = expected
+        .iter()
+        .map(|(p, e)| p.pow(*e))
+        .fold(ubig!(1), |acc, x| acc * x);
+    assert_eq!(n.factor(), expected);
+
+    assert_eq!((&p1 * &p2).factor(), [(p1, 1), (p2, 1)]);
+    assert_eq!(p3.pow(4).factor(), [(p3, 4)]);
+
+    // The 6th Fermat number.
+    let f6 = (ubig!(1) << 64) + ubig!(1);
+    assert_eq!(
+        f6.factor(),
+        [(ubig!(274177), 1), (ubig!(67280421310721), 1)]
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_factor_zero() {
+    let _ = ubig!(0).factor();
+}
+
+#[test]
+fn test_divisors() {
+    fn sorted_divisors(n: &UBig) -> Vec<UBig> {
+        let mut divisors: Vec<UBig> = n.divisors().collect();
+        divisors.sort();
+        divisors
+    }
+
+    assert_eq!(sorted_divisors(&ubig!(1)), [ubig!(1)]);
+    assert_eq!(sorted_divisors(&ubig!(1031)), [ubig!(1), ubig!(1031)]);
+    for n in 1u32..300 {
+        let naive: Vec<UBig> = (1..=n).filter(|d| n % d == 0).map(UBig::from).collect();
+        let n = UBig::from(n);
+        assert_eq!(sorted_divisors(&n), naive);
+        assert_eq!(n.num_divisors(), UBig::from(naive.len()));
+    }
+
+    let p = ubig!(4294967291);
+    let n = ubig!(72) * &p;
+    let divisors = sorted_divisors(&n);
+    assert_eq!(divisors.len(), 24);
+    assert!(divisors.iter().all(|d| (&n % d).is_zero()));
+    assert_eq!(divisors[23], n);
+    assert_eq!(n.num_divisors(), ubig!(24));
+
+    // The product of the first 70 primes has 2^70 divisors.
+    let mut primorial = ubig!(1);
+    let mut p = ubig!(1);
+    for _ in 0..70 {
+        p = p.next_prime();
+        primorial *= &p;
+    }
+    assert_eq!(primorial.num_divisors(), ubig!(1) << 70);
+    assert_eq!(primorial.divisors().take(1000).count(), 1000);
+}
+
+#[test]
+fn test_euler_phi() {
+    for n in 1u32..300 {
+        let naive = (1..=n)
+            .filter(|k| UBig::from(*k).gcd(&UBig::from(n)) == ubig!(1))
+            .count();
+        assert_eq!(UBig::from(n).euler_phi(), UBig::from(naive));
+    }
+
+    // RSA-style modulus.
+    let p = ubig!(4294967291);
+    let q = ubig!(4294967311);
+    assert_eq!((&p * &q).euler_phi(), (&p - ubig!(1)) * (&q - ubig!(1)));
+    assert_eq!((ubig!(1) << 100).euler_phi(), ubig!(1) << 99);
+}
+
+#[test]
+#[should_panic]
+fn test_euler_phi_zero() {
+    let _ = ubig!(0).euler_phi();
+}
@@ -1,4 +1,4 @@
-use ibig::{ibig, ubig};
+use ibig::{ibig, ubig, IBig, UBig};
 
 #[test]
 fn test_pow_ubig() {
@@ -37,3 +37,151 @@ fn test_pow_ibig() {
         assert_eq!(a.pow(*b), *c);
     }
 }
+
+#[test]
+fn test_pow2() {
+    for exp in (0..300).chain([1000, 4095, 4096, 4097, 50000]) {
+        let x = UBig::pow2(exp);
+        assert_eq!(x, ubig!(1) << exp);
+        assert_eq!(x.bit_len(), exp + 1);
+        assert_eq!(x.trailing_zeros(), Some(exp));
+        assert_eq!(IBig::pow2(exp), IBig::from(x));
+    }
+    assert_eq!(ubig!(2).pow(1000), UBig::pow2(1000));
+}
+
+#[test]
+fn test_pow_special_bases() {
+    for exp in [0, 1, 2, 3, 63, 64, 65, 1000] {
+        let zero = if exp == 0 { ubig!(1) } else { ubig!(0) };
+        assert_eq!(ubig!(0).pow_instrumented(exp), (zero, 0));
+        assert_eq!(ubig!(1).pow_instrumented(exp), (ubig!(1), 0));
+        assert_eq!(ubig!(2).pow_instrumented(exp), (ubig!(1) << exp, 0));
+
+        assert_eq!(ibig!(1).pow(exp), ibig!(1));
+        let sign = if exp % 2 == 0 { ibig!(1) } else { ibig!(-1) };
+        assert_eq!(ibig!(-1).pow(exp), sign);
+        assert_eq!(ibig!(-2).pow(exp), sign * (ibig!(1) << exp));
+    }
+}
+
+#[test]
+fn test_pow_instrumented() {
+    assert_eq!(ubig!(3).pow_instrumented(0), (ubig!(1), 0));
+    assert_eq!(ubig!(3).pow_instrumented(1), (ubig!(3), 0));
+    assert_eq!(ubig!(3).pow_instrumented(2), (ubig!(9), 1));
+    assert_eq!(ubig!(2).pow_instrumented(100), (ubig!(1) << 100, 0));
+    assert_eq!(ubig!(0).pow_instrumented(100), (ubig!(0), 0));
+
+    let x = ubig!(_0x123456789abcdef0123456789abcdef);
+    for exp in [5, 16, 31, 100, 300] {
+        let (res, count) = x.pow_instrumented(exp);
+        assert_eq!(res, x.pow(exp));
+        // One squaring per bit after the first, one multiplication per set bit after the first.
+        let bits = usize::BITS - exp.leading_zeros();
+        assert_eq!(count, (bits - 1 + exp.count_ones() - 1) as usize);
+    }
+}
+
+#[test]
+fn test_checked_pow() {
+    assert_eq!(ubig!(3).checked_pow(0), Some(ubig!(1)));
+    assert_eq!(ubig!(0).checked_pow(usize::MAX), Some(ubig!(0)));
+    assert_eq!(ubig!(1).checked_pow(usize::MAX), Some(ubig!(1)));
+    assert_eq!(ubig!(3).checked_pow(100), Some(ubig!(3).pow(100)));
+    assert_eq!(ubig!(2).checked_pow(usize::MAX), None);
+
+    // Only feasible when the maximum size is small, e.g. with 16-bit words.
+    if UBig::MAX_BIT_LEN <= 1 << 20 {
+        let m = UBig::MAX_BIT_LEN;
+        assert_eq!(ubig!(2).checked_pow(m - 1), Some(ubig!(1) << (m - 1)));
+        assert_eq!(ubig!(2).checked_pow(m), None);
+        // 3^k has floor(k * log2(3)) + 1 bits.
+        let k = (m as f64 / 3f64.log2()) as usize;
+        let fits = ubig!(3).pow(k - 1);
+        assert!(fits.bit_len() <= m);
+        assert_eq!(ubig!(3).checked_pow(k - 1), Some(fits));
+        assert_eq!(ubig!(3).checked_pow(k + 1), None);
+    }
+}
+
+#[test]
+fn test_eval_poly() {
+    assert_eq!(UBig::eval_poly(&[], &ubig!(5)), ubig!(0));
+    assert_eq!(UBig::eval_poly(&[ubig!(7)], &ubig!(5)), ubig!(7));
+    assert_eq!(
+        UBig::eval_poly(&[ubig!(1), ubig!(0), ubig!(4), ubig!(2)], &ubig!(3)),
+        ubig!(1) + ubig!(4) * ubig!(9) + ubig!(2) * ubig!(27)
+    );
+    assert_eq!(
+        UBig::eval_poly(&[ubig!(5), ubig!(6), ubig!(7)], &ubig!(0)),
+        ubig!(5)
+    );
+
+    // Digits as coefficients.
+    let digits: Vec<UBig> = [3u8, 2, 1, 0, 9, 8, 7, 6, 5, 4]
+        .iter()
+        .map(|d| UBig::from(*d))
+        .collect();
+    assert_eq!(UBig::eval_poly(&digits, &ubig!(10)), ubig!(4567890123));
+
+    let x = ubig!(_0x123456789abcdef0123456789);
+    let coeffs = [ubig!(11), ubig!(_0xfedcba9876543210), ubig!(3), ubig!(1)];
+    let expected = &coeffs[0] + &coeffs[1] * &x + &coeffs[2] * x.pow(2) + &coeffs[3] * x.pow(3);
+    assert_eq!(UBig::eval_poly(&coeffs, &x), expected);
+
+    assert_eq!(IBig::eval_poly(&[], &ibig!(-5)), ibig!(0));
+    assert_eq!(
+        IBig::eval_poly(&[ibig!(-1), ibig!(3), ibig!(-2)], &ibig!(-4)),
+        ibig!(-1) + ibig!(3) * ibig!(-4) + ibig!(-2) * ibig!(16)
+    );
+}
+
+#[test]
+fn test_checked_pow_bits() {
+    for base in 0u32..20 {
+        let base = UBig::from(base);
+        for exp in 0usize..20 {
+            let actual = base.pow(exp).bit_len();
+            let predicted = base.checked_pow_bits(&UBig::from(exp)).unwrap();
+            assert!(predicted >= actual);
+            assert!(predicted - actual < exp.max(1));
+            if base.is_power_of_two() || base <= ubig!(1) || exp == 0 {
+                assert_eq!(predicted, actual);
+            }
+        }
+    }
+
+    let x = ubig!(_0xfedcba9876543210fedcba9876543210);
+    assert_eq!(x.checked_pow_bits(&ubig!(100)), Some(12800));
+    assert_eq!(x.checked_pow_bits(&UBig::from(usize::MAX)), None);
+    assert_eq!(x.checked_pow_bits(&(ubig!(1) << 200)), None);
+    assert_eq!(ubig!(0).checked_pow_bits(&(ubig!(1) << 200)), Some(0));
+    assert_eq!(ubig!(1).checked_pow_bits(&(ubig!(1) << 200)), Some(1));
+    assert_eq!(ubig!(2).checked_pow_bits(&UBig::from(usize::MAX)), None);
+    assert_eq!(
+        ubig!(2).checked_pow_bits(&UBig::from(usize::MAX - 1)),
+        Some(usize::MAX)
+    );
+}
+
+#[test]
+fn test_mul_pow() {
+    let values = [
+        ubig!(0),
+        ubig!(1),
+        ubig!(2),
+        ubig!(3),
+        ubig!(10),
+        ubig!(16),
+        ubig!(_0xfedcba9876543210fedcba9876543210),
+        ubig!(1) << 100,
+    ];
+    for x in &values {
+        for base in &values {
+            for exp in [0, 1, 2, 7, 30] {
+                assert_eq!(x.mul_pow(base, exp), x * base.pow(exp));
+            }
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use ibig::{error::ParseError, ibig, ubig, IBig, UBig};
+use ibig::{error::ParseError, fmt::RadixFormatter, ibig, ubig, IBig, UBig};
 
 #[test]
 fn test_ubig_format() {
@@ -25,6 +25,17 @@ fn test_ubig_format() {
 
     assert_eq!(format!("{}", ubig!(123)), "123");
     assert_eq!(format!("{:?}", ubig!(123)), "123");
+    assert_eq!(
+        format!("{:#?}", ubig!(123)),
+        "UBig::Small {\n    value: 123,\n}"
+    );
+    let large = ubig!(1) << 200;
+    let pretty = format!("{:#?}", large);
+    assert!(pretty.starts_with(&format!(
+        "UBig::Large {{\n    value: {},\n    words: ",
+        large
+    )));
+    assert!(pretty.contains("capacity: "));
     assert_eq!(format!("{:=>5}", ubig!(123)), "==123");
 
     let a = UBig::from_be_bytes(&[
@@ -100,6 +111,109 @@ fn test_ubig_in_radix() {
         "83c0d7401f0188462502c2e5f7035386b1c341d307e5fbe8200756201607769a706134cfab1");
 }
 
+#[test]
+fn test_to_radix_string() {
+    assert_eq!(ubig!(0).to_radix_string(2), "0");
+    assert_eq!(ubig!(100).to_radix_string(4), "1210");
+    assert_eq!(ubig!(1294).to_radix_string(36), "zy");
+    assert_eq!(ubig!(1294).to_radix_string_upper(36), "ZY");
+    assert_eq!(ubig!(83).to_radix_string_upper(3), "10002");
+    let a = ubig!(_0x83c0d7401f0188462502c2e5f7035386b1c341d307e5fbe8200756201607769a706134cfab1);
+    for radix in [2, 7, 10, 16, 36] {
+        assert_eq!(a.to_radix_string(radix), a.in_radix(radix).to_string());
+        assert_eq!(
+            a.to_radix_string_upper(radix),
+            format!("{:#}", a.in_radix(radix))
+        );
+        assert_eq!(
+            UBig::from_str_radix(&a.to_radix_string(radix), radix),
+            Ok(a.clone())
+        );
+    }
+
+    assert_eq!(ibig!(0).to_radix_string(10), "0");
+    assert_eq!(ibig!(-1294).to_radix_string(36), "-zy");
+    assert_eq!(ibig!(-1294).to_radix_string_upper(36), "-ZY");
+    assert_eq!(ibig!(1294).to_radix_string_upper(36), "ZY");
+}
+
+#[test]
+fn test_write_radix_digits() {
+    let mut digits = vec![7; 100];
+    ubig!(0).write_radix_digits(2, &mut digits);
+    assert_eq!(digits, [0]);
+    ubig!(0x1f3).write_radix_digits(16, &mut digits);
+    assert_eq!(digits, [1, 15, 3]);
+    ubig!(1294).write_radix_digits(36, &mut digits);
+    assert_eq!(digits, [35, 34]);
+
+    let a = ubig!(_0x83c0d7401f0188462502c2e5f7035386b1c341d307e5fbe8200756201607769a706134cfab1);
+    for x in [a.clone(), ubig!(3).pow(3000)] {
+        for radix in [2, 7, 10, 16, 36] {
+            x.write_radix_digits(radix, &mut digits);
+            let expected: Vec<u8> = x
+                .to_radix_string(radix)
+                .chars()
+                .map(|c| c.to_digit(radix).unwrap() as u8)
+                .collect();
+            assert_eq!(digits, expected);
+        }
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_write_radix_digits_invalid_radix() {
+    ubig!(5).write_radix_digits(1, &mut Vec::new());
+}
+
+#[test]
+#[should_panic]
+fn test_to_radix_string_invalid_radix() {
+    let _ = ubig!(5).to_radix_string(37);
+}
+
+#[test]
+fn test_radix_formatter() {
+    let numbers = [
+        ubig!(0),
+        ubig!(83),
+        ubig!(1) << 100,
+        (ubig!(1) << 5000) - ubig!(1),
+        ubig!(3).pow(10000),
+        ubig!(12345) << 20000,
+        ubig!(7) << 1000,
+    ];
+    for radix in [3, 10, 16, 36] {
+        let mut formatter = RadixFormatter::new(radix);
+        assert_eq!(formatter.radix(), radix);
+        // Each number either extends the cached powers or uses only some of them.
+        for x in numbers.iter().chain(numbers.iter().rev()) {
+            assert_eq!(
+                formatter.format(x).to_string(),
+                x.in_radix(radix).to_string()
+            );
+            assert_eq!(
+                format!("{:#}", formatter.format(x)),
+                format!("{:#}", x.in_radix(radix))
+            );
+        }
+    }
+
+    let mut formatter = RadixFormatter::new(10);
+    assert_eq!(
+        format!("{:+08}", formatter.format(&ubig!(1234))),
+        "+0001234"
+    );
+    assert_eq!(format!("{:.6}", formatter.format(&ubig!(1234))), "001234");
+}
+
+#[test]
+#[should_panic]
+fn test_radix_formatter_invalid_radix() {
+    let _ = RadixFormatter::new(1);
+}
+
 #[test]
 fn test_ibig_format() {
     assert_eq!(format!("{:b}", ibig!(0)), "0");
@@ -144,9 +258,37 @@ fn test_ibig_format() {
 
     assert_eq!(format!("{}", ibig!(-123)), "-123");
     assert_eq!(format!("{:?}", ibig!(-123)), "-123");
+    assert_eq!(
+        format!("{:#?}", ibig!(-123)),
+        "IBig {\n    sign: Negative,\n    magnitude: UBig::Small {\n        value: 123,\n    },\n}"
+    );
     assert_eq!(format!("{:=>10}", ibig!(-123)), "======-123");
 }
 
+#[test]
+fn test_pretty_debug_in_containers() {
+    #[derive(Debug)]
+    #[allow(dead_code)]
+    struct Point {
+        x: UBig,
+        y: IBig,
+    }
+
+    let large = ubig!(1) << 200;
+    let point = Point {
+        x: large.clone(),
+        y: -IBig::from(large.clone()),
+    };
+    let value = format!("value: {},", large);
+    let pretty = format!("{:#?}", point);
+    assert_eq!(pretty.matches(&value).count(), 2);
+    assert!(pretty.contains("sign: Negative"));
+
+    let pretty = format!("{:#?}", vec![ubig!(5), large]);
+    assert!(pretty.contains("value: 5,"));
+    assert!(pretty.contains(&value));
+}
+
 #[test]
 fn test_ibig_in_radix() {
     assert_eq!(format!("{}", ibig!(0).in_radix(2)), "0");
@@ -170,6 +312,137 @@ fn test_ibig_in_radix() {
     assert_eq!(ibig!(-1234).to_string(), "-1234");
 }
 
+#[test]
+fn test_format_signed_zero() {
+    // Zero is never negative, however it was computed.
+    let zeros = [
+        ibig!(0),
+        -ibig!(0),
+        ibig!(-5) + ibig!(5),
+        ibig!(-5) * ibig!(0),
+        ibig!(-5) % ibig!(5),
+        -(ibig!(1) << 100) + (ibig!(1) << 100),
+    ];
+    for zero in &zeros {
+        assert_eq!(format!("{}", zero), "0");
+        assert_eq!(format!("{:+}", zero), "+0");
+        assert_eq!(format!("{:+}", zero), format!("{:+}", ubig!(0)));
+        assert_eq!(format!("{:+x}", zero), "+0");
+        assert_eq!(format!("{:+#X}", zero), "+0x0");
+        assert_eq!(format!("{:+b}", zero), "+0");
+        assert_eq!(format!("{:+o}", zero), "+0");
+        assert_eq!(format!("{:+05}", zero), "+0000");
+        assert_eq!(format!("{:+.3}", zero), "+000");
+        assert_eq!(format!("{:<+4}|", zero), "+0  |");
+        assert_eq!(format!("{:+}", zero.in_radix(7)), "+0");
+        assert_eq!(format!("{:+?}", zero), "+0");
+        assert_eq!(format!("{:+e}", zero), "+0e0");
+        assert_eq!(format!("{:e}", zero), "0e0");
+        assert_eq!(zero.to_radix_string(10), "0");
+    }
+    assert_eq!(format!("{:+}", ubig!(0)), "+0");
+    assert_eq!(format!("{:+#x}", ubig!(0)), "+0x0");
+    assert_eq!(format!("{:+05}", ubig!(0)), "+0000");
+    assert_eq!(format!("{:+e}", ubig!(0)), "+0e0");
+}
+
+#[test]
+fn test_format_precision() {
+    assert_eq!(format!("{:.10}", ubig!(0)), "0000000000");
+    assert_eq!(format!("{:.10}", ubig!(12345)), "0000012345");
+    assert_eq!(format!("{:.3}", ubig!(12345)), "12345");
+    assert_eq!(format!("{:.10}", ibig!(-12345)), "-0000012345");
+    assert_eq!(format!("{:+.10}", ibig!(12345)), "+0000012345");
+    assert_eq!(format!("{:14.10}", ibig!(-12345)), "   -0000012345");
+    assert_eq!(format!("{:<14.10}|", ubig!(12345)), "0000012345    |");
+    assert_eq!(format!("{:014.10}", ibig!(-12345)), "-0000000012345");
+    assert_eq!(format!("{:#.10x}", ubig!(0xabc)), "0x0000000abc");
+    assert_eq!(format!("{:.10X}", ibig!(-0xabc)), "-0000000ABC");
+    assert_eq!(format!("{:.10b}", ubig!(5)), "0000000101");
+    assert_eq!(format!("{:.10o}", ubig!(8)), "0000000010");
+    assert_eq!(format!("{:.10}", ubig!(7).in_radix(36)), "0000000007");
+    assert_eq!(format!("{:#.5}", ibig!(-35).in_radix(36)), "-0000Z");
+
+    let large = ubig!(1) << 200;
+    let digits = large.to_string();
+    assert_eq!(
+        format!("{:.100}", large),
+        format!("{}{}", "0".repeat(100 - 61), digits)
+    );
+    assert_eq!(format!("{:.10}", large), digits);
+    let hex = format!("{:x}", large);
+    assert_eq!(
+        format!("{:.60x}", large),
+        format!("{}{}", "0".repeat(60 - 51), hex)
+    );
+}
+
+#[test]
+fn test_format_exp() {
+    for x in (0..2000u64).chain([9999, 10000, 123456789, u64::MAX, u64::MAX - 1]) {
+        let b = UBig::from(x);
+        assert_eq!(format!("{:e}", b), format!("{:e}", x));
+        assert_eq!(format!("{:E}", b), format!("{:E}", x));
+        for precision in 0..5 {
+            assert_eq!(
+                format!("{:.*e}", precision, b),
+                format!("{:.*e}", precision, x)
+            );
+        }
+        let y = -(x as i128);
+        assert_eq!(format!("{:e}", IBig::from(y)), format!("{:e}", y));
+        assert_eq!(format!("{:.1E}", IBig::from(y)), format!("{:.1E}", y));
+    }
+
+    assert_eq!(format!("{:e}", ubig!(1200)), "1.2e3");
+    assert_eq!(format!("{:.0e}", ubig!(25)), "2e1");
+    assert_eq!(format!("{:.2e}", ubig!(9999)), "1.00e4");
+    assert_eq!(format!("{:.3e}", ubig!(0)), "0.000e0");
+    assert_eq!(format!("{:+e}", ubig!(1200)), "+1.2e3");
+    assert_eq!(format!("{:>8e}", ubig!(1200)), "   1.2e3");
+    assert_eq!(format!("{:08e}", ibig!(-1200)), "-001.2e3");
+
+    let a = ubig!(10).pow(100);
+    assert_eq!(format!("{:e}", a), "1e100");
+    assert_eq!(format!("{:.2e}", &a - ubig!(1)), "1.00e100");
+    assert_eq!(format!("{:.2E}", &a * ubig!(1235)), "1.24E103");
+    assert_eq!(format!("{:.2e}", &a * ubig!(1245)), "1.24e103");
+    assert_eq!(format!("{:.2e}", &a * ubig!(1245) + ubig!(1)), "1.25e103");
+    assert_eq!(format!("{:.1e}", -IBig::from(&a * ubig!(125))), "-1.2e102");
+    assert_eq!(format!("{:.105e}", a), format!("1.{}e100", "0".repeat(105)));
+}
+
+#[test]
+fn test_significant_digits() {
+    for radix in [2, 3, 7, 8, 10, 16, 36] {
+        let r = UBig::from(radix);
+        for k in [1, 5, 40, 200, 3000] {
+            let p = r.pow(k);
+            for x in [&p - ubig!(1), p.clone(), &p + ubig!(1)] {
+                let all: Vec<u8> = x
+                    .in_radix(radix)
+                    .to_string()
+                    .chars()
+                    .map(|c| c.to_digit(radix).unwrap() as u8)
+                    .collect();
+                for n in [0, 1, 3, k - 1, k, k + 1, k + 5] {
+                    let (digits, len) = x.significant_digits(n, radix);
+                    assert_eq!(len, all.len());
+                    assert_eq!(digits, &all[..n.min(all.len())]);
+                }
+            }
+        }
+    }
+    assert_eq!(ubig!(0).significant_digits(0, 10), (vec![], 1));
+    assert_eq!(ubig!(0).significant_digits(3, 2), (vec![0], 1));
+}
+
+#[test]
+#[should_panic]
+fn test_significant_digits_invalid_radix() {
+    let _ = ubig!(10).significant_digits(1, 37);
+}
+
 #[test]
 fn test_ubig_from_str_radix() {
     assert_eq!(
@@ -223,6 +496,36 @@ fn test_ubig_from_str_radix() {
     }
 }
 
+#[test]
+fn test_from_str_radix_decimal_groups() {
+    let digits = "9081726354453627180918273645546372819";
+    for len in 1..=digits.len() {
+        let s = &digits[..len];
+        let expected = s
+            .bytes()
+            .fold(ubig!(0), |acc, b| acc * ubig!(10) + UBig::from(b - b'0'));
+        assert_eq!(UBig::from_str_radix(s, 10), Ok(expected.clone()));
+        let le: String = s.chars().rev().collect();
+        assert_eq!(UBig::from_str_radix_le(&le, 10), Ok(expected));
+
+        // Every byte just outside the digit range, in every position.
+        for pos in 0..len {
+            for bad in ["/", ":", "a", "\u{e9}", "\u{7f}", " "] {
+                let s = format!("{}{}{}", &s[..pos], bad, &s[pos + 1..]);
+                assert_eq!(UBig::from_str_radix(&s, 10), Err(ParseError::InvalidDigit));
+                assert_eq!(
+                    UBig::from_str_radix_le(&s, 10),
+                    Err(ParseError::InvalidDigit)
+                );
+            }
+        }
+    }
+    assert_eq!(
+        UBig::from_str_radix("99999999999999999999999999999999", 10),
+        Ok(ubig!(10).pow(32) - ubig!(1))
+    );
+}
+
 #[test]
 fn test_ibig_from_str_radix() {
     assert_eq!(
@@ -262,6 +565,260 @@ fn test_ibig_from_str_radix() {
     }
 }
 
+#[test]
+fn test_ibig_from_str_radix_unicode() {
+    assert_eq!(
+        IBig::from_str_radix_unicode("\u{2212}123", 10),
+        Ok(ibig!(-123))
+    );
+    assert_eq!(IBig::from_str_radix_unicode("−ff", 16), Ok(ibig!(-0xff)));
+    assert_eq!(IBig::from_str_radix_unicode("-123", 10), Ok(ibig!(-123)));
+    assert_eq!(IBig::from_str_radix_unicode("+123", 10), Ok(ibig!(123)));
+    assert_eq!(IBig::from_str_radix_unicode("123", 10), Ok(ibig!(123)));
+    assert_eq!(IBig::from_str_radix_unicode("−0", 10), Ok(ibig!(0)));
+    assert_eq!(
+        IBig::from_str_radix_unicode("−", 10).unwrap_err(),
+        ParseError::NoDigits
+    );
+    assert_eq!(
+        IBig::from_str_radix_unicode("−-1", 10).unwrap_err(),
+        ParseError::InvalidDigit
+    );
+    assert_eq!(
+        IBig::from_str_radix_unicode("−+1", 10).unwrap_err(),
+        ParseError::InvalidDigit
+    );
+    assert_eq!(
+        IBig::from_str_radix_unicode("1−", 10).unwrap_err(),
+        ParseError::InvalidDigit
+    );
+
+    // The default parser stays strict.
+    assert_eq!(
+        IBig::from_str_radix("−123", 10).unwrap_err(),
+        ParseError::InvalidDigit
+    );
+}
+
+#[test]
+fn test_from_digit() {
+    for radix in 2..=36 {
+        for (value, c) in "0123456789abcdefghijklmnopqrstuvwxyz".chars().enumerate() {
+            let expected = if (value as u32) < radix {
+                Some(UBig::from(value))
+            } else {
+                None
+            };
+            assert_eq!(UBig::from_digit(c, radix), expected);
+            assert_eq!(UBig::from_digit(c.to_ascii_uppercase(), radix), expected);
+            assert_eq!(c.to_digit(radix).map(UBig::from), expected);
+        }
+    }
+    for c in [
+        '-', '+', ' ', '\0', '/', ':', '@', '[', '`', '{', '\u{2212}', '\u{ff11}', '\u{130}',
+    ] {
+        assert_eq!(UBig::from_digit(c, 36), None);
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_from_digit_invalid_radix() {
+    let _ = UBig::from_digit('0', 1);
+}
+
+#[test]
+fn test_from_str_radix_trimmed() {
+    assert_eq!(UBig::from_str_radix_trimmed("1234", 10), Ok(ubig!(1234)));
+    assert_eq!(
+        UBig::from_str_radix_trimmed(" \t\n\r\x0c+1234 \t\n\r\x0c", 10),
+        Ok(ubig!(1234))
+    );
+    assert_eq!(UBig::from_str_radix_trimmed("  ff\n", 16), Ok(ubig!(0xff)));
+    assert_eq!(
+        UBig::from_str_radix_trimmed("   ", 10).unwrap_err(),
+        ParseError::NoDigits
+    );
+    assert_eq!(
+        UBig::from_str_radix_trimmed("+ 1", 10).unwrap_err(),
+        ParseError::InvalidDigit
+    );
+    assert_eq!(
+        UBig::from_str_radix_trimmed("1 2", 10).unwrap_err(),
+        ParseError::InvalidDigit
+    );
+    // Vertical tab and non-ASCII whitespace are not trimmed.
+    assert_eq!(
+        UBig::from_str_radix_trimmed("\x0b1", 10).unwrap_err(),
+        ParseError::InvalidDigit
+    );
+    assert_eq!(
+        UBig::from_str_radix_trimmed("1\u{a0}", 10).unwrap_err(),
+        ParseError::InvalidDigit
+    );
+    // The strict version is unchanged.
+    assert_eq!(
+        UBig::from_str_radix(" 1", 10).unwrap_err(),
+        ParseError::InvalidDigit
+    );
+    assert!(" 1".parse::<UBig>().is_err());
+
+    assert_eq!(
+        IBig::from_str_radix_trimmed(" -1234\n", 10),
+        Ok(ibig!(-1234))
+    );
+    assert_eq!(IBig::from_str_radix_trimmed("\t+zz ", 36), Ok(ibig!(1295)));
+    assert_eq!(
+        IBig::from_str_radix_trimmed(" - 1", 10).unwrap_err(),
+        ParseError::InvalidDigit
+    );
+    assert_eq!(
+        IBig::from_str_radix_trimmed("", 10).unwrap_err(),
+        ParseError::NoDigits
+    );
+    assert!(" -1".parse::<IBig>().is_err());
+}
+
+#[test]
+fn test_from_digit_iter() {
+    assert_eq!(
+        UBig::from_digit_iter("".chars(), 10),
+        Err(ParseError::NoDigits)
+    );
+    assert_eq!(UBig::from_digit_iter("0".chars(), 10), Ok(ubig!(0)));
+    assert_eq!(UBig::from_digit_iter("000".chars(), 16), Ok(ubig!(0)));
+    assert_eq!(UBig::from_digit_iter("0012".chars(), 10), Ok(ubig!(12)));
+    assert_eq!(UBig::from_digit_iter("f1Ee".chars(), 16), Ok(ubig!(0xf1ee)));
+    assert_eq!(
+        UBig::from_digit_iter("+12".chars(), 10),
+        Err(ParseError::InvalidDigit)
+    );
+    assert_eq!(
+        UBig::from_digit_iter("12a".chars(), 10),
+        Err(ParseError::InvalidDigit)
+    );
+    assert_eq!(
+        UBig::from_digit_iter("1\u{e9}".chars(), 36),
+        Err(ParseError::InvalidDigit)
+    );
+
+    for radix in [2, 3, 8, 10, 16, 32, 36] {
+        for x in [
+            ubig!(7),
+            ubig!(0xfedcba9876543210fedcba9876543210),
+            ubig!(3).pow(5000),
+        ] {
+            let s = x.in_radix(radix).to_string();
+            // Feed the digits through an adapter, without a string to borrow from.
+            let digits = s.bytes().map(char::from);
+            assert_eq!(UBig::from_digit_iter(digits, radix), Ok(x.clone()));
+            for len in 1..s.len().min(100) {
+                assert_eq!(
+                    UBig::from_digit_iter(s[..len].chars(), radix),
+                    UBig::from_str_radix(&s[..len], radix)
+                );
+            }
+        }
+    }
+
+    // A long stream of repeated digits: 10^n - 1.
+    let nines = core::iter::repeat('9').take(5000);
+    assert_eq!(
+        UBig::from_digit_iter(nines, 10),
+        Ok(ubig!(10).pow(5000) - ubig!(1))
+    );
+}
+
+#[test]
+fn test_ibig_from_digit_iter() {
+    assert_eq!(
+        IBig::from_digit_iter("".chars(), 10),
+        Err(ParseError::NoDigits)
+    );
+    assert_eq!(
+        IBig::from_digit_iter("-".chars(), 10),
+        Err(ParseError::NoDigits)
+    );
+    assert_eq!(
+        IBig::from_digit_iter("+".chars(), 10),
+        Err(ParseError::NoDigits)
+    );
+    assert_eq!(IBig::from_digit_iter("-0".chars(), 10), Ok(ibig!(0)));
+    assert_eq!(IBig::from_digit_iter("-123".chars(), 10), Ok(ibig!(-123)));
+    assert_eq!(IBig::from_digit_iter("+123".chars(), 10), Ok(ibig!(123)));
+    assert_eq!(IBig::from_digit_iter("123".chars(), 10), Ok(ibig!(123)));
+    assert_eq!(
+        IBig::from_digit_iter("--1".chars(), 10),
+        Err(ParseError::InvalidDigit)
+    );
+    assert_eq!(
+        IBig::from_digit_iter("1-".chars(), 10),
+        Err(ParseError::InvalidDigit)
+    );
+    let s = format!("-{}", ubig!(3).pow(1000).in_radix(7));
+    assert_eq!(
+        IBig::from_digit_iter(s.chars(), 7),
+        IBig::from_str_radix(&s, 7)
+    );
+}
+
+#[test]
+fn test_ubig_from_str_radix_le() {
+    assert_eq!(
+        UBig::from_str_radix_le("", 10).unwrap_err(),
+        ParseError::NoDigits
+    );
+    assert_eq!(
+        UBig::from_str_radix_le("+1", 10).unwrap_err(),
+        ParseError::InvalidDigit
+    );
+    assert_eq!(
+        UBig::from_str_radix_le("210", 2).unwrap_err(),
+        ParseError::InvalidDigit
+    );
+    assert_eq!(UBig::from_str_radix_le("0", 10), Ok(ubig!(0)));
+    assert_eq!(UBig::from_str_radix_le("000", 16), Ok(ubig!(0)));
+    assert_eq!(UBig::from_str_radix_le("0110101", 2), Ok(ubig!(0b1010110)));
+    assert_eq!(UBig::from_str_radix_le("eE1f", 16), Ok(ubig!(0xf1ee)));
+    assert_eq!(UBig::from_str_radix_le("5432100", 10), Ok(ubig!(12345)));
+
+    for radix in [2, 3, 10, 16, 32, 36] {
+        for x in [
+            ubig!(0),
+            ubig!(7),
+            ubig!(0xfedcba9876543210fedcba9876543210),
+            ubig!(3).pow(5000),
+            ubig!(7).pow(13000),
+        ] {
+            let be = x.in_radix(radix).to_string();
+            let le: String = be.chars().rev().collect();
+            assert_eq!(UBig::from_str_radix_le(&le, radix), Ok(x.clone()));
+            assert_eq!(
+                UBig::from_str_radix_le(&le, radix),
+                UBig::from_str_radix(&be, radix)
+            );
+        }
+    }
+}
+
+#[test]
+fn test_from_str_radix_power_two_lengths() {
+    let digits = "z8y7x6w5v4u3t2s1r0qponmlkjihgfedcba";
+    for radix in [2, 4, 8, 16, 32] {
+        let valid: String = digits.chars().filter(|c| c.is_digit(radix)).collect();
+        // Lengths that end anywhere within a word, up to several words.
+        for len in 1..300 {
+            let s: String = valid.chars().cycle().take(len).collect();
+            let expected = s.chars().fold(ubig!(0), |acc, c| {
+                acc * UBig::from(radix) + UBig::from(c.to_digit(radix).unwrap())
+            });
+            assert_eq!(UBig::from_str_radix(&s, radix), Ok(expected.clone()));
+            let le: String = s.chars().rev().collect();
+            assert_eq!(UBig::from_str_radix_le(&le, radix), Ok(expected));
+        }
+    }
+}
+
 #[test]
 fn test_radix_round_trip() {
     assert_eq!(
@@ -76,3 +76,23 @@ fn test_random_arithmetic() {
         }
     }
 }
+
+#[test]
+fn test_random_prime() {
+    let mut rng = StdRng::seed_from_u64(1);
+    for bits in [2, 3, 4, 10, 64, 65, 128, 200] {
+        for _ in 0..5 {
+            let p = UBig::random_prime(bits, &mut rng);
+            assert_eq!(p.bit_len(), bits);
+            assert!(p.is_prime_bpsw());
+        }
+    }
+    let primes: Vec<UBig> = (0..100).map(|_| UBig::random_prime(2, &mut rng)).collect();
+    assert!(primes.contains(&ubig!(2)) && primes.contains(&ubig!(3)));
+}
+
+#[test]
+#[should_panic]
+fn test_random_prime_1_bit() {
+    let _ = UBig::random_prime(1, &mut StdRng::seed_from_u64(1));
+}
@@ -1,5 +1,5 @@
-use ibig::{ibig, ubig};
-use serde_test::{assert_de_tokens, assert_tokens, Token};
+use ibig::{ibig, ubig, BoundedUBig};
+use serde_test::{assert_de_tokens, assert_de_tokens_error, assert_tokens, Token};
 
 #[test]
 fn test_ubig_serde() {
@@ -33,6 +33,51 @@ fn test_ubig_serde() {
     );
 }
 
+#[test]
+fn test_bounded_ubig_serde() {
+    assert_tokens(
+        &BoundedUBig::<2>(ubig!(0x123451234567890abcdef)),
+        &[
+            Token::Seq { len: Some(2) },
+            Token::U64(0x1234567890abcdef),
+            Token::U64(0x12345),
+            Token::SeqEnd,
+        ],
+    );
+    assert_de_tokens(
+        &BoundedUBig::<2>(ubig!(0x123451234567890abcdef)),
+        &[
+            Token::Seq { len: None },
+            Token::U64(0x1234567890abcdef),
+            Token::U64(0x12345),
+            Token::SeqEnd,
+        ],
+    );
+    // A huge announced length is rejected before allocating.
+    assert_de_tokens_error::<BoundedUBig<2>>(
+        &[Token::Seq {
+            len: Some(usize::MAX),
+        }],
+        &format!(
+            "invalid length {}, expected a sequence of at most 2 64-bit words",
+            usize::MAX
+        ),
+    );
+    assert_de_tokens_error::<BoundedUBig<2>>(
+        &[
+            Token::Seq { len: None },
+            Token::U64(1),
+            Token::U64(2),
+            Token::U64(3),
+        ],
+        "invalid length 3, expected a sequence of at most 2 64-bit words",
+    );
+    assert_de_tokens_error::<BoundedUBig<0>>(
+        &[Token::Seq { len: Some(1) }],
+        "invalid length 1, expected a sequence of at most 0 64-bit words",
+    );
+}
+
 #[test]
 fn test_ibig_serde() {
     assert_tokens(
@@ -1,4 +1,4 @@
-use ibig::{ibig, ubig};
+use ibig::{ibig, ubig, UBig};
 
 #[test]
 #[allow(clippy::identity_op, clippy::op_ref)]
@@ -59,6 +59,43 @@ fn test_ubig_shl_too_large() {
     let _ = ubig!(1) << usize::MAX;
 }
 
+#[test]
+fn test_shr_extract() {
+    assert_eq!(ubig!(0).shr_extract(0), (ubig!(0), ubig!(0)));
+    assert_eq!(ubig!(0).shr_extract(100), (ubig!(0), ubig!(0)));
+    assert_eq!(ubig!(5).shr_extract(100), (ubig!(0), ubig!(5)));
+
+    let x = ubig!(_0x123456789abcdef0123456789abcdef0123456789abcdef);
+    for n in [0, 1, 7, 32, 63, 64, 65, 100, 128, 150, 184, 185, 1000] {
+        let (q, r) = x.shr_extract(n);
+        assert_eq!(q, &x >> n);
+        assert_eq!(r, &x & ((ubig!(1) << n) - ubig!(1)));
+        assert_eq!((q << n) + r, x);
+    }
+}
+
+#[test]
+fn test_checked_shl() {
+    assert_eq!(ubig!(0).checked_shl(0, 0), Some(ubig!(0)));
+    assert_eq!(ubig!(0).checked_shl(usize::MAX, 0), Some(ubig!(0)));
+    assert_eq!(ubig!(1).checked_shl(0, 0), None);
+    assert_eq!(ubig!(1).checked_shl(0, 1), Some(ubig!(1)));
+    assert_eq!(ubig!(1).checked_shl(usize::MAX, usize::MAX), None);
+    assert_eq!(ubig!(1).checked_shl(UBig::MAX_BIT_LEN, usize::MAX), None);
+    assert_eq!(
+        ubig!(1).checked_shl(10000, usize::MAX),
+        Some(ubig!(1) << 10000)
+    );
+
+    let x = ubig!(_0x123456789abcdef0123456789abcdef0123456789abcdef);
+    let bit_len = x.bit_len();
+    for n in [0, 1, 63, 64, 65, 1000] {
+        assert_eq!(x.checked_shl(n, bit_len + n), Some(&x << n));
+        assert_eq!(x.checked_shl(n, bit_len + n + 1), Some(&x << n));
+        assert_eq!(x.checked_shl(n, bit_len + n - 1), None);
+    }
+}
+
 #[test]
 #[allow(clippy::op_ref)]
 fn test_ibig_shl() {
@@ -78,6 +115,29 @@ fn test_shl_assign() {
     assert_eq!(a, ubig!(0xef0));
     a <<= &4;
     assert_eq!(a, ubig!(0xef00));
+
+    let mut b = ibig!(-0xef);
+    b <<= 4;
+    assert_eq!(b, ibig!(-0xef0));
+    b <<= &100;
+    assert_eq!(b, ibig!(-0xef0) << 100);
+}
+
+#[test]
+fn test_shl_assign_reuses_buffer() {
+    let start = (ubig!(1) << 1000) - ubig!(1);
+    let mut a = start.clone();
+    let mut reallocations = 0;
+    for _ in 0..10000 {
+        let capacity = a.capacity_words();
+        a <<= 1;
+        if a.capacity_words() != capacity {
+            reallocations += 1;
+        }
+    }
+    assert_eq!(a, start << 10000);
+    // Capacity grows geometrically rather than one word at a time.
+    assert!(reallocations <= 40);
 }
 
 #[test]
@@ -217,3 +277,24 @@ fn test_ibig_shr() {
         assert_eq!(x, *c);
     }
 }
+
+#[test]
+fn test_wrapping_shl_shr() {
+    fn mask(x: &UBig, width: usize) -> UBig {
+        x & ((ubig!(1) << width) - ubig!(1))
+    }
+
+    assert_eq!(ubig!(0).wrapping_shl(3, 10), ubig!(0));
+    assert_eq!(ubig!(0xff).wrapping_shl(4, 8), ubig!(0xf0));
+    assert_eq!(ubig!(0xff).wrapping_shl(0, 0), ubig!(0));
+    assert_eq!(ubig!(0xff).wrapping_shr(4, 6), ubig!(0b11));
+    assert_eq!(ubig!(0xff).wrapping_shr(0, 100), ubig!(0xff));
+
+    let x = ubig!(_0x123456789abcdef0123456789abcdef0123456789abcdef);
+    for width in [0, 1, 5, 16, 63, 64, 65, 100, 128, 190, 300] {
+        for n in [0, 1, 7, 32, 64, 65, 150, 400] {
+            assert_eq!(x.wrapping_shl(n, width), mask(&(&x << n), width));
+            assert_eq!(x.wrapping_shr(n, width), mask(&x, width) >> n);
+        }
+    }
+}
@@ -1,7 +1,7 @@
 use ibig::{
     ibig,
     ops::{Abs, UnsignedAbs},
-    ubig,
+    ubig, IBig, Sign,
 };
 
 #[test]
@@ -14,6 +14,33 @@ fn test_neg() {
     assert_eq!(-&ibig!(123), ibig!(-123));
 }
 
+#[test]
+fn test_negate() {
+    let mut a = ibig!(123);
+    a.negate();
+    assert_eq!(a, ibig!(-123));
+    a.negate();
+    assert_eq!(a, ibig!(123));
+
+    let mut zero = ibig!(0);
+    zero.negate();
+    assert_eq!(zero, ibig!(0));
+    assert_eq!(zero.sign(), Sign::Positive);
+
+    // div_rem_into keeps the spare capacity of its destination, which a reallocated copy of the
+    // magnitude would not have.
+    let mut q = ubig!(1) << 5000;
+    let mut r = ubig!(0);
+    (ubig!(1) << 1000).div_rem_into(&ubig!(3).pow(300), &mut q, &mut r);
+    let mut big = -IBig::from(q);
+    let expected = -&big;
+    let capacity = big.magnitude().capacity_words();
+    assert!(capacity > expected.magnitude().capacity_words());
+    big.negate();
+    assert_eq!(big, expected);
+    assert_eq!(big.magnitude().capacity_words(), capacity);
+}
+
 #[test]
 fn test_abs() {
     assert_eq!(ibig!(123).abs(), ibig!(123));
@@ -30,9 +57,53 @@ fn test_unsigned_abs() {
     assert_eq!((&ibig!(-123)).unsigned_abs(), ubig!(123));
 }
 
+#[test]
+fn test_abs_unsigned() {
+    assert_eq!(ibig!(123).abs_unsigned(), ubig!(123));
+    assert_eq!(ibig!(-123).abs_unsigned(), ubig!(123));
+    assert_eq!(ibig!(0).abs_unsigned(), ubig!(0));
+    let a = ibig!(-0x123456789abcdef0123456789abcdef);
+    assert_eq!(a.abs_unsigned(), ubig!(0x123456789abcdef0123456789abcdef));
+    assert_eq!(a.abs_unsigned(), a.clone().unsigned_abs());
+    assert_eq!(IBig::from(a.abs_unsigned()), a.abs());
+}
+
+#[test]
+fn test_magnitude() {
+    assert_eq!(*ibig!(123).magnitude(), ubig!(123));
+    assert_eq!(*ibig!(-123).magnitude(), ubig!(123));
+    assert_eq!(*ibig!(0).magnitude(), ubig!(0));
+    let a = ibig!(-0x123456789abcdef0123456789abcdef);
+    assert_eq!(*a.magnitude(), ubig!(0x123456789abcdef0123456789abcdef));
+    assert_eq!(a.magnitude().clone(), a.unsigned_abs());
+}
+
 #[test]
 fn test_signum() {
     assert_eq!(ibig!(-500).signum(), ibig!(-1));
     assert_eq!(ibig!(0).signum(), ibig!(0));
     assert_eq!(ibig!(500).signum(), ibig!(1));
 }
+
+#[test]
+fn test_sign() {
+    assert_eq!(ibig!(-5).sign(), Sign::Negative);
+    assert_eq!(ibig!(0).sign(), Sign::Positive);
+    assert_eq!((ibig!(3) - ibig!(3)).sign(), Sign::Positive);
+    assert_eq!(ibig!(5).sign(), Sign::Positive);
+
+    assert_eq!(-Sign::Positive, Sign::Negative);
+    assert_eq!(-Sign::Negative, Sign::Positive);
+    assert_eq!(Sign::Positive * Sign::Positive, Sign::Positive);
+    assert_eq!(Sign::Positive * Sign::Negative, Sign::Negative);
+    assert_eq!(Sign::Negative * Sign::Positive, Sign::Negative);
+    assert_eq!(Sign::Negative * Sign::Negative, Sign::Positive);
+    let mut sign = Sign::Negative;
+    sign *= Sign::Negative;
+    assert_eq!(sign, Sign::Positive);
+
+    assert_eq!(Sign::Negative * ibig!(5), ibig!(-5));
+    assert_eq!(Sign::Negative * ibig!(-5), ibig!(5));
+    assert_eq!(Sign::Negative * ibig!(0), ibig!(0));
+    assert_eq!(Sign::Positive * ibig!(-5), ibig!(-5));
+}